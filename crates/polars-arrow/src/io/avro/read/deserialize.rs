@@ -59,6 +59,19 @@ fn make_mutable(
                 Box::new(DynMutableStructArray::new(values, data_type.clone()))
                     as Box<dyn MutableArray>
             },
+            ArrowDataType::Union(fields, _, _) => {
+                let avro_variants = union_variants(avro_field);
+                let values = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let avro_variant = avro_variants.as_ref().map(|v| v[i]);
+                        make_mutable(field.data_type(), avro_variant, capacity)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Box::new(DynMutableUnionArray::new(values, data_type.clone()))
+                    as Box<dyn MutableArray>
+            },
             other => {
                 polars_bail!(nyi = "Deserializing type {other:#?} is still not implemented")
             },
@@ -66,6 +79,43 @@ fn make_mutable(
     })
 }
 
+/// The variants of an avro union that are read into arrow, i.e. all but null.
+fn union_variants(avro_field: Option<&AvroSchema>) -> Option<Vec<&AvroSchema>> {
+    match avro_field {
+        Some(AvroSchema::Union(schemas)) => Some(
+            schemas
+                .iter()
+                .filter(|s| !matches!(s, AvroSchema::Null))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Reads the index of the variant of an avro union and returns its schema together with its
+/// index in the arrow union, or `None` for the null variant.
+fn read_union_variant<'a>(
+    avro_field: &'a AvroSchema,
+    block: &mut &[u8],
+) -> PolarsResult<Option<(usize, &'a AvroSchema)>> {
+    let AvroSchema::Union(schemas) = avro_field else {
+        unreachable!()
+    };
+    let index = util::zigzag_i64(block)?;
+    let schema = usize::try_from(index)
+        .ok()
+        .and_then(|index| schemas.get(index))
+        .ok_or_else(|| polars_err!(oos = "Avro union index {index} is out of bounds"))?;
+    if matches!(schema, AvroSchema::Null) {
+        return Ok(None);
+    }
+    let type_id = schemas[..index as usize]
+        .iter()
+        .filter(|s| !matches!(s, AvroSchema::Null))
+        .count();
+    Ok(Some((type_id, schema)))
+}
+
 fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     if let AvroSchema::Union(schemas) = avro_field {
         schemas[0] == AvroSchema::Null
@@ -168,6 +218,20 @@ fn deserialize_value<'a>(
             }
             array.try_push_valid()?;
         },
+        ArrowDataType::Union(_, _, _) => {
+            let variant = read_union_variant(avro_field, &mut block)?;
+            let array = array
+                .as_mut_any()
+                .downcast_mut::<DynMutableUnionArray>()
+                .unwrap();
+            match variant {
+                Some((type_id, schema)) => {
+                    block = deserialize_value(array.mut_values(type_id), schema, block)?;
+                    array.try_push_valid(type_id)?;
+                },
+                None => MutableArray::push_null(array),
+            }
+        },
         _ => match data_type.to_physical_type() {
             PhysicalType::Boolean => {
                 let is_valid = block[0] == 1;
@@ -392,6 +456,11 @@ fn skip_item<'a>(
                 block = skip_item(field, &avro_field.schema, block)?;
             }
         },
+        ArrowDataType::Union(fields, _, _) => {
+            if let Some((type_id, schema)) = read_union_variant(avro_field, &mut block)? {
+                block = skip_item(&fields[type_id], schema, block)?;
+            }
+        },
         _ => match field.data_type.to_physical_type() {
             PhysicalType::Boolean => {
                 let _ = block[0] == 1;
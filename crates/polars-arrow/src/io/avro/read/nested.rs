@@ -314,3 +314,106 @@ impl MutableArray for DynMutableStructArray {
         todo!();
     }
 }
+
+/// Auxiliary struct to build a sparse [`UnionArray`].
+#[derive(Debug)]
+pub struct DynMutableUnionArray {
+    data_type: ArrowDataType,
+    types: Vec<i8>,
+    values: Vec<Box<dyn MutableArray>>,
+}
+
+impl DynMutableUnionArray {
+    pub fn new(values: Vec<Box<dyn MutableArray>>, data_type: ArrowDataType) -> Self {
+        Self {
+            data_type,
+            types: vec![],
+            values,
+        }
+    }
+
+    /// The values of a variant
+    pub fn mut_values(&mut self, field: usize) -> &mut dyn MutableArray {
+        self.values[field].as_mut()
+    }
+
+    /// Marks `field` as the variant of the last value pushed to it. The other variants are null.
+    #[inline]
+    pub fn try_push_valid(&mut self, field: usize) -> PolarsResult<()> {
+        let type_id = i8::try_from(field).map_err(|_| polars_err!(ComputeError: "overflow"))?;
+        self.types.push(type_id);
+        self.values
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| *i != field)
+            .for_each(|(_, x)| x.push_null());
+        Ok(())
+    }
+
+    /// Unions have no validity, a null points to the (null) first variant.
+    #[inline]
+    fn push_null(&mut self) {
+        self.types.push(0);
+        self.values.iter_mut().for_each(|x| x.push_null());
+    }
+}
+
+impl MutableArray for DynMutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let values = self.values.iter_mut().map(|x| x.as_box()).collect();
+
+        Box::new(UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            values,
+            None,
+        ))
+    }
+
+    fn as_arc(&mut self) -> std::sync::Arc<dyn Array> {
+        let values = self.values.iter_mut().map(|x| x.as_box()).collect();
+
+        std::sync::Arc::new(UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            values,
+            None,
+        ))
+    }
+
+    fn data_type(&self) -> &ArrowDataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        // every variant has a value for every row
+        self.values.iter_mut().for_each(|x| x.reserve(additional));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.values.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+}
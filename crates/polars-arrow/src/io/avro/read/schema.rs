@@ -36,6 +36,28 @@ pub fn infer_schema(record: &Record) -> PolarsResult<ArrowSchema> {
         .into())
 }
 
+/// The name of a variant of a union: the name of a named type or else of the type itself.
+/// These are unique, as avro unions may not hold two types of the same name.
+fn variant_name(schema: &AvroSchema) -> String {
+    match schema {
+        AvroSchema::Null => "null",
+        AvroSchema::Boolean => "boolean",
+        AvroSchema::Int(_) => "int",
+        AvroSchema::Long(_) => "long",
+        AvroSchema::Float => "float",
+        AvroSchema::Double => "double",
+        AvroSchema::Bytes(_) => "bytes",
+        AvroSchema::String(_) => "string",
+        AvroSchema::Array(_) => "array",
+        AvroSchema::Map(_) => "map",
+        AvroSchema::Union(_) => "union",
+        AvroSchema::Record(Record { name, .. })
+        | AvroSchema::Enum(Enum { name, .. })
+        | AvroSchema::Fixed(Fixed { name, .. }) => name,
+    }
+    .to_string()
+}
+
 fn schema_to_field(
     schema: &AvroSchema,
     name: Option<&str>,
@@ -105,11 +127,14 @@ fn schema_to_field(
                     polars_bail!(nyi = "Can't read avro union {schema:?}");
                 }
             } else {
+                // A null variant makes the union nullable: arrow unions have no validity, so
+                // these nulls are stored in the first variant instead.
                 let fields = schemas
                     .iter()
-                    .map(|s| schema_to_field(s, None, Metadata::default()))
+                    .filter(|s| !matches!(s, AvroSchema::Null))
+                    .map(|s| schema_to_field(s, Some(&variant_name(s)), Metadata::default()))
                     .collect::<PolarsResult<Vec<Field>>>()?;
-                ArrowDataType::Union(fields, None, UnionMode::Dense)
+                ArrowDataType::Union(fields, None, UnionMode::Sparse)
             }
         },
         AvroSchema::Record(Record { fields, .. }) => {
@@ -33,7 +33,9 @@ fn type_to_schema(
     is_nullable: bool,
     name_counter: &mut i32,
 ) -> PolarsResult<AvroSchema> {
-    Ok(if is_nullable {
+    // unions are always written with a null variant
+    let is_union = matches!(data_type.to_logical_type(), ArrowDataType::Union(_, _, _));
+    Ok(if is_nullable && !is_union {
         AvroSchema::Union(vec![
             AvroSchema::Null,
             _type_to_schema(data_type, name_counter)?,
@@ -74,6 +76,17 @@ fn _type_to_schema(data_type: &ArrowDataType, name_counter: &mut i32) -> PolarsR
                 .map(|f| field_to_field(f, name_counter))
                 .collect::<PolarsResult<Vec<_>>>()?,
         )),
+        // Avro identifies the variants by their type, so their names are not kept.
+        ArrowDataType::Union(fields, _, _) => {
+            let mut schemas = vec![AvroSchema::Null];
+            for field in fields {
+                if field.data_type() == &ArrowDataType::Null {
+                    polars_bail!(nyi = "write a union with a null variant to avro")
+                }
+                schemas.push(_type_to_schema(field.data_type(), name_counter)?);
+            }
+            AvroSchema::Union(schemas)
+        },
         ArrowDataType::Date32 => AvroSchema::Int(Some(IntLogical::Date)),
         ArrowDataType::Time32(TimeUnit::Millisecond) => AvroSchema::Int(Some(IntLogical::Time)),
         ArrowDataType::Time64(TimeUnit::Microsecond) => AvroSchema::Long(Some(LongLogical::Time)),
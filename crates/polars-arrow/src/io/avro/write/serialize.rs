@@ -4,7 +4,7 @@ use avro_schema::write::encode;
 use super::super::super::iterator::*;
 use crate::array::*;
 use crate::bitmap::utils::ZipValidity;
-use crate::datatypes::{ArrowDataType, IntervalUnit, PhysicalType, PrimitiveType};
+use crate::datatypes::{ArrowDataType, IntervalUnit, PhysicalType, PrimitiveType, UnionMode};
 use crate::offset::Offset;
 use crate::types::months_days_ns;
 
@@ -204,6 +204,34 @@ fn struct_optional<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer
     ))
 }
 
+/// Serializes a sparse union whose avro schema starts with null, followed by the variants.
+fn union_sparse<'a>(array: &'a UnionArray, schemas: &[AvroSchema]) -> BoxSerializer<'a> {
+    let mut inner = array
+        .fields()
+        .iter()
+        .zip(&schemas[1..])
+        .map(|(x, schema)| new_serializer(x.as_ref(), schema))
+        .collect::<Vec<_>>();
+
+    Box::new(BufStreamingIterator::new(
+        0..array.len(),
+        move |row, buf| {
+            let (type_id, slot) = array.index(row);
+            let is_valid = array.fields()[type_id].is_valid(slot);
+            let variant = if is_valid { type_id as i64 + 1 } else { 0 };
+            encode::zigzag_encode(variant, buf).unwrap();
+            // every variant has a value in every row, only the active one is written
+            for (i, item) in inner.iter_mut().enumerate() {
+                let value = item.next().unwrap();
+                if is_valid && i == type_id {
+                    buf.extend_from_slice(value);
+                }
+            }
+        },
+        vec![],
+    ))
+}
+
 /// Creates a [`StreamingIterator`] trait object that presents items from `array`
 /// encoded according to `schema`.
 /// # Panic
@@ -493,6 +521,9 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
             };
             struct_optional(array.as_any().downcast_ref().unwrap(), inner)
         },
+        (PhysicalType::Union, AvroSchema::Union(inner)) => {
+            union_sparse(array.as_any().downcast_ref().unwrap(), inner)
+        },
         (a, b) => todo!("{:?} -> {:?} not supported", a, b),
     }
 }
@@ -504,6 +535,11 @@ pub fn can_serialize(data_type: &ArrowDataType) -> bool {
         List(inner) => return can_serialize(&inner.data_type),
         LargeList(inner) => return can_serialize(&inner.data_type),
         Struct(inner) => return inner.iter().all(|inner| can_serialize(&inner.data_type)),
+        Union(inner, _, UnionMode::Sparse) => {
+            return inner
+                .iter()
+                .all(|inner| inner.data_type != Null && can_serialize(&inner.data_type))
+        },
         _ => {},
     };
 
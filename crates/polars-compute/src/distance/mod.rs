@@ -0,0 +1,14 @@
+// Low-level kernels between two vectors of the same length.
+pub trait DistanceKernel: Sized + Copy {
+    /// The sum of the products of the elements of `a` and `b`.
+    fn dot_kernel(a: &[Self], b: &[Self]) -> Self;
+
+    /// The sum of the squared differences between the elements of `a` and `b`.
+    fn squared_l2_kernel(a: &[Self], b: &[Self]) -> Self;
+}
+
+#[cfg(not(feature = "simd"))]
+mod scalar;
+
+#[cfg(feature = "simd")]
+mod simd;
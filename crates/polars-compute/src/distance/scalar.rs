@@ -0,0 +1,47 @@
+use super::DistanceKernel;
+
+// Independent accumulators break the dependency chain between the additions, so the
+// compiler can keep them in vector registers.
+const LANES: usize = 8;
+
+fn fold_pairs<T, F>(a: &[T], b: &[T], zero: T, f: F) -> T
+where
+    T: Copy + std::ops::Add<Output = T>,
+    F: Fn(T, T, T) -> T,
+{
+    assert_eq!(a.len(), b.len());
+    let mut a_chunks = a.chunks_exact(LANES);
+    let mut b_chunks = b.chunks_exact(LANES);
+
+    let mut state = [zero; LANES];
+    for (ca, cb) in a_chunks.by_ref().zip(b_chunks.by_ref()) {
+        for ((acc, x), y) in state.iter_mut().zip(ca).zip(cb) {
+            *acc = f(*acc, *x, *y);
+        }
+    }
+    let mut out = state.into_iter().fold(zero, |acc, x| acc + x);
+    for (x, y) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        out = f(out, *x, *y);
+    }
+    out
+}
+
+macro_rules! impl_distance_kernel {
+    ($T:ty) => {
+        impl DistanceKernel for $T {
+            fn dot_kernel(a: &[$T], b: &[$T]) -> $T {
+                fold_pairs(a, b, 0.0, |acc, x, y| acc + x * y)
+            }
+
+            fn squared_l2_kernel(a: &[$T], b: &[$T]) -> $T {
+                fold_pairs(a, b, 0.0, |acc, x, y| {
+                    let d = x - y;
+                    acc + d * d
+                })
+            }
+        }
+    };
+}
+
+impl_distance_kernel!(f32);
+impl_distance_kernel!(f64);
@@ -0,0 +1,51 @@
+use std::simd::prelude::*;
+use std::simd::{LaneCount, SimdElement, SupportedLaneCount};
+
+use super::DistanceKernel;
+
+fn fold_pairs<const N: usize, T, F>(a: &[T], b: &[T], zero: T, mut simd_f: F) -> Simd<T, N>
+where
+    T: SimdElement,
+    F: FnMut(Simd<T, N>, Simd<T, N>, Simd<T, N>) -> Simd<T, N>,
+    LaneCount<N>: SupportedLaneCount,
+{
+    assert_eq!(a.len(), b.len());
+    let mut a_chunks = a.chunks_exact(N);
+    let mut b_chunks = b.chunks_exact(N);
+
+    let mut state = Simd::splat(zero);
+    for (ca, cb) in a_chunks.by_ref().zip(b_chunks.by_ref()) {
+        state = simd_f(state, Simd::from_slice(ca), Simd::from_slice(cb));
+    }
+    let a_rest = a_chunks.remainder();
+    if !a_rest.is_empty() {
+        // Padding both sides with zeros adds nothing to the sum of products or differences.
+        let mut rest_a = [zero; N];
+        let mut rest_b = [zero; N];
+        rest_a[..a_rest.len()].copy_from_slice(a_rest);
+        rest_b[..a_rest.len()].copy_from_slice(b_chunks.remainder());
+        state = simd_f(state, Simd::from_array(rest_a), Simd::from_array(rest_b));
+    }
+    state
+}
+
+macro_rules! impl_distance_kernel {
+    ($T:ty, $N:literal) => {
+        impl DistanceKernel for $T {
+            fn dot_kernel(a: &[$T], b: &[$T]) -> $T {
+                fold_pairs::<$N, $T, _>(a, b, 0.0, |acc, x, y| acc + x * y).reduce_sum()
+            }
+
+            fn squared_l2_kernel(a: &[$T], b: &[$T]) -> $T {
+                fold_pairs::<$N, $T, _>(a, b, 0.0, |acc, x, y| {
+                    let d = x - y;
+                    acc + d * d
+                })
+                .reduce_sum()
+            }
+        }
+    };
+}
+
+impl_distance_kernel!(f32, 16);
+impl_distance_kernel!(f64, 8);
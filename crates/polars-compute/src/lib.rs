@@ -2,6 +2,7 @@
 
 pub mod arithmetic;
 pub mod comparisons;
+pub mod distance;
 pub mod filter;
 pub mod min_max;
 
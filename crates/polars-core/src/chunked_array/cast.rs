@@ -415,6 +415,17 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // name the entries before we cast them by name
+                let entries = self.clone().into_map()?.0;
+                let entries = entries.cast(&List(Box::new(map_entries_dtype(key, value))))?;
+                entries
+                    .list()?
+                    .clone()
+                    .into_map()
+                    .map(|ca| ca.into_series())
+            },
             _ => {
                 polars_bail!(
                     ComputeError: "cannot cast List type (inner: '{:?}', to: '{:?}')",
@@ -0,0 +1,99 @@
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+/// Name of the struct field that holds the keys of a map.
+pub const MAP_KEY_NAME: &str = "key";
+/// Name of the struct field that holds the values of a map.
+pub const MAP_VALUE_NAME: &str = "value";
+
+/// The dtype of the entries of a map: a struct with a `key` and a `value` field.
+pub fn map_entries_dtype(key: &DataType, value: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(MAP_KEY_NAME, key.clone()),
+        Field::new(MAP_VALUE_NAME, value.clone()),
+    ])
+}
+
+impl ListChunked {
+    /// Interpret the `{key, value}` structs of this list as the entries of a map.
+    ///
+    /// # Safety
+    /// The inner dtype must be [`map_entries_dtype`] of `key` and `value` and the keys may not
+    /// be null.
+    pub unsafe fn into_map_unchecked(self, key: DataType, value: DataType) -> MapChunked {
+        let mut ca = MapChunked::new_logical(self);
+        ca.2 = Some(DataType::Map(Box::new(key), Box::new(value)));
+        ca
+    }
+
+    /// Interpret a list of structs with two fields as a map. The first field holds the keys and
+    /// may not contain nulls, the second one holds the values.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let (key, value) = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                (fields[0].dtype.clone(), fields[1].dtype.clone())
+            },
+            dt => polars_bail!(
+                ComputeError: "expected a list of structs with two fields to create a map, got list[{}]", dt
+            ),
+        };
+        let entries = self.apply_to_inner(&|s| {
+            let ca = s.struct_()?;
+            let mut fields = ca.fields().to_vec();
+            polars_ensure!(fields[0].null_count() == 0, ComputeError: "map keys cannot be null");
+            fields[0].rename(MAP_KEY_NAME);
+            fields[1].rename(MAP_VALUE_NAME);
+            StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+        })?;
+        // SAFETY: the entries are renamed and the keys are checked.
+        Ok(unsafe { entries.into_map_unchecked(key, value) })
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast(&self, dtype: &DataType) -> PolarsResult<Series> {
+        self.0.cast(dtype)
+    }
+}
+
+impl MapChunked {
+    /// The entries of every map as a list of `{key, value}` structs.
+    pub fn physical(&self) -> &ListChunked {
+        &self.0
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        match self.2.as_ref().unwrap() {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match self.2.as_ref().unwrap() {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the keys and the values of all maps as flat [`Series`], ignoring the offsets.
+    pub fn keys_and_values(&self) -> (Series, Series) {
+        let entries = self.0.get_inner();
+        let entries = entries.struct_().unwrap();
+        (entries.fields()[0].clone(), entries.fields()[1].clone())
+    }
+}
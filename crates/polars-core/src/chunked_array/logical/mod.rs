@@ -16,20 +16,28 @@ mod duration;
 pub use duration::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
 #[cfg(feature = "dtype-categorical")]
 pub use categorical::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 #[cfg(feature = "dtype-time")]
 pub use time::*;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 
 use crate::prelude::*;
 
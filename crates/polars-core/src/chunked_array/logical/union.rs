@@ -0,0 +1,298 @@
+use arrow::buffer::Buffer;
+
+use super::*;
+use crate::prelude::*;
+
+/// Name of the struct field that holds the index of the active variant of a union.
+pub const UNION_TAG_NAME: &str = "__tag";
+
+/// The physical dtype of a union: a struct with the index of the active variant followed by a
+/// field for each variant.
+pub fn union_entries_dtype(variants: &[Field]) -> DataType {
+    let mut fields = Vec::with_capacity(variants.len() + 1);
+    fields.push(Field::new(UNION_TAG_NAME, DataType::Int8));
+    fields.extend_from_slice(variants);
+    DataType::Struct(fields)
+}
+
+/// A column where every row holds a value of one of several variants.
+///
+/// The values are stored as a struct with the index of the active variant and a field for every
+/// variant. A variant is only valid in the rows where it is active, and the index is null for
+/// null rows.
+#[derive(Clone)]
+pub struct UnionChunked {
+    entries: StructChunked,
+    dtype: DataType,
+}
+
+impl StructChunked {
+    /// Interpret the fields of this struct as the tag and the variants of a union.
+    ///
+    /// # Safety
+    /// The first field must be the [`Int8`](DataType::Int8) tag, followed by the `variants`. A
+    /// variant may only be valid where the tag points to it.
+    pub unsafe fn into_union_unchecked(self, variants: Vec<Field>) -> UnionChunked {
+        UnionChunked {
+            entries: self,
+            dtype: DataType::Union(variants),
+        }
+    }
+}
+
+impl UnionChunked {
+    /// Create a union from a column per variant. Each row takes the value of the first column
+    /// that is not null in that row.
+    pub fn from_variants(name: &str, variants: &[Series]) -> PolarsResult<Self> {
+        polars_ensure!(!variants.is_empty(), ComputeError: "a union needs at least one variant");
+        polars_ensure!(
+            variants.len() <= i8::MAX as usize,
+            ComputeError: "a union can have at most {} variants", i8::MAX
+        );
+        let len = variants.iter().map(|s| s.len()).max().unwrap();
+        let variants = variants
+            .iter()
+            .map(|s| match s.len() {
+                n if n == len => Ok(s.clone()),
+                1 => Ok(s.new_from_index(0, len)),
+                _ => polars_bail!(ShapeMismatch: "expected all variants to have equal length"),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut tag = vec![None; len];
+        for (i, s) in variants.iter().enumerate().rev() {
+            for (row, valid) in s.is_not_null().into_iter().enumerate() {
+                if valid == Some(true) {
+                    tag[row] = Some(i as i8);
+                }
+            }
+        }
+        let tag = Int8Chunked::from_slice_options(UNION_TAG_NAME, &tag);
+        Self::from_tag_and_values(name, tag, &variants)
+    }
+
+    /// Create a union with a single active variant: the one with the dtype of `s`.
+    pub fn from_series(s: &Series, variants: &[Field]) -> PolarsResult<Self> {
+        let Some(idx) = variants.iter().position(|fld| fld.data_type() == s.dtype()) else {
+            polars_bail!(
+                SchemaMismatch: "cannot cast {} to a union without a variant of that dtype", s.dtype()
+            )
+        };
+        let tag = s
+            .is_not_null()
+            .into_iter()
+            .map(|valid| (valid == Some(true)).then_some(idx as i8))
+            .collect::<Int8Chunked>()
+            .with_name(UNION_TAG_NAME);
+        let values = variants
+            .iter()
+            .enumerate()
+            .map(|(i, fld)| {
+                if i == idx {
+                    s.clone().with_name(fld.name())
+                } else {
+                    Series::full_null(fld.name(), s.len(), fld.data_type())
+                }
+            })
+            .collect::<Vec<_>>();
+        Self::from_tag_and_values(s.name(), tag, &values)
+    }
+
+    /// Mask the `variants` where the `tag` does not point to them and combine them.
+    fn from_tag_and_values(
+        name: &str,
+        tag: Int8Chunked,
+        variants: &[Series],
+    ) -> PolarsResult<Self> {
+        let mut fields = Vec::with_capacity(variants.len() + 1);
+        for (i, s) in variants.iter().enumerate() {
+            let idx = tag
+                .into_iter()
+                .enumerate()
+                .map(|(row, t)| (t == Some(i as i8)).then_some(row as IdxSize))
+                .collect::<Vec<_>>();
+            fields.push(s.take(&IdxCa::from_slice_options("", &idx))?);
+        }
+        fields.insert(0, tag.into_series());
+        let variants = fields[1..]
+            .iter()
+            .map(|s| Field::new(s.name(), s.dtype().clone()))
+            .collect();
+        let entries = StructChunked::new(name, &fields)?;
+        // SAFETY: the variants are masked by the tag.
+        Ok(unsafe { entries.into_union_unchecked(variants) })
+    }
+
+    /// Convert an arrow union, dense or sparse, to a union.
+    pub(crate) fn from_arrow(
+        name: &str,
+        arr: &UnionArray,
+        fields: &[ArrowField],
+    ) -> PolarsResult<Self> {
+        let slots = (0..arr.len()).map(|i| arr.index(i)).collect::<Vec<_>>();
+        let mut tag = vec![None; arr.len()];
+        let mut variants = Vec::with_capacity(fields.len());
+        for (i, (values, field)) in arr.fields().iter().zip(fields).enumerate() {
+            // SAFETY: the dtype of the values is given by the field.
+            let values = unsafe {
+                Series::_try_from_arrow_unchecked_with_md(
+                    &field.name,
+                    vec![values.clone()],
+                    field.data_type(),
+                    Some(&field.metadata),
+                )?
+            };
+            let idx = slots
+                .iter()
+                .map(|&(t, slot)| (t == i).then_some(slot as IdxSize))
+                .collect::<Vec<_>>();
+            let values = values.take(&IdxCa::from_slice_options("", &idx))?;
+            // Null values make the row null.
+            for (row, valid) in values.is_not_null().into_iter().enumerate() {
+                if valid == Some(true) {
+                    tag[row] = Some(i as i8);
+                }
+            }
+            variants.push(values);
+        }
+        let tag = Int8Chunked::from_slice_options(UNION_TAG_NAME, &tag);
+        Self::from_tag_and_values(name, tag, &variants)
+    }
+
+    /// The tag and the variants as a struct.
+    pub fn physical(&self) -> &StructChunked {
+        &self.entries
+    }
+
+    pub fn variants(&self) -> &[Field] {
+        match &self.dtype {
+            DataType::Union(variants) => variants,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The index of the active variant in every row.
+    pub fn tag_index(&self) -> &Int8Chunked {
+        self.entries.fields()[0].i8().unwrap()
+    }
+
+    /// The name of the active variant in every row.
+    pub fn tag(&self) -> StringChunked {
+        let variants = self.variants();
+        let mut out: StringChunked = self
+            .tag_index()
+            .into_iter()
+            .map(|t| t.map(|t| variants[t as usize].name().as_str()))
+            .collect();
+        out.rename(self.name());
+        out
+    }
+
+    /// The values of the variant with the given name. These are null where another variant is
+    /// active.
+    pub fn variant(&self, name: &str) -> PolarsResult<Series> {
+        let idx = self
+            .variants()
+            .iter()
+            .position(|fld| fld.name() == name)
+            .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name))?;
+        Ok(self.entries.fields()[idx + 1].clone())
+    }
+
+    pub fn name(&self) -> &str {
+        self.entries.name()
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.entries.rename(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn entries_mut(&mut self) -> &mut StructChunked {
+        &mut self.entries
+    }
+
+    /// Convert chunk `i` to a sparse arrow union.
+    pub(crate) fn to_arrow(&self, i: usize, pl_flavor: bool) -> ArrayRef {
+        let arr = self.entries.to_arrow(i, pl_flavor);
+        let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+        let tag = arr.values()[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i8>>()
+            .unwrap();
+        // Arrow unions have no validity, null rows point to the (null) first variant.
+        let types: Buffer<i8> = tag.iter().map(|t| t.copied().unwrap_or(0)).collect();
+        Box::new(UnionArray::new(
+            self.dtype.to_arrow(pl_flavor),
+            types,
+            arr.values()[1..].to_vec(),
+            None,
+        ))
+    }
+
+    /// Take the active value of every row, cast to `dtype`.
+    fn collapse(&self, dtype: &DataType) -> PolarsResult<Series> {
+        let len = self.len();
+        let mut values = Series::new_empty(self.name(), dtype);
+        for s in &self.entries.fields()[1..] {
+            values.append(&s.cast(dtype)?)?;
+        }
+        let idx = self
+            .tag_index()
+            .into_iter()
+            .enumerate()
+            .map(|(row, t)| t.map(|t| (t as usize * len + row) as IdxSize))
+            .collect::<Vec<_>>();
+        values.take(&IdxCa::from_slice_options(self.name(), &idx))
+    }
+}
+
+impl LogicalType for UnionChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        unsafe { Ok(self.get_any_value_unchecked(i)) }
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.tag_index().get_unchecked(i) {
+            Some(t) => self.entries.fields()[t as usize + 1].get_unchecked(i),
+            None => AnyValue::Null,
+        }
+    }
+
+    /// Casting to a union with as many variants casts the variants in order, casting to the
+    /// physical dtype returns the entries and any other dtype takes the active value of every row.
+    fn cast(&self, dtype: &DataType) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Union(variants) if variants.len() == self.variants().len() => {
+                let mut fields = self.entries.fields().to_vec();
+                for (s, fld) in fields[1..].iter_mut().zip(variants) {
+                    *s = s.cast(fld.data_type())?;
+                    s.rename(fld.name());
+                }
+                let entries = StructChunked::new(self.name(), &fields)?;
+                // SAFETY: casting keeps the nulls of the variants.
+                Ok(unsafe { entries.into_union_unchecked(variants.clone()) }.into_series())
+            },
+            DataType::Union(_) => polars_bail!(
+                InvalidOperation: "cannot cast {} to {} with another number of variants", self.dtype, dtype
+            ),
+            dt if dt == &self.dtype.to_physical() => {
+                let entries = self.entries.clone().into_series();
+                Ok(entries.to_physical_repr().into_owned())
+            },
+            _ => self.collapse(dtype),
+        }
+    }
+}
@@ -53,8 +53,9 @@ fn midpoint_interpol<T: Float>(lower: T, upper: T) -> T {
     }
 }
 
+/// Compute the quantile of `vals`, which are reordered in place.
 // Uses quickselect instead of sorting all data
-fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
+pub fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     vals: &mut [T],
     quantile: f64,
     interpol: QuantileInterpolOptions,
@@ -70,6 +70,15 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::Array(s, *width)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = map_entries_dtype(key, value);
+            let s = Series::from_chunks_and_dtype_unchecked("", vec![v], &entries.to_physical())
+                .cast_unchecked(&entries)
+                .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(rev_map, _) => {
             let arr = &*(arr as *const dyn Array as *const UInt32Array);
@@ -87,6 +96,17 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let arr = &*(arr as *const dyn Array as *const StructArray);
             AnyValue::Struct(idx, arr, flds)
         },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(variants) => {
+            // the physical entries: the tag followed by the variants
+            let arr = &*(arr as *const dyn Array as *const StructArray);
+            let tag = &*(&*arr.values()[0] as *const dyn Array as *const Int8Array);
+            if tag.is_null(idx) {
+                return AnyValue::Null;
+            }
+            let t = tag.value_unchecked(idx) as usize;
+            arr_to_any_value(&*arr.values()[t + 1], idx, &variants[t].dtype)
+        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(tu, tz) => {
             let arr = &*(arr as *const dyn Array as *const Int64Array);
@@ -101,9 +101,13 @@ enum SerializableDataType {
     List(Box<SerializableDataType>),
     #[cfg(feature = "dtype-array")]
     Array(Box<SerializableDataType>, usize),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
     Null,
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown,
     #[cfg(feature = "dtype-categorical")]
@@ -140,10 +144,17 @@ impl From<&DataType> for SerializableDataType {
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
             Null => Self::Null,
             Unknown => Self::Unknown,
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds.clone()),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => Self::Union(variants.clone()),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, ordering) => Self::Categorical(None, *ordering),
             #[cfg(feature = "dtype-categorical")]
@@ -184,10 +195,14 @@ impl From<SerializableDataType> for DataType {
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
             Null => Self::Null,
             Unknown => Self::Unknown,
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => Self::Union(variants),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, ordering) => Self::Categorical(None, ordering),
             #[cfg(feature = "dtype-categorical")]
@@ -1,5 +1,8 @@
 use std::collections::BTreeMap;
 
+#[cfg(feature = "dtype-union")]
+use arrow::datatypes::UnionMode;
+
 use super::*;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::ObjectRegistry;
@@ -8,6 +11,9 @@ pub type TimeZone = String;
 
 pub static DTYPE_ENUM_KEY: &str = "POLARS.CATEGORICAL_TYPE";
 pub static DTYPE_ENUM_VALUE: &str = "ENUM";
+/// Marks a struct in formats without unions, e.g. parquet, as the physical layout of a union.
+pub static DTYPE_UNION_KEY: &str = "POLARS.STRUCT_TYPE";
+pub static DTYPE_UNION_VALUE: &str = "UNION";
 
 #[derive(Clone, Debug, Default)]
 pub enum DataType {
@@ -45,6 +51,9 @@ pub enum DataType {
     Array(Box<DataType>, usize),
     /// A nested list with a variable size in each row
     List(Box<DataType>),
+    /// Key-value pairs in each row, stored as a list of `{key, value}` structs
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
     #[cfg(feature = "object")]
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
@@ -58,6 +67,9 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A value of one of the variants in each row
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
     #[default]
     Unknown,
@@ -93,6 +105,12 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
+                #[cfg(feature = "dtype-union")]
+                (Union(lhs), Union(rhs)) => lhs == rhs,
                 _ => std::mem::discriminant(self) == std::mem::discriminant(other),
             }
         }
@@ -135,8 +153,12 @@ impl DataType {
     pub fn is_known(&self) -> bool {
         match self {
             DataType::List(inner) => inner.is_known(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => variants.iter().all(|fld| fld.dtype.is_known()),
             DataType::Unknown => false,
             _ => true,
         }
@@ -166,6 +188,8 @@ impl DataType {
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Array(Box::new(dt.to_physical()), *width),
             List(dt) => List(Box::new(dt.to_physical())),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => List(Box::new(map_entries_dtype(key, value).to_physical())),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let new_fields = fields
@@ -174,6 +198,8 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => union_entries_dtype(variants).to_physical(),
             _ => self.clone(),
         }
     }
@@ -222,8 +248,12 @@ impl DataType {
             List(inner) => inner.contains_views(),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_views(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => variants.iter().any(|field| field.dtype.contains_views()),
             _ => false,
         }
     }
@@ -375,6 +405,18 @@ impl DataType {
             List(dt) => Ok(ArrowDataType::LargeList(Box::new(
                 dt.to_arrow_field("item", pl_flavor),
             ))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Arrow requires the entries and the keys to be non-nullable.
+                let entries = ArrowDataType::Struct(vec![
+                    ArrowField::new(MAP_KEY_NAME, key.try_to_arrow(pl_flavor)?, false),
+                    value.to_arrow_field(MAP_VALUE_NAME, pl_flavor),
+                ]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new("entries", entries, false)),
+                    false,
+                ))
+            },
             Null => Ok(ArrowDataType::Null),
             #[cfg(feature = "object")]
             Object(_, _) => {
@@ -398,6 +440,11 @@ impl DataType {
                 let fields = fields.iter().map(|fld| fld.to_arrow(pl_flavor)).collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => {
+                let fields = variants.iter().map(|fld| fld.to_arrow(pl_flavor)).collect();
+                Ok(ArrowDataType::Union(fields, None, UnionMode::Sparse))
+            },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown => {
                 polars_bail!(InvalidOperation: "cannot convert Unknown dtype data to Arrow")
@@ -412,6 +459,8 @@ impl DataType {
             List(field) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => variants.iter().all(|fld| fld.dtype.is_nested_null()),
             _ => false,
         }
     }
@@ -464,6 +513,8 @@ impl Display for DataType {
             #[cfg(feature = "dtype-array")]
             DataType::Array(tp, size) => return write!(f, "array[{tp}, {size}]"),
             DataType::List(tp) => return write!(f, "list[{tp}]"),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
             #[cfg(feature = "object")]
             DataType::Object(s, _) => s,
             #[cfg(feature = "dtype-categorical")]
@@ -472,6 +523,14 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|fld| fld.dtype.to_string())
+                    .collect::<Vec<_>>();
+                return write!(f, "union[{}]", variants.join(", "));
+            },
             DataType::Unknown => "unknown",
             DataType::BinaryOffset => "binary[offset]",
         };
@@ -136,6 +136,18 @@ impl DataType {
             #[cfg(feature = "dtype-array")]
             ArrowDataType::FixedSizeList(f, size) => DataType::Array(DataType::from_arrow(f.data_type(), bin_to_view).boxed(), *size),
             ArrowDataType::LargeList(f) | ArrowDataType::List(f) => DataType::List(DataType::from_arrow(f.data_type(), bin_to_view).boxed()),
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(f, _) => match f.data_type() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    DataType::from_arrow(fields[0].data_type(), bin_to_view).boxed(),
+                    DataType::from_arrow(fields[1].data_type(), bin_to_view).boxed(),
+                ),
+                // Not a valid map, read it as a list of its entries.
+                dt => DataType::List(DataType::from_arrow(dt, bin_to_view).boxed()),
+            },
+            // Without the map dtype, maps are read as a list of their entries.
+            #[cfg(not(feature = "dtype-map"))]
+            ArrowDataType::Map(f, _) => DataType::List(DataType::from_arrow(f.data_type(), bin_to_view).boxed()),
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => DataType::Datetime(tu.into(), DataType::canonical_timezone(tz)),
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
@@ -151,6 +163,10 @@ impl DataType {
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
             }
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(fields, _, _) => {
+                DataType::Union(fields.iter().map(|fld| fld.into()).collect())
+            }
             ArrowDataType::Extension(name, _, _) if name == "POLARS_EXTENSION_TYPE" => {
                 #[cfg(feature = "object")]
                 {
@@ -186,6 +202,14 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
+        #[cfg(feature = "dtype-union")]
+        if let ArrowDataType::Struct(fields) = f.data_type() {
+            // Formats without unions store them as their tagged struct.
+            if f.metadata.get(DTYPE_UNION_KEY).map(|s| s.as_str()) == Some(DTYPE_UNION_VALUE) {
+                let variants = fields[1..].iter().map(|fld| fld.into()).collect();
+                return Field::new(&f.name, DataType::Union(variants));
+            }
+        }
         Field::new(&f.name, f.data_type().into())
     }
 }
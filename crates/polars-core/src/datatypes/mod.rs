@@ -156,6 +156,8 @@ impl_polars_datatype!(StringType, String, Utf8ViewArray, 'a, &'a str, Option<&'a
 impl_polars_datatype!(BinaryType, Binary, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>);
 impl_polars_datatype!(BinaryOffsetType, BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>);
 impl_polars_datatype!(BooleanType, Boolean, BooleanArray, 'a, bool, bool);
+#[cfg(feature = "dtype-map")]
+impl_polars_datatype!(MapType, Unknown, ListArray<i64>, 'a, Box<dyn Array>, Option<Box<dyn Array>>);
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListType {}
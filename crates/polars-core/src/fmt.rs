@@ -334,6 +334,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_, _) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -357,6 +362,14 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            #[cfg(feature = "dtype-union")]
+            dt @ DataType::Union(_) => format_array!(
+                f,
+                self.union().unwrap(),
+                format!("{dt}"),
+                self.name(),
+                "Series"
+            ),
             DataType::Null => {
                 format_array!(f, self.null().unwrap(), "null", self.name(), "Series")
             },
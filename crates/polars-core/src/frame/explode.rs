@@ -16,6 +16,8 @@ fn get_exploded(series: &Series) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
         DataType::List(_) => series.list().unwrap().explode_and_offsets(),
         #[cfg(feature = "dtype-array")]
         DataType::Array(_, _) => series.array().unwrap().explode_and_offsets(),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => series.map().unwrap().physical().explode_and_offsets(),
         _ => polars_bail!(opq = explode, series.dtype()),
     }
 }
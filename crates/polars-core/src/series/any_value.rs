@@ -256,6 +256,54 @@ impl Series {
                 any_values_to_decimal(av, *precision, *scale)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(av, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                any_values_to_list(av, &map_entries_dtype(key, value), strict)?.cast(dtype)?
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => {
+                // every value goes to the first variant of its dtype, or that it can be cast to
+                let tag = av
+                    .iter()
+                    .map(|av| {
+                        if av.is_null() {
+                            return Ok(None);
+                        }
+                        let av_dtype = av.dtype();
+                        let idx = variants.iter().position(|fld| fld.data_type() == &av_dtype);
+                        match idx {
+                            Some(idx) => Ok(Some(idx)),
+                            None if !strict => Ok(variants
+                                .iter()
+                                .position(|fld| av.strict_cast(fld.data_type()).is_ok())),
+                            None => polars_bail!(
+                                SchemaMismatch: "no variant of {} matches a value of dtype {}", dtype, av_dtype
+                            ),
+                        }
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let columns = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, fld)| {
+                        let values = av
+                            .iter()
+                            .zip(&tag)
+                            .map(|(av, t)| match t {
+                                Some(t) if *t == i => av.clone(),
+                                _ => AnyValue::Null,
+                            })
+                            .collect::<Vec<_>>();
+                        Series::from_any_values_and_dtype(
+                            fld.name(),
+                            &values,
+                            fld.data_type(),
+                            strict,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                UnionChunked::from_variants(name, &columns)?.into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(av, inner, strict, *size)?
                 .into_series()
@@ -82,6 +82,13 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = DataType::List(Box::new(map_entries_dtype(key, value)));
+                ListChunked::from_chunks_and_dtype_unchecked(name, chunks, entries)
+                    .into_map_unchecked(*key.clone(), *value.clone())
+                    .into_series()
+            },
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -104,6 +111,16 @@ impl Series {
             Struct(_) => {
                 Series::_try_from_arrow_unchecked(name, chunks, &dtype.to_arrow(true)).unwrap()
             },
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => {
+                let entries = union_entries_dtype(variants).to_arrow(true);
+                let s = Series::_try_from_arrow_unchecked(name, chunks, &entries).unwrap();
+                s.struct_()
+                    .unwrap()
+                    .clone()
+                    .into_union_unchecked(variants.clone())
+                    .into_series()
+            },
             #[cfg(feature = "object")]
             Object(_, _) => {
                 assert_eq!(chunks.len(), 1);
@@ -438,7 +455,35 @@ impl Series {
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
-                Ok(StructChunked::new_unchecked(name, &fields).into_series())
+                let ca = StructChunked::new_unchecked(name, &fields);
+                #[cfg(feature = "dtype-union")]
+                if md
+                    .and_then(|md| md.get(DTYPE_UNION_KEY))
+                    .map(|s| s.as_str())
+                    == Some(DTYPE_UNION_VALUE)
+                {
+                    // A union that was written as its tagged struct.
+                    let variants = fields[1..].iter().map(|s| s.field().into_owned()).collect();
+                    return Ok(ca.into_union_unchecked(variants).into_series());
+                }
+                Ok(ca.into_series())
+            },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(fields, _, _) => {
+                let mut out: Option<Series> = None;
+                for arr in &chunks {
+                    let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+                    let s = UnionChunked::from_arrow(name, arr, fields)?.into_series();
+                    match out.as_mut() {
+                        Some(out) => {
+                            out.append(&s)?;
+                        },
+                        None => out = Some(s),
+                    }
+                }
+                Ok(out.unwrap_or_else(|| {
+                    Series::full_null(name, 0, &DataType::from_arrow(dtype, true))
+                }))
             },
             ArrowDataType::FixedSizeBinary(_) => {
                 if verbose() {
@@ -525,7 +570,15 @@ fn map_arrays_to_series(name: &str, chunks: Vec<ArrayRef>) -> PolarsResult<Serie
             )) as ArrayRef
         })
         .collect::<Vec<_>>();
-    Series::try_from((name, chunks))
+    let s = Series::try_from((name, chunks))?;
+    #[cfg(feature = "dtype-map")]
+    {
+        s.list()?.clone().into_map().map(|ca| ca.into_series())
+    }
+    #[cfg(not(feature = "dtype-map"))]
+    {
+        Ok(s)
+    }
 }
 
 fn convert<F: Fn(&dyn Array) -> ArrayRef>(arr: &[ArrayRef], f: F) -> Vec<ArrayRef> {
@@ -666,7 +719,15 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
-        | ArrowDataType::Date64) => {
+        | ArrowDataType::Date64
+        | ArrowDataType::Map(_, _)) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked("", arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        dt @ ArrowDataType::Union(_, _, _) => {
             let dt = dt.clone();
             let mut s = Series::_try_from_arrow_unchecked("", arrays, &dt).unwrap();
             let dtype = s.dtype().clone();
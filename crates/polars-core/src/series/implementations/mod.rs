@@ -19,12 +19,16 @@ mod decimal;
 mod duration;
 mod floats;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
 mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -0,0 +1,239 @@
+use super::*;
+use crate::prelude::*;
+use crate::series::private::PrivateSeriesNumeric;
+
+unsafe impl IntoSeries for UnionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl PrivateSeriesNumeric for SeriesWrap<UnionChunked> {}
+
+impl SeriesWrap<UnionChunked> {
+    fn entries(&self) -> Series {
+        self.0.physical().clone().into_series()
+    }
+
+    fn finish(&self, entries: Series) -> Series {
+        let entries = entries.struct_().unwrap().clone();
+        // SAFETY: the entries come from this union.
+        unsafe { entries.into_union_unchecked(self.0.variants().to_vec()) }.into_series()
+    }
+
+    fn apply_physical<F: Fn(&Series) -> Series>(&self, f: F) -> Series {
+        self.finish(f(&self.entries()))
+    }
+
+    fn try_apply_physical<F: Fn(&Series) -> PolarsResult<Series>>(
+        &self,
+        f: F,
+    ) -> PolarsResult<Series> {
+        Ok(self.finish(f(&self.entries())?))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<UnionChunked> {
+    fn compute_len(&mut self) {
+        for s in self.0.entries_mut().fields_mut() {
+            s._get_inner_mut().compute_len();
+        }
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(Field::new(self.0.name(), self.0.dtype().clone()))
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    #[allow(unused)]
+    fn _set_flags(&mut self, flags: Settings) {}
+    fn _get_flags(&self) -> Settings {
+        Settings::empty()
+    }
+    fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
+        self.apply_physical(|s| s.explode_by_offsets(offsets))
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.union().unwrap().physical().clone().into_series();
+        self.entries().equal_element(idx_self, idx_other, &other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.union()?.physical().clone().into_series();
+        self.try_apply_physical(|s| s.zip_with_same_type(mask, &other))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        // the lists hold the entries, only the inner dtype has to be restored
+        let list = self.entries().agg_list(groups);
+        let dtype = DataType::List(Box::new(self.dtype().clone()));
+        Series::from_chunks_and_dtype_unchecked(list.name(), list.chunks().clone(), &dtype)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.entries().group_tuples(multithreaded, sorted)
+    }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.entries().vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        self.entries().vec_hash_combine(build_hasher, hashes)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<UnionChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name)
+    }
+
+    fn has_validity(&self) -> bool {
+        self.0.tag_index().has_validity()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.tag_index().chunk_id()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.entries_mut().chunks_mut()
+    }
+
+    fn n_chunks(&self) -> usize {
+        self.0.tag_index().chunks().len()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical(|s| s.slice(offset, length))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut entries = self.entries();
+        entries.append(&other.union()?.physical().clone().into_series())?;
+        self.0 = self.finish(entries).union()?.clone();
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let mut entries = self.entries();
+        entries.extend(&other.union()?.physical().clone().into_series())?;
+        self.0 = self.finish(entries).union()?.clone();
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_apply_physical(|s| s.filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_apply_physical(|s| s.take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.apply_physical(|s| s.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_apply_physical(|s| s.take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.apply_physical(|s| s.take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.apply_physical(|s| s.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_physical(|s| s.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType) -> PolarsResult<Series> {
+        self.0.cast(dtype)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    /// A row is null if no variant is active.
+    fn null_count(&self) -> usize {
+        self.0.tag_index().null_count()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.try_apply_physical(|s| s.unique())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.entries().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.entries().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.tag_index().is_null().with_name(self.name())
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.tag_index().is_not_null().with_name(self.name())
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.entries_mut().fields_mut().iter_mut().for_each(|s| {
+            s.shrink_to_fit();
+        });
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical(|s| s.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn sort_with(&self, options: SortOptions) -> Series {
+        self.apply_physical(|s| s.sort_with(options))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.entries().arg_sort(options)
+    }
+}
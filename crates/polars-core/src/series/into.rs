@@ -24,6 +24,8 @@ impl Series {
             // make sure that we recursively apply all logical types.
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => self.struct_().unwrap().to_arrow(chunk_idx, pl_flavor),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_) => self.union().unwrap().to_arrow(chunk_idx, pl_flavor),
             // special list branch to
             // make sure that we recursively apply all logical types.
             DataType::List(inner) => {
@@ -57,6 +59,29 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let ca = self.map().unwrap();
+                let entries = ca.physical().clone().into_series();
+                let arr = entries.to_arrow(chunk_idx, pl_flavor);
+                let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+
+                // The entries of an arrow map are not nullable.
+                let data_type = self.dtype().to_arrow(pl_flavor);
+                let ArrowDataType::Map(field, _) = &data_type else {
+                    unreachable!()
+                };
+                let values =
+                    StructArray::new(field.data_type().clone(), values.values().to_vec(), None);
+                let arr = MapArray::new(
+                    data_type,
+                    arr.offsets().try_into().unwrap(),
+                    values.boxed(),
+                    arr.validity().cloned(),
+                );
+                Box::new(arr)
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, ordering) | DataType::Enum(_, ordering)) => {
                 let ca = self.categorical().unwrap();
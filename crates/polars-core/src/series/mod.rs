@@ -304,6 +304,12 @@ impl Series {
         if !dtype.is_known() || (dtype.is_primitive() && dtype == self.dtype()) {
             return Ok(self.clone());
         }
+        #[cfg(feature = "dtype-union")]
+        if let DataType::Union(variants) = dtype {
+            if !matches!(self.dtype(), DataType::Union(_)) {
+                return UnionChunked::from_series(self, variants).map(|ca| ca.into_series());
+            }
+        }
         let ret = self.0.cast(dtype);
         let len = self.len();
         if self.null_count() == len {
@@ -380,6 +386,8 @@ impl Series {
             DataType::List(_) => self.list().unwrap().explode(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => self.array().unwrap().explode(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map().unwrap().physical().explode(),
             _ => Ok(self.clone()),
         }
     }
@@ -450,6 +458,8 @@ impl Series {
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => Cow::Owned(self.cast(&UInt32).unwrap()),
             List(inner) => Cow::Owned(self.cast(&List(Box::new(inner.to_physical()))).unwrap()),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => Cow::Owned(self.cast(&self.dtype().to_physical()).unwrap()),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
                 let arr = self.struct_().unwrap();
@@ -461,6 +471,11 @@ impl Series {
                 let ca = StructChunked::new(self.name(), &fields).unwrap();
                 Cow::Owned(ca.into_series())
             },
+            #[cfg(feature = "dtype-union")]
+            Union(_) => {
+                let entries = self.union().unwrap().physical().clone().into_series();
+                Cow::Owned(entries.to_physical_repr().into_owned())
+            },
             _ => Cow::Borrowed(self),
         }
     }
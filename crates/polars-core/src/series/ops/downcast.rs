@@ -148,6 +148,12 @@ impl Series {
         unpack_chunked!(self, DataType::Array(_, _) => ArrayChunked, "FixedSizeList")
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype `[DataType::Map]`
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        unpack_chunked!(self, DataType::Map(_, _) => MapChunked, "Map")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Categorical]`
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
@@ -167,6 +173,12 @@ impl Series {
         unpack_chunked!(self, DataType::Struct(_) => StructChunked, "Struct")
     }
 
+    /// Unpack to [`UnionChunked`] of dtype `[DataType::Union]`
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        unpack_chunked!(self, DataType::Union(_) => UnionChunked, "Union")
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype `[DataType::Null]`
     pub fn null(&self) -> PolarsResult<&NullChunked> {
         unpack_chunked!(self, DataType::Null => NullChunked, "Null")
@@ -11,6 +11,13 @@ impl Series {
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key, value);
+                let ca = ListChunked::full_null_with_dtype(name, size, &entries);
+                // SAFETY: the entries have the dtype of the map.
+                unsafe { ca.into_map_unchecked(*key.clone(), *value.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(rev_map, _) | DataType::Enum(rev_map, _)) => {
                 let mut ca =
@@ -49,6 +56,13 @@ impl Series {
                     .collect::<Vec<_>>();
                 StructChunked::new(name, &fields).unwrap().into_series()
             },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => {
+                let entries = Series::full_null(name, size, &union_entries_dtype(variants));
+                let entries = entries.struct_().unwrap().clone();
+                // SAFETY: all variants are null.
+                unsafe { entries.into_union_unchecked(variants.clone()) }.into_series()
+            },
             DataType::Null => Series::new_null(name, size),
             _ => {
                 macro_rules! primitive {
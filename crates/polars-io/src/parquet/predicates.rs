@@ -1,7 +1,7 @@
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{get_field_columns, FileMetaData, RowGroupMetaData};
 
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 
@@ -59,3 +59,114 @@ pub(super) fn read_this_row_group(
     }
     Ok(true)
 }
+
+/// Summary of a column over all row groups of a file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnSummary {
+    pub null_count: Option<usize>,
+    /// The largest distinct count the writer stored for a row group, a lower bound for the
+    /// file.
+    pub distinct_count: Option<usize>,
+    /// Size of the uncompressed column data.
+    pub uncompressed_size: usize,
+}
+
+/// Summarize the columns of `schema` from the metadata of a file.
+pub fn column_summaries(
+    md: &FileMetaData,
+    schema: &ArrowSchema,
+) -> PolarsResult<Vec<ColumnSummary>> {
+    schema
+        .fields
+        .iter()
+        .map(|field| {
+            let mut summary = ColumnSummary {
+                null_count: Some(0),
+                ..Default::default()
+            };
+            for rg in &md.row_groups {
+                summary.uncompressed_size += get_field_columns(rg.columns(), &field.name)
+                    .iter()
+                    .map(|column| column.uncompressed_size() as usize)
+                    .sum::<usize>();
+
+                let stats = deserialize(field, rg)?;
+                let distinct_count = Series::try_from(("", stats.distinct_count.clone()))
+                    .ok()
+                    .and_then(|s| s.max::<usize>().ok().flatten());
+                summary.distinct_count = summary.distinct_count.max(distinct_count);
+                let null_count = ColumnStats::from_arrow_stats(stats, field).null_count();
+                summary.null_count = summary.null_count.zip(null_count).map(|(a, b)| a + b);
+            }
+            Ok(summary)
+        })
+        .collect()
+}
+
+/// The column the file is sorted by, according to the `sorting_columns` the writer declared.
+///
+/// Returns the name, whether it is sorted descending and whether the nulls come first. A writer
+/// declares the order within a row group, so the statistics must show that the row groups are
+/// in order as well.
+pub fn sorted_column(
+    md: &FileMetaData,
+    schema: &ArrowSchema,
+) -> PolarsResult<Option<(String, bool, bool)>> {
+    let mut sorting = None;
+    for rg in &md.row_groups {
+        let Some(first) = rg.sorting_columns().and_then(|columns| columns.first()) else {
+            return Ok(None);
+        };
+        match &sorting {
+            None => sorting = Some(first.clone()),
+            Some(sorting) if sorting == first => {},
+            _ => return Ok(None),
+        }
+    }
+    let Some(sorting) = sorting else {
+        return Ok(None);
+    };
+    // Only top level columns.
+    let Some(descriptor) = md.schema_descr.columns().get(sorting.column_idx as usize) else {
+        return Ok(None);
+    };
+    let [name] = descriptor.path_in_schema.as_slice() else {
+        return Ok(None);
+    };
+    let Some(field) = schema.fields.iter().find(|field| &field.name == name) else {
+        return Ok(None);
+    };
+
+    if md.row_groups.len() > 1 {
+        let mut prev_bound: Option<Series> = None;
+        for rg in &md.row_groups {
+            let stats = ColumnStats::from_arrow_stats(deserialize(field, rg)?, field);
+            let (Some(0), Some(min), Some(max)) =
+                (stats.null_count(), stats.to_min(), stats.to_max())
+            else {
+                return Ok(None);
+            };
+            let (first, last) = if sorting.descending {
+                (max, min)
+            } else {
+                (min, max)
+            };
+            if let Some(prev) = &prev_bound {
+                let in_order = if sorting.descending {
+                    prev.gt_eq(first)?
+                } else {
+                    prev.lt_eq(first)?
+                };
+                if !in_order.all() {
+                    return Ok(None);
+                }
+            }
+            prev_bound = Some(last.clone());
+        }
+    }
+    Ok(Some((
+        name.clone(),
+        sorting.descending,
+        sorting.nulls_first,
+    )))
+}
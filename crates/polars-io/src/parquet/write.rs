@@ -3,6 +3,8 @@ use std::io::Write;
 
 use arrow::array::Array;
 use arrow::chunk::Chunk;
+#[cfg(feature = "dtype-union")]
+use arrow::datatypes::Metadata;
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df_as_ref};
@@ -169,7 +171,10 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let fields = schema.to_arrow(true).fields;
+        let fields = schema
+            .iter()
+            .map(|(name, dtype)| to_parquet_arrow_field(name, dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
         let schema = ArrowSchema::from(fields);
 
         let parquet_schema = to_parquet_schema(&schema)?;
@@ -236,6 +241,59 @@ fn prepare_rg_iter<'a>(
     })
 }
 
+/// Parquet has no unions, so top-level unions are written as their tagged struct.
+fn to_parquet_arrow_field(name: &str, dtype: &DataType) -> PolarsResult<ArrowField> {
+    #[cfg(feature = "dtype-union")]
+    if let DataType::Union(variants) = dtype {
+        let metadata =
+            Metadata::from([(DTYPE_UNION_KEY.to_string(), DTYPE_UNION_VALUE.to_string())]);
+        let field = ArrowField::new(name, union_entries_dtype(variants).to_arrow(true), true)
+            .with_metadata(metadata);
+        ensure_no_union(&field.data_type)?;
+        return Ok(field);
+    }
+    let field = dtype.to_arrow_field(name, true);
+    #[cfg(feature = "dtype-union")]
+    ensure_no_union(&field.data_type)?;
+    Ok(field)
+}
+
+#[cfg(feature = "dtype-union")]
+fn ensure_no_union(data_type: &ArrowDataType) -> PolarsResult<()> {
+    match data_type {
+        ArrowDataType::Union(_, _, _) => {
+            polars_bail!(ComputeError: "writing nested unions to parquet is not supported")
+        },
+        ArrowDataType::List(field)
+        | ArrowDataType::LargeList(field)
+        | ArrowDataType::FixedSizeList(field, _)
+        | ArrowDataType::Map(field, _) => ensure_no_union(&field.data_type),
+        ArrowDataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|field| ensure_no_union(&field.data_type)),
+        _ => Ok(()),
+    }
+}
+
+/// Replace the unions by their tagged struct, see [`to_parquet_arrow_field`].
+#[cfg(feature = "dtype-union")]
+fn unions_to_physical(df: &DataFrame) -> Cow<DataFrame> {
+    let is_union = |s: &Series| matches!(s.dtype(), DataType::Union(_));
+    if !df.get_columns().iter().any(is_union) {
+        return Cow::Borrowed(df);
+    }
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Union(_) => s.union().unwrap().physical().clone().into_series(),
+            _ => s.clone(),
+        })
+        .collect();
+    // SAFETY: the columns keep their names and lengths.
+    Cow::Owned(unsafe { DataFrame::new_no_checks(columns) })
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
@@ -278,6 +336,10 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        #[cfg(feature = "dtype-union")]
+        let df = unions_to_physical(df);
+        #[cfg(feature = "dtype-union")]
+        let df = df.as_ref();
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
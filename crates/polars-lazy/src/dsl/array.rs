@@ -0,0 +1,87 @@
+use polars_core::prelude::*;
+use polars_plan::dsl::*;
+
+use crate::dsl::eval::eval_field_to_dtype;
+use crate::dsl::list::{evaluate_on_groups, is_user_apply, run_per_sublist, validate_eval_expr};
+
+/// The dtype of evaluating an expression that keeps the number of elements on the arrays of `f`.
+fn eval_array_field_to_dtype(f: &Field, expr: &Expr) -> Field {
+    let out = eval_field_to_dtype(f, expr, false);
+    match f.data_type() {
+        DataType::Array(_, width) => {
+            Field::new(f.name(), DataType::Array(Box::new(out.dtype), *width))
+        },
+        _ => Field::new(f.name(), DataType::List(Box::new(out.dtype))),
+    }
+}
+
+/// Evaluate `expr` on the elements of every sub-array at once. As the arrays have a fixed width,
+/// the groups are slices of `width` elements over the inner values.
+fn run_on_group_by_engine(
+    name: &str,
+    arr: &ArrayChunked,
+    expr: &Expr,
+) -> PolarsResult<Option<Series>> {
+    let width = arr.width() as IdxSize;
+    let groups = (0..arr.len() as IdxSize)
+        .map(|row| [row * width, width])
+        .collect();
+    let groups = GroupsProxy::Slice {
+        groups,
+        rolling: false,
+    };
+    // The elements are referred to as `col("")`.
+    evaluate_on_groups(name, arr.get_inner().with_name(""), &groups, expr)
+}
+
+pub trait ArrayNameSpaceExtension: Sized {
+    /// Run any [`Expr`] on the elements of these arrays. The expression should keep the number
+    /// of elements, so that the result is an array of the same width.
+    fn eval(self, expr: Expr, parallel: bool) -> Expr;
+}
+
+impl ArrayNameSpaceExtension for ArrayNameSpace {
+    fn eval(self, expr: Expr, parallel: bool) -> Expr {
+        let expr2 = expr.clone();
+        let func = move |s: Series| {
+            validate_eval_expr(&expr, "arr.eval")?;
+            let arr = s.array()?.clone();
+            let width = arr.width();
+
+            // # fast returns
+            // ensure we get the new schema
+            let output_field = eval_array_field_to_dtype(arr.ref_field(), &expr);
+            if arr.is_empty() {
+                return Ok(Some(Series::new_empty(s.name(), output_field.data_type())));
+            }
+            if arr.null_count() == arr.len() {
+                return Ok(Some(s.cast(output_field.data_type())?));
+            }
+
+            let fits_idx_size = arr.len() * width <= (IdxSize::MAX as usize);
+            let out = if fits_idx_size && s.null_count() == 0 && !is_user_apply(&expr) {
+                run_on_group_by_engine(s.name(), &arr, &expr)?
+            } else {
+                let lst = s.cast(&DataType::List(Box::new(arr.inner_dtype())))?;
+                let list_field = eval_field_to_dtype(arr.ref_field(), &expr, true);
+                run_per_sublist(s, lst.list()?, &expr, parallel, list_field)?
+            };
+            out.map(|out| {
+                out.cast(output_field.data_type()).map_err(|_| {
+                    polars_err!(
+                        ComputeError: "the expression in `arr.eval` should keep the {} elements of every array; \
+                        consider using `list.eval` instead", width
+                    )
+                })
+            })
+            .transpose()
+        };
+
+        self.0
+            .map(
+                func,
+                GetOutput::map_field(move |f| eval_array_field_to_dtype(f, &expr2)),
+            )
+            .with_fmt("arr.eval")
+    }
+}
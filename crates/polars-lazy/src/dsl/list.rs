@@ -43,7 +43,7 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupsProxy> {
     })
 }
 
-fn run_per_sublist(
+pub(super) fn run_per_sublist(
     s: Series,
     lst: &ListChunked,
     expr: &Expr,
@@ -124,11 +124,22 @@ fn run_on_group_by_engine(
     // Invariant in List means values physicals can be cast to inner dtype
     let values = unsafe { values.cast_unchecked(&inner_dtype).unwrap() };
 
+    evaluate_on_groups(name, values, &groups, expr)
+}
+
+/// Evaluate `expr` on the `groups` of the elements in `values`, aggregated into lists.
+pub(super) fn evaluate_on_groups(
+    name: &str,
+    values: Series,
+    groups: &GroupsProxy,
+    expr: &Expr,
+) -> PolarsResult<Option<Series>> {
+    let inner_dtype = values.dtype().clone();
     let df_context = values.into_frame();
     let phys_expr = prepare_expression_for_context("", expr, &inner_dtype, Context::Aggregation)?;
 
     let state = ExecutionState::new();
-    let mut ac = phys_expr.evaluate_on_groups(&df_context, &groups, &state)?;
+    let mut ac = phys_expr.evaluate_on_groups(&df_context, groups, &state)?;
     let out = match ac.agg_state() {
         AggState::AggregatedScalar(_) | AggState::Literal(_) => {
             let out = ac.aggregated();
@@ -139,6 +150,40 @@ fn run_on_group_by_engine(
     Ok(Some(out.with_name(name)))
 }
 
+/// Check that `expr` only refers to the elements it is evaluated on in `function`.
+pub(super) fn validate_eval_expr(expr: &Expr, function: &str) -> PolarsResult<()> {
+    for e in expr.into_iter() {
+        match e {
+            #[cfg(feature = "dtype-categorical")]
+            Expr::Cast {
+                data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
+                ..
+            } => {
+                polars_bail!(
+                    ComputeError: "casting to categorical not allowed in `{}`", function
+                )
+            },
+            Expr::Column(name) => {
+                polars_ensure!(
+                    name.is_empty(),
+                    ComputeError:
+                    "named columns are not allowed in `{}`; consider using `element` or `col(\"\")`",
+                    function
+                );
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+/// Whether `expr` contains a user function with a custom return type.
+pub(super) fn is_user_apply(expr: &Expr) -> bool {
+    expr.into_iter().any(
+        |e| matches!(e, Expr::AnonymousFunction { options, .. } if options.fmt_str == MAP_LIST_NAME),
+    )
+}
+
 pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
     /// Run any [`Expr`] on these lists elements
     fn eval(self, expr: Expr, parallel: bool) -> Expr {
@@ -146,27 +191,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
 
         let expr2 = expr.clone();
         let func = move |s: Series| {
-            for e in expr.into_iter() {
-                match e {
-                    #[cfg(feature = "dtype-categorical")]
-                    Expr::Cast {
-                        data_type: DataType::Categorical(_, _) | DataType::Enum(_, _),
-                        ..
-                    } => {
-                        polars_bail!(
-                            ComputeError: "casting to categorical not allowed in `list.eval`"
-                        )
-                    },
-                    Expr::Column(name) => {
-                        polars_ensure!(
-                            name.is_empty(),
-                            ComputeError:
-                            "named columns are not allowed in `list.eval`; consider using `element` or `col(\"\")`"
-                        );
-                    },
-                    _ => {},
-                }
-            }
+            validate_eval_expr(&expr, "list.eval")?;
             let lst = s.list()?.clone();
 
             // # fast returns
@@ -182,11 +207,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             let fits_idx_size = lst.get_values_size() <= (IdxSize::MAX as usize);
             // If a users passes a return type to `apply`, e.g. `return_dtype=pl.Int64`,
             // this fails as the list builder expects `List<Int64>`, so let's skip that for now.
-            let is_user_apply = || {
-                expr.into_iter().any(|e| matches!(e, Expr::AnonymousFunction { options, .. } if options.fmt_str == MAP_LIST_NAME))
-            };
-
-            if fits_idx_size && s.null_count() == 0 && !is_user_apply() {
+            if fits_idx_size && s.null_count() == 0 && !is_user_apply(&expr) {
                 run_on_group_by_engine(s.name(), &lst, &expr)
             } else {
                 run_per_sublist(s, &lst, &expr, parallel, output_field)
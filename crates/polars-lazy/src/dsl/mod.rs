@@ -32,6 +32,8 @@
 //! These kinds of invalid operations will only yield an error at runtime, when
 //! [`collect`](crate::frame::LazyFrame::collect) is called on the [`LazyFrame`].
 
+#[cfg(all(feature = "list_eval", feature = "dtype-array"))]
+mod array;
 #[cfg(any(feature = "cumulative_eval", feature = "list_eval"))]
 mod eval;
 pub mod functions;
@@ -39,6 +41,8 @@ mod into;
 #[cfg(feature = "list_eval")]
 mod list;
 
+#[cfg(all(feature = "list_eval", feature = "dtype-array"))]
+pub use array::*;
 #[cfg(any(feature = "cumulative_eval", feature = "list_eval"))]
 pub use eval::*;
 pub use functions::*;
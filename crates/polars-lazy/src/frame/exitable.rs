@@ -3,10 +3,86 @@ use std::sync::mpsc::{channel, Receiver};
 use std::sync::Mutex;
 
 use polars_core::POOL;
+#[cfg(feature = "streaming")]
+use polars_pipe::pipeline::{ProgressCallback, ProgressTracker, StreamingProgress};
 
 use super::*;
 
+/// Token that can be used to cancel a running query from another thread.
+///
+/// The query stops at the next node or streaming batch and returns an error.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the query at earliest convenience.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Hooks into the execution of a query, see [`LazyFrame::collect_with_hooks`].
+#[derive(Clone, Default)]
+pub struct CollectHooks {
+    cancel: Option<CancellationToken>,
+    #[cfg(feature = "streaming")]
+    progress: Option<ProgressCallback>,
+}
+
+impl CollectHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop the query once `token` is cancelled.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Report the rows read and emitted by the streaming engine to `callback`.
+    ///
+    /// The callback is called after every batch, so it should be cheap.
+    #[cfg(feature = "streaming")]
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&StreamingProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
+
 impl LazyFrame {
+    /// Execute the query like [`LazyFrame::collect`], with cancellation and progress
+    /// reporting.
+    ///
+    /// If the token in `hooks` is cancelled, the query returns an error at the next
+    /// node or streaming batch. Progress is only reported for the parts of the query
+    /// that run in the streaming engine.
+    pub fn collect_with_hooks(self, hooks: CollectHooks) -> PolarsResult<DataFrame> {
+        let mut state = ExecutionState::new();
+        if let Some(token) = hooks.cancel {
+            state.set_cancel_token(token.0);
+        }
+        #[cfg(feature = "streaming")]
+        state.set_progress_tracker(
+            hooks
+                .progress
+                .map(|callback| Arc::new(ProgressTracker::new(callback))),
+        );
+        let (mut state, mut physical_plan, _) = self.prepare_collect_with_state(false, state)?;
+        physical_plan.execute(&mut state)
+    }
+
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
 
@@ -0,0 +1,158 @@
+//! Joins on predicates between the columns of both tables.
+#[cfg(feature = "is_between")]
+use polars_ops::prelude::ClosedInterval;
+
+use super::*;
+
+enum Side {
+    Left,
+    Right,
+}
+
+/// Columns of both tables, named as in the output of the join.
+struct JoinColumns<'a> {
+    schema_left: &'a Schema,
+    schema_right: &'a Schema,
+    suffix: &'a str,
+}
+
+impl JoinColumns<'_> {
+    /// The name of an output column in the right table.
+    fn right_name<'b>(&self, name: &'b str) -> Option<&'b str> {
+        if self.schema_right.contains(name) && !self.schema_left.contains(name) {
+            return Some(name);
+        }
+        let stripped = name.strip_suffix(self.suffix)?;
+        (self.schema_right.contains(stripped) && self.schema_left.contains(stripped))
+            .then_some(stripped)
+    }
+
+    /// The table `e` reads from, with the columns of the right table renamed to their names in
+    /// that table.
+    fn side(&self, e: &Expr) -> Option<(Side, Expr)> {
+        let names = expr_to_leaf_column_names(e);
+        if names.is_empty() {
+            return None;
+        }
+        if names.iter().all(|name| self.schema_left.contains(name)) {
+            return Some((Side::Left, e.clone()));
+        }
+        if names.iter().any(|name| self.right_name(name).is_none()) {
+            return None;
+        }
+        let mut e = e.clone();
+        e.mutate().apply(|e| {
+            if let Expr::Column(name) = e {
+                if let Some(right_name) = self.right_name(name) {
+                    *name = Arc::from(right_name);
+                }
+            }
+            true
+        });
+        Some((Side::Right, e))
+    }
+
+    /// Split `predicate` in the comparisons between the tables and the rest.
+    fn split(
+        &self,
+        predicate: &Expr,
+        comparisons: &mut Vec<(Expr, JoinOperator, Expr)>,
+        rest: &mut Vec<Expr>,
+    ) {
+        match predicate {
+            Expr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            } => {
+                self.split(left, comparisons, rest);
+                self.split(right, comparisons, rest);
+            },
+            Expr::BinaryExpr { left, op, right } => {
+                let op = match op {
+                    Operator::Eq => JoinOperator::Eq,
+                    Operator::Lt => JoinOperator::Lt,
+                    Operator::LtEq => JoinOperator::LtEq,
+                    Operator::Gt => JoinOperator::Gt,
+                    Operator::GtEq => JoinOperator::GtEq,
+                    _ => return rest.push(predicate.clone()),
+                };
+                match (self.side(left), self.side(right)) {
+                    (Some((Side::Left, l)), Some((Side::Right, r))) => comparisons.push((l, op, r)),
+                    (Some((Side::Right, r)), Some((Side::Left, l))) => {
+                        comparisons.push((l, op.swap_operands(), r))
+                    },
+                    _ => rest.push(predicate.clone()),
+                }
+            },
+            #[cfg(feature = "is_between")]
+            Expr::Function {
+                input,
+                function: FunctionExpr::Boolean(BooleanFunction::IsBetween { closed }),
+                ..
+            } => {
+                let (lower, upper) = match closed {
+                    ClosedInterval::Both => (Operator::GtEq, Operator::LtEq),
+                    ClosedInterval::Left => (Operator::GtEq, Operator::Lt),
+                    ClosedInterval::Right => (Operator::Gt, Operator::LtEq),
+                    ClosedInterval::None => (Operator::Gt, Operator::Lt),
+                };
+                let [value, low, high] = input.as_slice() else {
+                    return rest.push(predicate.clone());
+                };
+                for (op, bound) in [(lower, low), (upper, high)] {
+                    let comparison = binary_expr(value.clone(), op, bound.clone());
+                    self.split(&comparison, comparisons, rest);
+                }
+            },
+            _ => rest.push(predicate.clone()),
+        }
+    }
+}
+
+impl JoinBuilder {
+    /// Finish the builder as a join on `predicates` instead of on keys.
+    ///
+    /// The predicates refer to the columns as they are named in the output, so columns of the
+    /// right table that also exist in the left table carry the suffix. Comparisons (`==`, `<`,
+    /// `<=`, `>`, `>=` and `is_between`) between an expression of the left and an expression
+    /// of the right table are evaluated by an inequality join, see [`JoinType::IEJoin`]. Other
+    /// predicates filter the result. All columns of both tables are kept.
+    pub fn join_where<E: AsRef<[Expr]>>(self, predicates: E) -> LazyFrame {
+        let other = self.other.as_ref().expect("with not set");
+        let schema_left = fallible!(self.lf.schema(), &self.lf);
+        let schema_right = fallible!(other.schema(), &self.lf);
+        let columns = JoinColumns {
+            schema_left: &schema_left,
+            schema_right: &schema_right,
+            suffix: self.suffix.as_deref().unwrap_or("_right"),
+        };
+
+        let mut comparisons = vec![];
+        let mut rest = vec![];
+        for predicate in predicates.as_ref() {
+            columns.split(predicate, &mut comparisons, &mut rest);
+        }
+
+        let lf = if comparisons.is_empty() {
+            self.how(JoinType::Cross).finish()
+        } else {
+            let mut left_on = Vec::with_capacity(comparisons.len());
+            let mut right_on = Vec::with_capacity(comparisons.len());
+            let mut operators = Vec::with_capacity(comparisons.len());
+            for (l, op, r) in comparisons {
+                left_on.push(l);
+                operators.push(op);
+                right_on.push(r);
+            }
+            self.left_on(left_on)
+                .right_on(right_on)
+                .how(JoinType::IEJoin(IEJoinOptions::new(operators)))
+                .finish()
+        };
+        match rest.into_iter().reduce(|a, b| a.and(b)) {
+            Some(predicate) => lf.filter(predicate),
+            None => lf,
+        }
+    }
+}
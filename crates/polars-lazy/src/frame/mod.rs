@@ -5,6 +5,8 @@ mod python;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "iejoin")]
+mod join_where;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
@@ -32,16 +34,20 @@ pub use ndjson::*;
 pub use parquet::*;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
+#[cfg(feature = "streaming")]
+use polars_pipe::pipeline::MemoryManager;
 pub use polars_plan::frame::{AllowedOptimizations, OptState};
 use polars_plan::global::FETCH_ROWS;
 use smartstring::alias::String as SmartString;
 
 use crate::fallible;
 use crate::physical_plan::executors::Executor;
-use crate::physical_plan::planner::{create_physical_expr, create_physical_plan};
+use crate::physical_plan::planner::{
+    create_instrumented_physical_plan, create_physical_expr, create_physical_plan,
+};
 use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "streaming")]
-use crate::physical_plan::streaming::insert_streaming_nodes;
+use crate::physical_plan::streaming::{insert_streaming_nodes, insert_streaming_nodes_auto};
 use crate::prelude::*;
 
 pub trait IntoLazy {
@@ -140,6 +146,11 @@ impl LazyFrame {
             streaming: false,
             eager: false,
             fast_projection: false,
+            join_reorder: false,
+            memory_limit: None,
+            result_cache: false,
+            auto_streaming: false,
+            runtime_join_filters: false,
         })
     }
 
@@ -187,12 +198,64 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle reordering of inner joins.
+    ///
+    /// Chains of inner joins are reordered by the estimated cardinality of their inputs, so that
+    /// the most selective joins are done first. This is off by default, as it changes the row
+    /// order of the output.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
+    /// Toggle runtime filters of joins.
+    ///
+    /// The build side of an inner or semi join is executed first if it is estimated to be
+    /// small, and its keys are used to skip the row groups and rows of the probe side scan that
+    /// can't find a match.
+    pub fn with_runtime_join_filters(mut self, toggle: bool) -> Self {
+        self.opt_state.runtime_join_filters = toggle;
+        self
+    }
+
     /// Allow (partial) streaming engine.
     pub fn with_streaming(mut self, toggle: bool) -> Self {
         self.opt_state.streaming = toggle;
         self
     }
 
+    /// Choose between the streaming engine and the default engine per subplan.
+    ///
+    /// Subplans whose estimated size exceeds the memory limit, or a share of the free memory
+    /// if no limit is set, run on the streaming engine and the rest on the default engine.
+    /// `POLARS_VERBOSE` reports the choice for every subplan. This has no effect if
+    /// streaming is turned on.
+    pub fn with_auto_streaming(mut self, toggle: bool) -> Self {
+        self.opt_state.auto_streaming = toggle;
+        self
+    }
+
+    /// Set the memory budget in bytes of the streaming engine.
+    ///
+    /// Sinks that buffer data (sort, group-by, join) reserve memory from this budget. Sort and
+    /// group-by spill to disk when it runs out, a join build side that doesn't fit returns an
+    /// error. This has no effect on the default engine.
+    pub fn with_memory_limit(mut self, limit: Option<usize>) -> Self {
+        self.opt_state.memory_limit = limit;
+        self
+    }
+
+    /// Serve the results of repeated subplans from the [`ResultCache`].
+    ///
+    /// The results of the query and of the inputs of its joins and unions are cached across
+    /// queries. Subplans are identified by their optimized plan and the modification times of
+    /// the files they read, so only subplans that read local files without user defined
+    /// functions are cached.
+    pub fn with_result_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.result_cache = toggle;
+        self
+    }
+
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
@@ -246,6 +309,41 @@ impl LazyFrame {
         }
     }
 
+    /// Estimate the number of rows, null counts, distinct counts and byte sizes of the result
+    /// without running the query.
+    ///
+    /// The estimates are derived from the metadata of parquet files, samples of CSV files and
+    /// in-memory frames, and propagated through the optimized plan.
+    pub fn estimate(&self) -> PolarsResult<PlanEstimate> {
+        let mut lf = self.clone();
+        // estimate the plan itself, not the pipelines of the streaming engine
+        lf.opt_state.streaming = false;
+        let (node, lp_arena, expr_arena) = lf.to_alp_optimized()?;
+        Ok(estimate_plan(node, &lp_arena, &expr_arena))
+    }
+
+    /// Serialize the logical plan in a versioned format, e.g. to ship it to another process.
+    ///
+    /// User defined functions and anonymous scans in the plan must be registered, see
+    /// [`register_series_udf`] and friends.
+    #[cfg(feature = "serde")]
+    pub fn serialize_plan<W: std::io::Write>(
+        &self,
+        writer: W,
+        format: PlanFormat,
+    ) -> PolarsResult<()> {
+        self.logical_plan.serialize_versioned(writer, format)
+    }
+
+    /// Create a LazyFrame from a plan written by [`LazyFrame::serialize_plan`].
+    ///
+    /// Returns an error if the plan was written in a format version this version of polars
+    /// can't read.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_plan<R: std::io::Read>(reader: R, format: PlanFormat) -> PolarsResult<Self> {
+        LogicalPlan::deserialize_versioned(reader, format).map(LazyFrame::from)
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -563,11 +661,34 @@ impl LazyFrame {
     }
 
     pub(crate) fn optimize_with_scratch(
+        self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        scratch: &mut Vec<Node>,
+        fmt: bool,
+    ) -> PolarsResult<Node> {
+        self.optimize_with_state(
+            lp_arena,
+            expr_arena,
+            scratch,
+            fmt,
+            &ExecutionState::new(),
+            None,
+        )
+    }
+
+    /// Optimize the plan. Streaming pipelines that are inserted share the stop token,
+    /// memory budget and progress tracker of `state`. The subplans whose results can be
+    /// cached are collected in `result_cache`.
+    #[allow(unused_variables)]
+    fn optimize_with_state(
         self,
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
         _fmt: bool,
+        state: &ExecutionState,
+        result_cache: Option<&mut PlHashMap<Node, PlanFingerprint>>,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
@@ -598,11 +719,37 @@ impl LazyFrame {
                 Some(io_expr)
             }),
         )?;
+        // The streaming engine replaces parts of the plan, the profiler needs the full plan.
+        if let Some(profiler) = state.plan_profiler() {
+            profiler.set_plan(lp_top, lp_arena, expr_arena);
+        }
+        // Fingerprint before the streaming engine replaces subplans with opaque pipelines. The
+        // pipelines keep the node of the subplan they replace.
+        if let Some(result_cache) = result_cache {
+            *result_cache = result_cache_candidates(lp_top, lp_arena, expr_arena);
+        }
 
         if streaming {
             #[cfg(feature = "streaming")]
             {
-                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt, true)?;
+                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt, true, state)?;
+            }
+            #[cfg(not(feature = "streaming"))]
+            {
+                panic!("activate feature 'streaming'")
+            }
+        } else if opt_state.auto_streaming {
+            #[cfg(feature = "streaming")]
+            {
+                insert_streaming_nodes_auto(
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                    scratch,
+                    _fmt,
+                    opt_state.memory_limit,
+                    state,
+                )?;
             }
             #[cfg(not(feature = "streaming"))]
             {
@@ -612,17 +759,40 @@ impl LazyFrame {
         Ok(lp_top)
     }
 
-    #[allow(unused_mut)]
     fn prepare_collect(
+        self,
+        check_sink: bool,
+    ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
+        self.prepare_collect_with_state(check_sink, ExecutionState::new())
+    }
+
+    #[allow(unused_mut)]
+    fn prepare_collect_with_state(
         mut self,
         check_sink: bool,
+        mut state: ExecutionState,
     ) -> PolarsResult<(ExecutionState, Box<dyn Executor>, bool)> {
         let file_caching = self.opt_state.file_caching;
+        #[cfg(feature = "streaming")]
+        state.set_memory_manager(
+            self.opt_state
+                .memory_limit
+                .map(|limit| Arc::new(MemoryManager::new(Some(limit)))),
+        );
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let mut scratch = vec![];
-        let lp_top =
-            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
+        let mut result_cache = PlHashMap::new();
+        let use_result_cache = self.opt_state.result_cache;
+        let runtime_join_filters = self.opt_state.runtime_join_filters;
+        let lp_top = self.optimize_with_state(
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut scratch,
+            false,
+            &state,
+            use_result_cache.then_some(&mut result_cache),
+        )?;
 
         let finger_prints = if file_caching {
             #[cfg(any(
@@ -655,9 +825,21 @@ impl LazyFrame {
         } else {
             true
         };
-        let physical_plan = create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let profile = state.plan_profiler().is_some();
+        let physical_plan = if profile || runtime_join_filters || !result_cache.is_empty() {
+            create_instrumented_physical_plan(
+                lp_top,
+                &mut lp_arena,
+                &mut expr_arena,
+                profile,
+                runtime_join_filters,
+                result_cache,
+            )?
+        } else {
+            create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?
+        };
 
-        let state = ExecutionState::with_finger_prints(finger_prints);
+        state.set_finger_prints(finger_prints);
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -704,6 +886,29 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Run the query and return the optimized plan in tree format, annotated with the wall time,
+    /// the rows in and out and the estimated peak memory of every node.
+    ///
+    /// This works for both the default and the streaming engine.
+    pub fn explain_analyze(self) -> PolarsResult<String> {
+        let (_, profile) = self.profile_plan()?;
+        Ok(profile.describe_tree_format())
+    }
+
+    /// Run the query and return a tuple containing the materialized DataFrame and the
+    /// optimized plan with the runtime statistics of its nodes.
+    ///
+    /// The [`PlanProfile`] can be rendered in tree format or as a dot diagram.
+    pub fn profile_plan(self) -> PolarsResult<(DataFrame, PlanProfile)> {
+        let profiler = Arc::new(PlanProfiler::default());
+        let mut state = ExecutionState::new();
+        state.set_plan_profiler(Some(profiler.clone()));
+        let (mut state, mut physical_plan, _) = self.prepare_collect_with_state(false, state)?;
+        let out = physical_plan.execute(&mut state)?;
+        let profile = profiler.finish()?;
+        Ok((out, profile))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1108,6 +1313,27 @@ impl LazyFrame {
         self.join(other, vec![], vec![], JoinArgs::new(JoinType::Cross))
     }
 
+    /// Join this query with another lazy query on predicates between their columns.
+    ///
+    /// Comparisons between the tables such as `col("start").lt_eq(col("ts"))` are evaluated
+    /// by an inequality join instead of filtering the Cartesian product. See
+    /// [`JoinBuilder::join_where`] for how the predicates are interpreted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn sessions_of_events(sessions: LazyFrame, events: LazyFrame) -> LazyFrame {
+    ///         sessions
+    ///         .join_where(events, [col("start").lt_eq(col("ts")), col("end").gt(col("ts"))])
+    /// }
+    /// ```
+    #[cfg(feature = "iejoin")]
+    pub fn join_where<E: AsRef<[Expr]>>(self, other: LazyFrame, predicates: E) -> LazyFrame {
+        self.join_builder().with(other).join_where(predicates)
+    }
+
     /// Left join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1644,6 +1870,42 @@ impl LazyFrame {
                 function,
                 optimizations,
                 schema,
+                None,
+                name.unwrap_or("ANONYMOUS UDF"),
+            )
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Apply a function/closure once the logical plan get executed, declaring which columns it
+    /// reads and which columns it passes through unchanged.
+    ///
+    /// Filters on the pass-through columns are applied before the function, and columns the
+    /// query doesn't need are removed before the function if it doesn't read them. See
+    /// [`ColumnLineage`] for what the function has to guarantee.
+    pub fn map_with_lineage<F>(
+        self,
+        function: F,
+        lineage: ColumnLineage,
+        schema: Option<Arc<dyn UdfSchema>>,
+        name: Option<&'static str>,
+    ) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        let opt_state = self.get_opt_state();
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            ..Default::default()
+        };
+        let lp = self
+            .get_plan_builder()
+            .map(
+                function,
+                optimizations,
+                schema,
+                Some(lineage),
                 name.unwrap_or("ANONYMOUS UDF"),
             )
             .build();
@@ -1886,6 +2148,8 @@ pub struct JoinBuilder {
     suffix: Option<String>,
     validation: JoinValidation,
     join_nulls: bool,
+    #[cfg(feature = "asof_join")]
+    asof_tolerance: Option<Expr>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1901,6 +2165,8 @@ impl JoinBuilder {
             join_nulls: false,
             suffix: None,
             validation: Default::default(),
+            #[cfg(feature = "asof_join")]
+            asof_tolerance: None,
         }
     }
 
@@ -1972,14 +2238,41 @@ impl JoinBuilder {
         self
     }
 
+    /// The tolerance of an asof join, evaluated per row of the left table. Matches that are
+    /// further from the left key are discarded. See [`AsOfOptions::tolerance_column`].
+    #[cfg(feature = "asof_join")]
+    pub fn asof_tolerance(mut self, tolerance: Expr) -> Self {
+        self.asof_tolerance = Some(tolerance);
+        self
+    }
+
     /// Finish builder
-    pub fn finish(self) -> LazyFrame {
+    #[allow(unused_mut)]
+    pub fn finish(mut self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
         let other = self.other.expect("with not set");
 
         // if any of the nodes reads from files we must activate this this plan as well.
         opt_state.file_caching |= other.opt_state.file_caching;
 
+        // A tolerance expression is evaluated as a temporary column of the left table.
+        #[cfg(feature = "asof_join")]
+        let mut drop_tolerance = false;
+        #[cfg(feature = "asof_join")]
+        if let (Some(tolerance), JoinType::AsOf(options)) =
+            (self.asof_tolerance.take(), &mut self.how)
+        {
+            if let Expr::Column(name) = &tolerance {
+                options.tolerance_column = Some(name.as_ref().into());
+            } else {
+                self.lf = self
+                    .lf
+                    .with_column(tolerance.alias(polars_plan::constants::ASOF_TOLERANCE_NAME));
+                options.tolerance_column = Some(polars_plan::constants::ASOF_TOLERANCE_NAME.into());
+                drop_tolerance = true;
+            }
+        }
+
         let args = JoinArgs {
             how: self.how,
             validation: self.validation,
@@ -2004,6 +2297,12 @@ impl JoinBuilder {
                 .into(),
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        #[allow(clippy::let_and_return)]
+        let out = LazyFrame::from_logical_plan(lp, opt_state);
+        #[cfg(feature = "asof_join")]
+        if drop_tolerance {
+            return out.drop([polars_plan::constants::ASOF_TOLERANCE_NAME]);
+        }
+        out
     }
 }
@@ -1,15 +1,19 @@
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
-//! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
-//! workflows. An optimization can never be pushed down passed a pivot.
+//! The schema of a pivot depends on the values of the pivoted column, so it can't be known
+//! without materializing the whole dataset. This makes a pivot quite a terrible operation for
+//! performant workflows. An optimization can never be pushed down passed such a pivot.
 //!
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
 //!
+//! If the values of the pivoted column are given up front, [`LazyFrame::pivot`] writes the pivot
+//! as a group by with an aggregation per value, which the optimizer understands.
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_ops::pivot::PivotAgg;
+use polars_plan::logical_plan::visitor::{RewritingVisitor, TreeWalker};
 
+use crate::fallible;
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -98,3 +102,112 @@ where
         separator,
     )
 }
+
+/// Restricts the columns an aggregation reads to the rows of a single pivot value.
+///
+/// Selectors of multiple columns are rejected, as every aggregation must produce a single
+/// column per pivot value.
+struct FilterInputs<'a> {
+    on: &'a str,
+    mask: &'a Expr,
+}
+
+impl RewritingVisitor for FilterInputs<'_> {
+    type Node = Expr;
+
+    fn mutate(&mut self, node: Expr) -> PolarsResult<Expr> {
+        Ok(match node {
+            Expr::Column(_) => node.filter(self.mask.clone()),
+            Expr::Len => col(self.on).filter(self.mask.clone()).len(),
+            Expr::Wildcard
+            | Expr::Columns(_)
+            | Expr::DtypeColumn(_)
+            | Expr::Nth(_)
+            | Expr::Selector(_) => polars_bail!(
+                InvalidOperation: "pivot aggregations must refer to their columns by name, got {:?}", node
+            ),
+            node => node,
+        })
+    }
+}
+
+/// The aggregations of every pivot value, named after the value.
+fn pivot_aggregations(
+    on: &str,
+    on_columns: &Series,
+    aggregations: &[Expr],
+    separator: &str,
+) -> PolarsResult<Vec<Expr>> {
+    polars_ensure!(!aggregations.is_empty(), ComputeError: "pivot needs at least one aggregation");
+    polars_ensure!(
+        on_columns.n_unique()? == on_columns.len(),
+        ComputeError: "the values of pivot column '{}' should be unique", on
+    );
+    let headers = on_columns.cast(&DataType::String)?;
+    let headers = headers.str()?;
+
+    let mut masks = Vec::with_capacity(on_columns.len());
+    for i in 0..on_columns.len() {
+        let value = on_columns.get(i)?;
+        let mask = if value.is_null() {
+            col(on).is_null()
+        } else {
+            col(on).eq(lit(LiteralValue::try_from(value)?))
+        };
+        masks.push(mask);
+    }
+
+    let mut exprs = Vec::with_capacity(aggregations.len() * masks.len());
+    for agg in aggregations {
+        let agg_name = expr_output_name(agg)?;
+        for (mask, header) in masks.iter().zip(headers) {
+            let header = header.unwrap_or("null");
+            let name = if aggregations.len() > 1 {
+                format!("{agg_name}{separator}{header}")
+            } else {
+                header.to_string()
+            };
+            let filtered = agg.clone().rewrite(&mut FilterInputs { on, mask })?;
+            // Index groups without this value get a null, as in an eager pivot.
+            exprs.push(
+                when(mask.clone().any(false))
+                    .then(filtered)
+                    .otherwise(lit(NULL))
+                    .alias(&name),
+            );
+        }
+    }
+    Ok(exprs)
+}
+
+impl LazyFrame {
+    /// Pivot the values of column `on` into columns, with a row per distinct `index`.
+    ///
+    /// Unlike the eager [`pivot`], the values that become columns are given by `on_columns`, so
+    /// the schema is known without running the query and optimizations are pushed through the
+    /// pivot. Rows with an `on` value that isn't in `on_columns` only add their index.
+    ///
+    /// Every aggregation runs on the rows of a single `on` value and must refer to its columns by
+    /// name, e.g. `col("price").sum()`. With one aggregation the columns are named after the
+    /// values, with several they are named `{agg}{separator}{value}` where `agg` is the output
+    /// name of the aggregation. The separator defaults to `_`.
+    pub fn pivot<E: AsRef<[IE]>, IE: Into<Expr> + Clone, A: AsRef<[Expr]>>(
+        self,
+        on: &str,
+        on_columns: &Series,
+        index: E,
+        aggregations: A,
+        separator: Option<&str>,
+    ) -> LazyFrame {
+        let exprs = fallible!(
+            pivot_aggregations(
+                on,
+                on_columns,
+                aggregations.as_ref(),
+                separator.unwrap_or("_")
+            ),
+            &self
+        );
+        self.group_by_stable(index).agg(exprs)
+    }
+}
@@ -8,6 +8,7 @@ pub struct CacheExec {
 
 impl Executor for CacheExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         // skip cache and always re-execute
         if self.count == 0 {
             if state.verbose() {
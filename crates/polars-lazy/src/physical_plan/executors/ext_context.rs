@@ -7,6 +7,7 @@ pub struct ExternalContext {
 
 impl Executor for ExternalContext {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         #[cfg(debug_assertions)]
         {
             if state.verbose() {
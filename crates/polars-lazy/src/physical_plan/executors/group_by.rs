@@ -28,6 +28,8 @@ pub struct GroupByExec {
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
+    /// Sortedness of the keys known from the plan.
+    sorted: Vec<Option<IsSorted>>,
 }
 
 impl GroupByExec {
@@ -40,6 +42,7 @@ impl GroupByExec {
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
+        sorted: Vec<Option<IsSorted>>,
     ) -> Self {
         Self {
             input,
@@ -49,6 +52,7 @@ impl GroupByExec {
             maintain_order,
             input_schema,
             slice,
+            sorted,
         }
     }
 }
@@ -96,11 +100,12 @@ pub(super) fn group_by_helper(
 
 impl GroupByExec {
     fn execute_impl(&mut self, state: &ExecutionState, df: DataFrame) -> PolarsResult<DataFrame> {
-        let keys = self
+        let mut keys = self
             .keys
             .iter()
             .map(|e| e.evaluate(&df, state))
-            .collect::<PolarsResult<_>>()?;
+            .collect::<PolarsResult<Vec<_>>>()?;
+        set_sorted_hints(&mut keys, &self.sorted);
         group_by_helper(
             df,
             keys,
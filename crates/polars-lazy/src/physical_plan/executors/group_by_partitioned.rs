@@ -249,18 +249,35 @@ impl PartitionGroupByExec {
         let mut lp_arena = Default::default();
         let node = to_alp(lp, &mut expr_arena, &mut lp_arena).unwrap();
 
+        // The nodes of this plan are not part of the profiled plan.
+        let profiler = state.plan_profiler().cloned();
+        state.set_plan_profiler(None);
+        let out = self.run_streaming_plan(state, node, &mut lp_arena, &mut expr_arena);
+        state.set_plan_profiler(profiler);
+        out
+    }
+
+    #[cfg(feature = "streaming")]
+    fn run_streaming_plan(
+        &self,
+        state: &mut ExecutionState,
+        node: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<PolarsResult<DataFrame>> {
         let inserted = streaming::insert_streaming_nodes(
             node,
-            &mut lp_arena,
-            &mut expr_arena,
+            lp_arena,
+            expr_arena,
             &mut vec![],
             false,
             false,
+            state,
         )
         .unwrap();
 
         if inserted {
-            let mut phys_plan = create_physical_plan(node, &mut lp_arena, &mut expr_arena).unwrap();
+            let mut phys_plan = create_physical_plan(node, lp_arena, expr_arena).unwrap();
 
             if state.verbose() {
                 eprintln!("run STREAMING HASH AGGREGATION")
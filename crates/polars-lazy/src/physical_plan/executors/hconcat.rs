@@ -9,6 +9,7 @@ pub(crate) struct HConcatExec {
 
 impl Executor for HConcatExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         #[cfg(debug_assertions)]
         {
             if state.verbose() {
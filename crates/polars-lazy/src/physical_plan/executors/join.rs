@@ -9,6 +9,11 @@ pub struct JoinExec {
     right_on: Vec<Arc<dyn PhysicalExpr>>,
     parallel: bool,
     args: JoinArgs,
+    /// Filters of the left scan, set from the right join keys at the given index.
+    runtime_filters: Vec<(usize, Arc<RuntimeJoinFilter>)>,
+    /// Sortedness of the left and right keys known from the plan.
+    left_sorted: Vec<Option<IsSorted>>,
+    right_sorted: Vec<Option<IsSorted>>,
 }
 
 impl JoinExec {
@@ -20,6 +25,9 @@ impl JoinExec {
         right_on: Vec<Arc<dyn PhysicalExpr>>,
         parallel: bool,
         args: JoinArgs,
+        runtime_filters: Vec<(usize, Arc<RuntimeJoinFilter>)>,
+        left_sorted: Vec<Option<IsSorted>>,
+        right_sorted: Vec<Option<IsSorted>>,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -28,6 +36,9 @@ impl JoinExec {
             right_on,
             parallel,
             args,
+            runtime_filters,
+            left_sorted,
+            right_sorted,
         }
     }
 }
@@ -47,7 +58,28 @@ impl Executor for JoinExec {
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
-        let (df_left, df_right) = if self.parallel {
+        let (df_left, df_right) = if !self.runtime_filters.is_empty() {
+            // The left scan needs the keys of the right side. If the estimate was off and the
+            // right side is large, the filters are not set and the scan reads all rows.
+            let df_right = input_right.execute(state)?;
+            let filters = if df_right.height() <= MAX_RUNTIME_FILTER_BUILD_ROWS {
+                self.runtime_filters.as_slice()
+            } else {
+                &[]
+            };
+            for (idx, filter) in filters {
+                let keys = self.right_on[*idx].evaluate(&df_right, state)?;
+                filter.set(&keys)?;
+                if state.verbose() {
+                    eprintln!(
+                        "runtime join filter on '{}' from {} keys",
+                        filter.column(),
+                        keys.len()
+                    );
+                }
+            }
+            (input_left.execute(state), Ok(df_right))
+        } else if self.parallel {
             let mut state_right = state.split();
             let mut state_left = state.split();
             state_right.branch_idx += 1;
@@ -85,24 +117,44 @@ impl Executor for JoinExec {
 
         state.record(|| {
 
-            let left_on_series = self
+            let mut left_on_series = self
                 .left_on
                 .iter()
                 .map(|e| e.evaluate(&df_left, state))
                 .collect::<PolarsResult<Vec<_>>>()?;
 
-            let right_on_series = self
+            let mut right_on_series = self
                 .right_on
                 .iter()
                 .map(|e| e.evaluate(&df_right, state))
                 .collect::<PolarsResult<Vec<_>>>()?;
+            set_sorted_hints(&mut left_on_series, &self.left_sorted);
+            set_sorted_hints(&mut right_on_series, &self.right_sorted);
 
             // make sure that we can join on evaluated expressions
-            for s in &left_on_series {
-                df_left.with_column(s.clone())?;
+            // inequality, interval and fuzzy joins keep the columns as they are, the keys are not in
+            // the output
+            #[allow(unused_mut)]
+            let mut keys_in_output = true;
+            #[cfg(feature = "iejoin")]
+            if matches!(self.args.how, JoinType::IEJoin(_)) {
+                keys_in_output = false;
+            }
+            #[cfg(feature = "interval_join")]
+            if matches!(self.args.how, JoinType::Interval(_)) {
+                keys_in_output = false;
             }
-            for s in &right_on_series {
-                df_right.with_column(s.clone())?;
+            #[cfg(feature = "fuzzy_join")]
+            if matches!(self.args.how, JoinType::Fuzzy(_)) {
+                keys_in_output = false;
+            }
+            if keys_in_output {
+                for s in &left_on_series {
+                    df_left.with_column(s.clone())?;
+                }
+                for s in &right_on_series {
+                    df_right.with_column(s.clone())?;
+                }
             }
 
             // prepare the tolerance
@@ -8,10 +8,12 @@ mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod hconcat;
 mod join;
+mod profile;
 mod projection;
 mod projection_utils;
 #[cfg(feature = "python")]
 mod python_scan;
+mod result_cache;
 mod scan;
 mod slice;
 mod sort;
@@ -23,6 +25,7 @@ mod unique;
 use std::borrow::Cow;
 
 pub use executor::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
 use polars_plan::global::FETCH_ROWS;
 use polars_plan::utils::*;
@@ -40,9 +43,11 @@ pub(super) use self::group_by_partitioned::*;
 pub(super) use self::group_by_rolling::GroupByRollingExec;
 pub(super) use self::hconcat::*;
 pub(super) use self::join::*;
+pub(super) use self::profile::*;
 pub(super) use self::projection::*;
 #[cfg(feature = "python")]
 pub(super) use self::python_scan::*;
+pub(super) use self::result_cache::*;
 pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
@@ -51,3 +56,15 @@ pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
 use super::*;
+
+/// Set the sortedness the planner derived for the evaluated keys, so that the
+/// sorted fast paths of the kernels can be used.
+fn set_sorted_hints(keys: &mut [Series], hints: &[Option<IsSorted>]) {
+    for (s, hint) in keys.iter_mut().zip(hints) {
+        if let Some(sorted) = hint {
+            if s.is_sorted_flag() == IsSorted::Not && s.null_count() == 0 {
+                s.set_sorted_flag(*sorted)
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::time::Instant;
+
+use super::*;
+
+/// Records the runtime statistics of the node of the plan that `input` executes.
+pub struct ProfileExec {
+    input: Box<dyn Executor>,
+    node: Node,
+}
+
+impl ProfileExec {
+    pub(crate) fn new(input: Box<dyn Executor>, node: Node) -> Self {
+        Self { input, node }
+    }
+}
+
+impl Executor for ProfileExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let Some(profiler) = state.plan_profiler().cloned() else {
+            return self.input.execute(state);
+        };
+        let start = Instant::now();
+        let df = self.input.execute(state)?;
+        profiler.record_materialized(self.node, start.elapsed(), &df);
+        Ok(df)
+    }
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+/// Serves the result of a subplan from the [`ResultCache`] and stores it on a miss.
+pub struct ResultCacheExec {
+    input: Box<dyn Executor>,
+    fingerprint: PlanFingerprint,
+}
+
+impl ResultCacheExec {
+    pub(crate) fn new(input: Box<dyn Executor>, fingerprint: PlanFingerprint) -> Self {
+        Self { input, fingerprint }
+    }
+}
+
+impl Executor for ResultCacheExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        let cache = ResultCache::global();
+        if let Some(df) = cache.get(&self.fingerprint) {
+            if state.verbose() {
+                eprintln!("RESULT CACHE HIT: fingerprint: {:x}", self.fingerprint.id());
+            }
+            return Ok(df);
+        }
+
+        let df = self.input.execute(state)?;
+        if state.verbose() {
+            eprintln!("RESULT CACHE SET: fingerprint: {:x}", self.fingerprint.id());
+        }
+        cache.insert(self.fingerprint.clone(), df.clone());
+        Ok(df)
+    }
+}
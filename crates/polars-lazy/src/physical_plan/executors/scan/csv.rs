@@ -54,6 +54,7 @@ impl CsvExec {
 
 impl Executor for CsvExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         #[allow(clippy::useless_asref)]
         let finger_print = FileFingerPrint {
             paths: Arc::new([self.path.clone()]),
@@ -32,6 +32,7 @@ impl IpcExec {
 
 impl Executor for IpcExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let finger_print = FileFingerPrint {
             paths: Arc::new([self.path.clone()]),
             #[allow(clippy::useless_asref)]
@@ -17,6 +17,7 @@ pub(crate) use csv::CsvExec;
 pub(crate) use ipc::IpcExec;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::ParquetExec;
+use polars_core::utils::accumulate_dataframes_vertical;
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 use polars_io::predicates::PhysicalIoExpr;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc", feature = "cse"))]
@@ -68,6 +69,7 @@ pub struct DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
@@ -107,8 +109,63 @@ pub(crate) struct AnonymousScanExec {
     pub(crate) predicate_has_windows: bool,
 }
 
+impl AnonymousScanExec {
+    fn read(&self, args: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut df = match self.function.n_partitions() {
+            None => self.function.scan(args)?,
+            Some(n_partitions) => self.read_partitions(n_partitions, args)?,
+        };
+        self.function.set_sorted_flags(&mut df);
+        Ok(df)
+    }
+
+    fn read_partitions(
+        &self,
+        n_partitions: usize,
+        args: AnonymousScanArgs,
+    ) -> PolarsResult<DataFrame> {
+        let evaluator = self
+            .predicate
+            .as_ref()
+            .and_then(|predicate| predicate.as_stats_evaluator());
+        let mut rows_left = args.n_rows.unwrap_or(usize::MAX);
+
+        let mut dfs = vec![];
+        for partition in 0..n_partitions {
+            if rows_left == 0 {
+                break;
+            }
+            if !self.function.should_read_partition(partition, evaluator)? {
+                continue;
+            }
+            for df in self.function.scan_batches(partition, args.clone())? {
+                let df = df?;
+                rows_left = rows_left.saturating_sub(df.height());
+                dfs.push(df);
+                if rows_left == 0 {
+                    break;
+                }
+            }
+        }
+
+        if dfs.is_empty() {
+            let schema = self
+                .output_schema
+                .as_ref()
+                .unwrap_or(&self.file_info.schema);
+            return Ok(DataFrame::from(schema.as_ref()));
+        }
+        let df = accumulate_dataframes_vertical(dfs)?;
+        Ok(match args.n_rows {
+            Some(n_rows) => df.head(Some(n_rows)),
+            None => df,
+        })
+    }
+}
+
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let mut args = AnonymousScanArgs {
             n_rows: self.file_options.n_rows,
             with_columns: self.file_options.with_columns.clone(),
@@ -124,13 +181,13 @@ impl Executor for AnonymousScanExec {
             (true, Some(predicate)) => state.record(
                 || {
                     args.predicate = predicate.as_expression().cloned();
-                    self.function.scan(args)
+                    self.read(args)
                 },
                 "anonymous_scan".into(),
             ),
             (false, Some(predicate)) => state.record(
                 || {
-                    let mut df = self.function.scan(args)?;
+                    let mut df = self.read(args)?;
                     let s = predicate.evaluate(&df, state)?;
                     if self.predicate_has_windows {
                         state.clear_window_expr_cache()
@@ -144,7 +201,7 @@ impl Executor for AnonymousScanExec {
                 },
                 "anonymous_scan".into(),
             ),
-            _ => state.record(|| self.function.scan(args), "anonymous_scan".into()),
+            _ => state.record(|| self.read(args), "anonymous_scan".into()),
         }
     }
 }
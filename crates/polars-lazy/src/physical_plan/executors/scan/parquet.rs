@@ -2,7 +2,6 @@ use std::path::PathBuf;
 
 #[cfg(feature = "cloud")]
 use polars_core::config::{get_file_prefetch_size, verbose};
-use polars_core::utils::accumulate_dataframes_vertical;
 use polars_io::cloud::CloudOptions;
 use polars_io::{is_cloud_url, RowIndex};
 
@@ -360,6 +359,7 @@ impl ParquetExec {
 
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         let finger_print = FileFingerPrint {
             paths: self.paths.clone(),
             #[allow(clippy::useless_asref)]
@@ -8,6 +8,7 @@ pub struct SliceExec {
 
 impl Executor for SliceExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
         #[cfg(debug_assertions)]
         {
             if state.verbose() {
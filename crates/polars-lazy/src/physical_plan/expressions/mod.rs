@@ -10,6 +10,7 @@ mod group_iter;
 mod literal;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
+mod runtime_filter;
 mod slice;
 mod sort;
 mod sortby;
@@ -35,6 +36,7 @@ use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 #[cfg(feature = "dynamic_group_by")]
 pub(crate) use rolling::RollingExpr;
+pub(crate) use runtime_filter::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
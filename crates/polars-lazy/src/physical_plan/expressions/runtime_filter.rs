@@ -0,0 +1,208 @@
+use std::sync::OnceLock;
+
+use polars_core::prelude::*;
+#[cfg(feature = "is_in")]
+use polars_ops::prelude::is_in;
+
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+
+/// Runtime filters are only created for build sides of up to this many rows.
+pub(crate) const MAX_RUNTIME_FILTER_BUILD_ROWS: usize = 1 << 20;
+
+/// Up to this many distinct build keys are kept to filter the probe rows exactly.
+/// Otherwise only their min/max is used.
+const MAX_RUNTIME_FILTER_KEYS: usize = 1 << 16;
+
+struct KeySummary {
+    /// `[min, max]` of the non-null keys, `None` if there are none.
+    min_max: Option<Series>,
+    /// Distinct non-null keys.
+    #[cfg_attr(not(feature = "is_in"), allow(dead_code))]
+    values: Option<Series>,
+}
+
+/// Summary of the join keys of the build side of a join.
+///
+/// It is shared between the join and the scan of the probe side. The join sets it
+/// once the build side is executed, so that the scan can skip row groups and rows
+/// that can't find a match.
+pub(crate) struct RuntimeJoinFilter {
+    column: Arc<str>,
+    keys: OnceLock<KeySummary>,
+}
+
+impl RuntimeJoinFilter {
+    pub(crate) fn new(column: Arc<str>) -> Self {
+        Self {
+            column,
+            keys: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Summarize the evaluated build keys.
+    pub(crate) fn set(&self, keys: &Series) -> PolarsResult<()> {
+        let keys = keys.drop_nulls();
+        let summary = if keys.is_empty() {
+            KeySummary {
+                min_max: None,
+                values: None,
+            }
+        } else {
+            let mut min_max = keys.min_as_series()?;
+            min_max.append(&keys.max_as_series()?)?;
+            let values = keys.unique()?;
+            KeySummary {
+                min_max: Some(min_max),
+                values: (cfg!(feature = "is_in") && values.len() <= MAX_RUNTIME_FILTER_KEYS)
+                    .then_some(values),
+            }
+        };
+        // A filter is only set once, a re-executed join has the same keys.
+        let _ = self.keys.set(summary);
+        Ok(())
+    }
+
+    /// Row mask of the probe keys that may find a match.
+    fn mask(&self, s: &Series) -> PolarsResult<Option<BooleanChunked>> {
+        let Some(summary) = self.keys.get() else {
+            return Ok(None);
+        };
+        let Some(min_max) = &summary.min_max else {
+            return Ok(Some(BooleanChunked::full(s.name(), false, s.len())));
+        };
+        #[cfg(feature = "is_in")]
+        if let Some(values) = &summary.values {
+            return is_in(s, values).map(Some);
+        }
+        let min_max = min_max.cast(s.dtype())?;
+        let lower = s.gt_eq(&min_max.slice(0, 1))?;
+        let upper = s.lt_eq(&min_max.slice(1, 1))?;
+        Ok(Some(lower & upper))
+    }
+}
+
+/// Predicate of a scan that is the probe side of joins with runtime filters.
+pub struct RuntimeFilterExpr {
+    filters: Vec<Arc<RuntimeJoinFilter>>,
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    // Used in file fingerprints and in display.
+    expr: Expr,
+}
+
+impl RuntimeFilterExpr {
+    pub(crate) fn new(
+        filters: Vec<Arc<RuntimeJoinFilter>>,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        let expr = predicate
+            .as_ref()
+            .and_then(|e| e.as_expression().cloned())
+            .unwrap_or_else(|| lit(true));
+        Self {
+            filters,
+            predicate,
+            expr,
+        }
+    }
+}
+
+impl PhysicalExpr for RuntimeFilterExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let mut mask = match &self.predicate {
+            Some(predicate) => predicate.evaluate(df, state)?.bool()?.clone(),
+            None => BooleanChunked::full("", true, df.height()),
+        };
+        for filter in &self.filters {
+            if let Some(filter_mask) = filter.mask(df.column(filter.column())?)? {
+                mask = mask & filter_mask;
+            }
+        }
+        Ok(mask.into_series())
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        _df: &DataFrame,
+        _groups: &'a GroupsProxy,
+        _state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        polars_bail!(InvalidOperation: "runtime join filters are only applied in scans")
+    }
+
+    fn to_field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(Field::new("", DataType::Boolean))
+    }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod stats {
+    use polars_io::predicates::{BatchStats, StatsEvaluator};
+
+    use super::*;
+
+    impl RuntimeJoinFilter {
+        fn should_read(&self, stats: &BatchStats) -> bool {
+            let Some(summary) = self.keys.get() else {
+                return true;
+            };
+            let Some(keys) = &summary.min_max else {
+                return false;
+            };
+            let Some(column) = stats
+                .get_stats(self.column())
+                .ok()
+                .and_then(|stats| stats.to_min_max())
+            else {
+                return true;
+            };
+            let Ok(keys) = keys.cast(column.dtype()) else {
+                return true;
+            };
+            use ChunkCompare as C;
+            // All values are smaller than the smallest key or larger than the largest key.
+            let disjoint = C::lt(&column.slice(1, 1), &keys.slice(0, 1))
+                .map(|ca| ca.all())
+                .unwrap_or(false)
+                || C::gt(&column.slice(0, 1), &keys.slice(1, 1))
+                    .map(|ca| ca.all())
+                    .unwrap_or(false);
+            !disjoint
+        }
+    }
+
+    impl StatsEvaluator for RuntimeFilterExpr {
+        fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+            if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() {
+                return Ok(true);
+            }
+            if let Some(evaluator) = self
+                .predicate
+                .as_ref()
+                .and_then(|predicate| predicate.as_stats_evaluator())
+            {
+                if !evaluator.should_read(stats)? {
+                    return Ok(false);
+                }
+            }
+            let read = self.filters.iter().all(|filter| filter.should_read(stats));
+            if !read && ExecutionState::new().verbose() {
+                eprintln!("parquet file can be skipped, the runtime join filter excludes it.")
+            }
+            Ok(read)
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub mod expressions;
 mod file_cache;
 mod node_timer;
 pub mod planner;
+pub(crate) mod result_cache;
 pub(crate) mod state;
 #[cfg(feature = "streaming")]
 pub(crate) mod streaming;
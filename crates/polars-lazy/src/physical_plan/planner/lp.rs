@@ -1,9 +1,14 @@
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
 use polars_plan::global::_set_n_rows_for_scan;
 
 use super::super::executors::{self, Executor};
+use super::super::expressions::{
+    RuntimeFilterExpr, RuntimeJoinFilter, MAX_RUNTIME_FILTER_BUILD_ROWS,
+};
 use super::*;
+use crate::prelude::{JoinArgs, JoinType, PhysicalExpr};
 use crate::utils::*;
 
 fn partitionable_gb(
@@ -140,10 +145,99 @@ fn partitionable_gb(
     partitionable
 }
 
+/// State that is shared between the nodes during the conversion to a physical plan.
+#[derive(Default)]
+struct PlanConversionState {
+    /// Runtime join filters that are applied by the scan at the given node.
+    runtime_filters: PlHashMap<Node, Vec<Arc<RuntimeJoinFilter>>>,
+    /// Record the runtime statistics of every node.
+    profile: bool,
+    /// Filter the probe side scans of joins by the keys of their build side.
+    runtime_join_filters: bool,
+    /// Subplans whose results are served from the [`ResultCache`].
+    result_cache: PlHashMap<Node, PlanFingerprint>,
+}
+
 pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Box<dyn Executor>> {
+    create_physical_plan_impl(root, lp_arena, expr_arena, &mut Default::default())
+}
+
+/// Create a physical plan that records the runtime statistics of the nodes in the
+/// [`PlanProfiler`] of the execution state if `profile` is set, filters the scans of joins by
+/// the keys of their build side if `runtime_join_filters` is set, and serves the results of the
+/// subplans in `result_cache` from the [`ResultCache`].
+pub(crate) fn create_instrumented_physical_plan(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    profile: bool,
+    runtime_join_filters: bool,
+    result_cache: PlHashMap<Node, PlanFingerprint>,
+) -> PolarsResult<Box<dyn Executor>> {
+    let mut plan_state = PlanConversionState {
+        profile,
+        runtime_join_filters,
+        result_cache,
+        ..Default::default()
+    };
+    create_physical_plan_impl(root, lp_arena, expr_arena, &mut plan_state)
+}
+
+fn create_physical_plan_impl(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    plan_state: &mut PlanConversionState,
+) -> PolarsResult<Box<dyn Executor>> {
+    // The streaming engine records the nodes of its pipelines itself.
+    let profile = plan_state.profile
+        && !matches!(
+            lp_arena.get(root),
+            ALogicalPlan::MapFunction {
+                function: FunctionNode::Pipeline { .. },
+                ..
+            }
+        );
+    let fingerprint = plan_state
+        .result_cache
+        .remove(&root)
+        .filter(|_| !has_outer_runtime_filters(root, lp_arena, plan_state));
+    let mut executor = create_physical_node(root, lp_arena, expr_arena, plan_state)?;
+    if let Some(fingerprint) = fingerprint {
+        executor = Box::new(executors::ResultCacheExec::new(executor, fingerprint));
+    }
+    if profile {
+        Ok(Box::new(executors::ProfileExec::new(executor, root)))
+    } else {
+        Ok(executor)
+    }
+}
+
+/// Whether a scan of the subplan at `root` is filtered by the keys of a join outside of it.
+///
+/// The result of such a subplan depends on the other side of that join, which is not part of
+/// its fingerprint, so it can't be cached. The filters of the joins in the subplan are only
+/// registered once the subplan is converted.
+fn has_outer_runtime_filters(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    plan_state: &PlanConversionState,
+) -> bool {
+    !plan_state.runtime_filters.is_empty()
+        && lp_arena
+            .iter(root)
+            .any(|(node, _)| plan_state.runtime_filters.contains_key(&node))
+}
+
+fn create_physical_node(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    plan_state: &mut PlanConversionState,
 ) -> PolarsResult<Box<dyn Executor>> {
     use ALogicalPlan::*;
 
@@ -168,7 +262,7 @@ pub fn create_physical_plan(
         Union { inputs, options } => {
             let inputs = inputs
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, plan_state))
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Box::new(executors::UnionExec { inputs, options }))
         },
@@ -177,17 +271,17 @@ pub fn create_physical_plan(
         } => {
             let inputs = inputs
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, plan_state))
                 .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Box::new(executors::HConcatExec { inputs, options }))
         },
         Slice { input, offset, len } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         },
         Selection { input, predicate } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             let mut state = ExpressionConversionState::default();
             let predicate = create_physical_expr(
                 predicate,
@@ -225,6 +319,14 @@ pub fn create_physical_plan(
                 })
                 .map_or(Ok(None), |v| v.map(Some))?;
 
+            let runtime_filters = plan_state.runtime_filters.remove(&root).unwrap_or_default();
+            let predicate = if runtime_filters.is_empty() {
+                predicate
+            } else {
+                Some(Arc::new(RuntimeFilterExpr::new(runtime_filters, predicate))
+                    as Arc<dyn PhysicalExpr>)
+            };
+
             match scan_type {
                 #[cfg(feature = "csv")]
                 FileScan::Csv {
@@ -286,7 +388,7 @@ pub fn create_physical_plan(
             ..
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             let mut state = ExpressionConversionState::new(POOL.current_num_threads() > expr.len());
             let phys_expr = create_physical_expressions(
                 expr.default_exprs(),
@@ -352,7 +454,7 @@ pub fn create_physical_plan(
                 Some(input_schema.as_ref()),
                 &mut Default::default(),
             )?;
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             Ok(Box::new(executors::SortExec {
                 input,
                 by_column,
@@ -360,11 +462,11 @@ pub fn create_physical_plan(
             }))
         },
         Cache { input, id, count } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             Ok(Box::new(executors::CacheExec { id, input, count }))
         },
         Distinct { input, options } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             Ok(Box::new(executors::UniqueExec { input, options }))
         },
         Aggregate {
@@ -396,7 +498,7 @@ pub fn create_physical_plan(
             let _slice = options.slice;
             #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.dynamic {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
@@ -410,7 +512,7 @@ pub fn create_physical_plan(
 
             #[cfg(feature = "dynamic_group_by")]
             if let Some(options) = options.rolling {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
                 return Ok(Box::new(executors::GroupByRollingExec {
                     input,
                     keys: phys_keys,
@@ -422,8 +524,13 @@ pub fn create_physical_plan(
                 }));
             }
 
+            let sorted_keys = sorted_key_hints(input, &keys, lp_arena, expr_arena);
+            // A sorted key is grouped in a single pass, partitioning would only add work.
+            let keys_sorted = keys.len() == 1 && sorted_keys[0].is_some();
+
             // We first check if we can partition the group_by on the latest moment.
-            let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
+            let partitionable =
+                !keys_sorted && partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
                 let from_partitioned_ds = (&*lp_arena).iter(input).any(|(_, lp)| {
                     if let Union { options, .. } = lp {
@@ -432,7 +539,7 @@ pub fn create_physical_plan(
                         false
                     }
                 });
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
                 let keys = keys
                     .iter()
                     .map(|node| node_to_expr(*node, expr_arena))
@@ -454,7 +561,7 @@ pub fn create_physical_plan(
                     aggs,
                 )))
             } else {
-                let input = create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -463,6 +570,7 @@ pub fn create_physical_plan(
                     maintain_order,
                     input_schema,
                     options.slice,
+                    sorted_keys,
                 )))
             }
         },
@@ -489,8 +597,34 @@ pub fn create_physical_plan(
                 false
             };
 
-            let input_left = create_physical_plan(input_left, lp_arena, expr_arena)?;
-            let input_right = create_physical_plan(input_right, lp_arena, expr_arena)?;
+            let runtime_filters = if plan_state.runtime_join_filters {
+                runtime_join_filters(
+                    input_left,
+                    input_right,
+                    &left_on,
+                    &right_on,
+                    &options.args,
+                    lp_arena,
+                    expr_arena,
+                )
+            } else {
+                vec![]
+            };
+            for (_, (scan, filter)) in &runtime_filters {
+                plan_state
+                    .runtime_filters
+                    .entry(*scan)
+                    .or_default()
+                    .push(filter.clone());
+            }
+
+            let left_sorted = sorted_key_hints(input_left, &left_on, lp_arena, expr_arena);
+            let right_sorted = sorted_key_hints(input_right, &right_on, lp_arena, expr_arena);
+
+            let input_left =
+                create_physical_plan_impl(input_left, lp_arena, expr_arena, plan_state)?;
+            let input_right =
+                create_physical_plan_impl(input_right, lp_arena, expr_arena, plan_state)?;
             let left_on = create_physical_expressions(
                 &left_on,
                 Context::Default,
@@ -513,6 +647,12 @@ pub fn create_physical_plan(
                 right_on,
                 parallel,
                 options.args,
+                runtime_filters
+                    .into_iter()
+                    .map(|(idx, (_, filter))| (idx, filter))
+                    .collect(),
+                left_sorted,
+                right_sorted,
             )))
         },
         HStack {
@@ -522,7 +662,7 @@ pub fn create_physical_plan(
             options,
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
 
             let mut state =
                 ExpressionConversionState::new(POOL.current_num_threads() > exprs.len());
@@ -554,18 +694,157 @@ pub fn create_physical_plan(
         MapFunction {
             input, function, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             Ok(Box::new(executors::UdfExec { input, function }))
         },
         ExtContext {
             input, contexts, ..
         } => {
-            let input = create_physical_plan(input, lp_arena, expr_arena)?;
+            let input = create_physical_plan_impl(input, lp_arena, expr_arena, plan_state)?;
             let contexts = contexts
                 .into_iter()
-                .map(|node| create_physical_plan(node, lp_arena, expr_arena))
+                .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, plan_state))
                 .collect::<PolarsResult<_>>()?;
             Ok(Box::new(executors::ExternalContext { input, contexts }))
         },
     }
 }
+
+/// Find the parquet or IPC scan below `node` that produces `column` unchanged and
+/// that may drop rows that don't find a match in the join.
+///
+/// The filter is only moved below nodes whose expressions work row by row, the same
+/// boundary the predicate pushdown respects. An aggregation, window or `cum_sum` above
+/// the scan would otherwise see fewer rows.
+fn runtime_filter_scan(
+    node: Node,
+    column: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Node> {
+    use ALogicalPlan::*;
+    let blocks = |e: &Node| aexpr_blocks_predicate_pushdown(*e, expr_arena);
+    match lp_arena.get(node) {
+        Selection { input, predicate } if !blocks(predicate) => {
+            runtime_filter_scan(*input, column, lp_arena, expr_arena)
+        },
+        Projection { input, expr, .. }
+            if expr.cse_exprs().is_empty() && !expr.default_exprs().iter().any(blocks) =>
+        {
+            let passes_column = expr.default_exprs().iter().any(
+                |e| matches!(expr_arena.get(*e), AExpr::Column(name) if name.as_ref() == column),
+            );
+            passes_column
+                .then(|| runtime_filter_scan(*input, column, lp_arena, expr_arena))
+                .flatten()
+        },
+        HStack { input, exprs, .. }
+            if exprs.cse_exprs().is_empty() && !exprs.default_exprs().iter().any(blocks) =>
+        {
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let overwrites_column = exprs.default_exprs().iter().any(|e| {
+                expr_arena
+                    .get(*e)
+                    .to_field(&input_schema, Context::Default, expr_arena)
+                    .map_or(true, |field| field.name() == column)
+            });
+            (!overwrites_column)
+                .then(|| runtime_filter_scan(*input, column, lp_arena, expr_arena))
+                .flatten()
+        },
+        Scan {
+            scan_type,
+            file_options,
+            ..
+        } if file_options.n_rows.is_none()
+            && file_options.row_index.is_none()
+            // a cached scan is shared with other nodes
+            && file_options.file_counter <= 1 =>
+        {
+            match scan_type {
+                #[cfg(feature = "parquet")]
+                FileScan::Parquet { .. } => Some(node),
+                #[cfg(feature = "ipc")]
+                FileScan::Ipc { .. } => Some(node),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// The sortedness of the keys that are columns of `input` known to be sorted.
+fn sorted_key_hints(
+    input: Node,
+    keys: &[Node],
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<Option<IsSorted>> {
+    keys.iter()
+        .map(|e| match expr_arena.get(*e) {
+            AExpr::Column(name) => {
+                sorted_column(input, name, lp_arena, expr_arena).map(|s| s.is_sorted())
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Create the runtime filters of an inner or semi join.
+///
+/// The right side is executed first and its keys are used to filter the scan of the
+/// left side, so this is only done if the right side is estimated to be small. Returns the
+/// index of the join key and the scan node of every filter.
+fn runtime_join_filters(
+    input_left: Node,
+    input_right: Node,
+    left_on: &[Node],
+    right_on: &[Node],
+    args: &JoinArgs,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<(usize, (Node, Arc<RuntimeJoinFilter>))> {
+    let filtering_join = match args.how {
+        JoinType::Inner => true,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi => true,
+        _ => false,
+    };
+    if !filtering_join || args.join_nulls || args.slice.is_some() {
+        return vec![];
+    }
+    // Running a large build side first costs more parallelism than its keys can save.
+    let build_is_small = estimate_plan(input_right, lp_arena, expr_arena)
+        .rows
+        .map_or(false, |rows| rows <= MAX_RUNTIME_FILTER_BUILD_ROWS);
+    if !build_is_small {
+        return vec![];
+    }
+    let schema_left = lp_arena.get(input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(input_right).schema(lp_arena);
+
+    let mut filters = vec![];
+    for (idx, (l, r)) in left_on.iter().zip(right_on).enumerate() {
+        let AExpr::Column(name) = expr_arena.get(*l) else {
+            continue;
+        };
+        let (Some(dtype), Ok(field_right)) = (
+            schema_left.get(name),
+            expr_arena
+                .get(*r)
+                .to_field(&schema_right, Context::Default, expr_arena),
+        ) else {
+            continue;
+        };
+        // Keys must compare the same on both sides.
+        let supported = dtype == field_right.data_type()
+            && (dtype.is_numeric() || dtype.is_temporal() || matches!(dtype, DataType::String));
+        if !supported {
+            continue;
+        }
+        if let Some(scan) = runtime_filter_scan(input_left, name, lp_arena, expr_arena) {
+            filters.push((idx, (scan, Arc::new(RuntimeJoinFilter::new(name.clone())))))
+        }
+    }
+    filters
+}
@@ -0,0 +1,278 @@
+//! Process wide cache of the results of subplans, shared between queries.
+#[cfg(feature = "ipc")]
+use std::fs::File;
+#[cfg(feature = "ipc")]
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+#[cfg(feature = "ipc")]
+use polars_io::prelude::*;
+
+use crate::prelude::*;
+
+const DEFAULT_MEMORY_CAPACITY: usize = 512 * 1024 * 1024;
+
+static RESULT_CACHE: Lazy<ResultCache> = Lazy::new(|| ResultCache::new(DEFAULT_MEMORY_CAPACITY));
+
+enum Stored {
+    Memory(DataFrame),
+    #[cfg(feature = "ipc")]
+    Spilled(PathBuf),
+}
+
+struct Entry {
+    stored: Stored,
+    bytes: usize,
+    last_used: u64,
+}
+
+#[cfg(feature = "ipc")]
+struct SpillConfig {
+    dir: PathBuf,
+    capacity: usize,
+    used: usize,
+    files_written: usize,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: PlHashMap<PlanFingerprint, Entry>,
+    clock: u64,
+    memory_capacity: usize,
+    memory_used: usize,
+    #[cfg(feature = "ipc")]
+    spill: Option<SpillConfig>,
+}
+
+/// Cache of the materialized results of subplans, keyed by their [`PlanFingerprint`].
+///
+/// Queries that enable the cache with `LazyFrame::with_result_cache` look up the result of
+/// the whole query and of the inputs of their joins and unions before executing them. Entries
+/// are evicted in least recently used order once the memory capacity is exceeded, or spilled
+/// to IPC files if a spill directory is set.
+pub struct ResultCache {
+    inner: Mutex<Inner>,
+}
+
+impl ResultCache {
+    /// Create a cache that holds up to `memory_capacity` bytes of results in memory.
+    pub fn new(memory_capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                memory_capacity,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// The cache that is used by the queries.
+    pub fn global() -> &'static ResultCache {
+        &RESULT_CACHE
+    }
+
+    /// Set the number of bytes of results that are held in memory.
+    pub fn set_memory_capacity(&self, memory_capacity: usize) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.memory_capacity = memory_capacity;
+        inner.evict();
+    }
+
+    /// Spill the results that are evicted from memory to IPC files in `dir`, holding up to
+    /// `capacity` bytes on disk. `None` stops spilling and removes the spilled results.
+    #[cfg(feature = "ipc")]
+    pub fn set_spill_dir(&self, dir: Option<PathBuf>, capacity: usize) -> PolarsResult<()> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove_spilled();
+        inner.spill = match dir {
+            Some(dir) => {
+                std::fs::create_dir_all(&dir)?;
+                Some(SpillConfig {
+                    dir,
+                    capacity,
+                    used: 0,
+                    files_written: 0,
+                })
+            },
+            None => None,
+        };
+        Ok(())
+    }
+
+    /// Remove all results.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        #[cfg(feature = "ipc")]
+        inner.remove_spilled();
+        inner.entries.clear();
+        inner.memory_used = 0;
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Estimated size in bytes of the results held in memory.
+    pub fn memory_usage(&self) -> usize {
+        self.inner.lock().unwrap().memory_used
+    }
+
+    /// Get the result of a subplan.
+    ///
+    /// Spilled results that can't be read anymore are removed and reported as missing.
+    pub(crate) fn get(&self, fingerprint: &PlanFingerprint) -> Option<DataFrame> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.clock += 1;
+        let clock = inner.clock;
+        let entry = inner.entries.get_mut(fingerprint)?;
+        entry.last_used = clock;
+        match &entry.stored {
+            Stored::Memory(df) => Some(df.clone()),
+            #[cfg(feature = "ipc")]
+            Stored::Spilled(path) => {
+                let path = path.clone();
+                // don't block the other queries while reading
+                drop(inner);
+                let df = File::open(path)
+                    .map_err(PolarsError::from)
+                    .and_then(|file| IpcReader::new(file).finish());
+                match df {
+                    Ok(df) => Some(df),
+                    Err(_) => {
+                        self.inner.lock().unwrap().remove(fingerprint);
+                        None
+                    },
+                }
+            },
+        }
+    }
+
+    /// Store the result of a subplan, evicting the least recently used results if needed.
+    pub(crate) fn insert(&self, fingerprint: PlanFingerprint, df: DataFrame) {
+        let bytes = df.estimated_size();
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove(&fingerprint);
+        inner.clock += 1;
+        let entry = Entry {
+            stored: Stored::Memory(df),
+            bytes,
+            last_used: inner.clock,
+        };
+        inner.entries.insert(fingerprint, entry);
+        inner.memory_used += bytes;
+        inner.evict();
+    }
+}
+
+impl Inner {
+    fn remove(&mut self, fingerprint: &PlanFingerprint) {
+        if let Some(entry) = self.entries.remove(fingerprint) {
+            match entry.stored {
+                Stored::Memory(_) => self.memory_used -= entry.bytes,
+                #[cfg(feature = "ipc")]
+                Stored::Spilled(path) => {
+                    let _ = std::fs::remove_file(path);
+                    if let Some(spill) = &mut self.spill {
+                        spill.used = spill.used.saturating_sub(entry.bytes);
+                    }
+                },
+            }
+        }
+    }
+
+    /// The least recently used entry that matches `predicate`.
+    ///
+    /// This scans all entries, the cache is expected to hold a moderate number of large results.
+    fn least_recently_used(&self, predicate: impl Fn(&Stored) -> bool) -> Option<PlanFingerprint> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| predicate(&entry.stored))
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(fingerprint, _)| fingerprint.clone())
+    }
+
+    fn evict(&mut self) {
+        while self.memory_used > self.memory_capacity {
+            let Some(fingerprint) =
+                self.least_recently_used(|stored| matches!(stored, Stored::Memory(_)))
+            else {
+                break;
+            };
+            #[cfg(feature = "ipc")]
+            if self.spill.is_some() {
+                self.spill_entry(&fingerprint);
+                continue;
+            }
+            self.remove(&fingerprint);
+        }
+
+        #[cfg(feature = "ipc")]
+        while self
+            .spill
+            .as_ref()
+            .map_or(false, |spill| spill.used > spill.capacity)
+        {
+            let Some(fingerprint) =
+                self.least_recently_used(|stored| matches!(stored, Stored::Spilled(_)))
+            else {
+                break;
+            };
+            self.remove(&fingerprint);
+        }
+    }
+
+    /// Move an entry from memory to disk, dropping it if it can't be written.
+    #[cfg(feature = "ipc")]
+    fn spill_entry(&mut self, fingerprint: &PlanFingerprint) {
+        let spill = self.spill.as_mut().unwrap();
+        let entry = self.entries.get_mut(fingerprint).unwrap();
+        let Stored::Memory(df) = &mut entry.stored else {
+            unreachable!()
+        };
+        let path = spill.dir.join(format!(
+            "polars-result-{:016x}-{}.ipc",
+            fingerprint.id(),
+            spill.files_written
+        ));
+        spill.files_written += 1;
+
+        match write_ipc(&path, df) {
+            Ok(file_size) => {
+                self.memory_used -= entry.bytes;
+                entry.bytes = file_size;
+                entry.stored = Stored::Spilled(path);
+                spill.used += file_size;
+            },
+            Err(_) => {
+                let _ = std::fs::remove_file(&path);
+                self.remove(fingerprint);
+            },
+        }
+    }
+
+    #[cfg(feature = "ipc")]
+    fn remove_spilled(&mut self) {
+        let spilled = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry.stored, Stored::Spilled(_)))
+            .map(|(fingerprint, _)| fingerprint.clone())
+            .collect::<Vec<_>>();
+        for fingerprint in spilled {
+            self.remove(&fingerprint);
+        }
+    }
+}
+
+#[cfg(feature = "ipc")]
+fn write_ipc(path: &Path, df: &mut DataFrame) -> PolarsResult<usize> {
+    let file = File::create(path)?;
+    IpcWriter::new(file).finish(df)?;
+    Ok(std::fs::metadata(path)?.len() as usize)
+}
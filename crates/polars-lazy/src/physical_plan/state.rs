@@ -7,6 +7,8 @@ use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
+#[cfg(feature = "streaming")]
+use polars_pipe::pipeline::{MemoryManager, ProgressTracker};
 #[cfg(any(
     feature = "parquet",
     feature = "csv",
@@ -14,6 +16,7 @@ use polars_ops::prelude::ChunkJoinOptIds;
     feature = "json"
 ))]
 use polars_plan::logical_plan::FileFingerPrint;
+use polars_plan::logical_plan::PlanProfiler;
 
 #[cfg(any(
     feature = "ipc",
@@ -91,7 +94,15 @@ pub struct ExecutionState {
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    // collects the runtime statistics of the plan nodes for `explain_analyze`
+    plan_profiler: Option<Arc<PlanProfiler>>,
     stop: Arc<AtomicBool>,
+    // memory budget shared by all streaming pipelines of the query
+    #[cfg(feature = "streaming")]
+    memory_manager: Option<Arc<MemoryManager>>,
+    // reports the progress of the streaming pipelines
+    #[cfg(feature = "streaming")]
+    progress: Option<Arc<ProgressTracker>>,
 }
 
 impl ExecutionState {
@@ -116,7 +127,12 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            plan_profiler: None,
             stop: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "streaming")]
+            memory_manager: None,
+            #[cfg(feature = "streaming")]
+            progress: None,
         }
     }
 
@@ -132,8 +148,17 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// Collect the runtime statistics of the nodes of the plan.
+    pub(crate) fn set_plan_profiler(&mut self, profiler: Option<Arc<PlanProfiler>>) {
+        self.plan_profiler = profiler;
+    }
+
+    pub(crate) fn plan_profiler(&self) -> Option<&Arc<PlanProfiler>> {
+        self.plan_profiler.as_ref()
+    }
+
     // This is wrong when the U64 overflows which will never happen.
-    pub(super) fn should_stop(&self) -> PolarsResult<()> {
+    pub(crate) fn should_stop(&self) -> PolarsResult<()> {
         polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
         Ok(())
     }
@@ -142,6 +167,43 @@ impl ExecutionState {
         self.stop.clone()
     }
 
+    /// Stop the query once `token` is set.
+    pub(crate) fn set_cancel_token(&mut self, token: Arc<AtomicBool>) {
+        self.stop = token;
+    }
+
+    #[cfg(feature = "streaming")]
+    pub(crate) fn set_memory_manager(&mut self, memory_manager: Option<Arc<MemoryManager>>) {
+        self.memory_manager = memory_manager;
+    }
+
+    #[cfg(feature = "streaming")]
+    pub(crate) fn memory_manager(&self) -> Option<Arc<MemoryManager>> {
+        self.memory_manager.clone()
+    }
+
+    #[cfg(feature = "streaming")]
+    pub(crate) fn set_progress_tracker(&mut self, progress: Option<Arc<ProgressTracker>>) {
+        self.progress = progress;
+    }
+
+    #[cfg(feature = "streaming")]
+    pub(crate) fn progress_tracker(&self) -> Option<Arc<ProgressTracker>> {
+        self.progress.clone()
+    }
+
+    /// Fresh state for a streaming pipeline that shares the query wide
+    /// stop token, memory budget, progress tracker and plan profiler of `self`.
+    #[cfg(feature = "streaming")]
+    pub(crate) fn streaming_state(&self) -> Self {
+        let mut state = Self::new();
+        state.stop = self.stop.clone();
+        state.memory_manager = self.memory_manager.clone();
+        state.progress = self.progress.clone();
+        state.plan_profiler = self.plan_profiler.clone();
+        state
+    }
+
     pub(super) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
@@ -175,7 +237,12 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            plan_profiler: self.plan_profiler.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "streaming")]
+            memory_manager: self.memory_manager.clone(),
+            #[cfg(feature = "streaming")]
+            progress: self.progress.clone(),
         }
     }
 
@@ -197,7 +264,12 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            plan_profiler: self.plan_profiler.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "streaming")]
+            memory_manager: self.memory_manager.clone(),
+            #[cfg(feature = "streaming")]
+            progress: self.progress.clone(),
         }
     }
 
@@ -207,19 +279,15 @@ impl ExecutionState {
         feature = "ipc",
         feature = "json"
     )))]
-    pub(crate) fn with_finger_prints(_finger_prints: Option<usize>) -> Self {
-        Self::new()
-    }
+    pub(crate) fn set_finger_prints(&mut self, _finger_prints: Option<usize>) {}
     #[cfg(any(
         feature = "parquet",
         feature = "csv",
         feature = "ipc",
         feature = "json"
     ))]
-    pub(crate) fn with_finger_prints(finger_prints: Option<Vec<FileFingerPrint>>) -> Self {
-        let mut new = Self::new();
-        new.file_cache = FileCache::new(finger_prints);
-        new
+    pub(crate) fn set_finger_prints(&mut self, finger_prints: Option<Vec<FileFingerPrint>>) {
+        self.file_cache = FileCache::new(finger_prints);
     }
 
     pub(crate) fn set_schema(&self, schema: SchemaRef) {
@@ -0,0 +1,123 @@
+//! Choice between the streaming engine and the default engine per subplan.
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+use polars_utils::sys::MEMINFO;
+
+use super::checks::streamable_join;
+use super::convert_alp::insert_streaming_nodes;
+use crate::physical_plan::state::ExecutionState;
+
+/// Share of the free system memory the default engine may use if no memory limit is set.
+const FREE_MEMORY_FRACTION: f64 = 0.5;
+
+/// Run the subplans that are estimated not to fit in memory on the streaming engine and the
+/// rest on the default engine, which is faster on data that fits.
+///
+/// The memory budget is `memory_limit`, or a share of the free system memory if it isn't set.
+/// Subplans of unknown size run on the default engine.
+pub(crate) fn insert_streaming_nodes_auto(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    scratch: &mut Vec<Node>,
+    fmt: bool,
+    memory_limit: Option<usize>,
+    state: &ExecutionState,
+) -> PolarsResult<()> {
+    let budget =
+        memory_limit.unwrap_or_else(|| (MEMINFO.free() as f64 * FREE_MEMORY_FRACTION) as usize);
+    let verbose = state.verbose();
+    let estimates = estimate_subplans(root, lp_arena, expr_arena);
+    let mut peaks = PlHashMap::new();
+
+    // Decide for the whole plan before inserting pipelines, as they replace the subplans.
+    let mut to_stream = vec![];
+    let mut stack = vec![root];
+    let mut inputs = vec![];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        match peak_size(node, lp_arena, &estimates, &mut peaks) {
+            Some(peak) if peak > budget => {
+                if splits_pipelines(lp) {
+                    // The node itself can't stream, but its inputs may.
+                    if verbose {
+                        eprintln!(
+                            "AUTO ENGINE: default engine for '{}': estimated peak of {peak} bytes exceeds the budget of {budget} bytes, but it can't stream; deciding per input",
+                            lp.name()
+                        );
+                    }
+                    lp.copy_inputs(&mut inputs);
+                    stack.append(&mut inputs);
+                } else {
+                    if verbose {
+                        eprintln!(
+                            "AUTO ENGINE: streaming engine for '{}': estimated peak of {peak} bytes exceeds the budget of {budget} bytes",
+                            lp.name()
+                        );
+                    }
+                    to_stream.push(node);
+                }
+            },
+            Some(peak) => {
+                if verbose {
+                    eprintln!(
+                        "AUTO ENGINE: default engine for '{}': estimated peak of {peak} bytes fits in the budget of {budget} bytes",
+                        lp.name()
+                    );
+                }
+            },
+            None => {
+                if verbose {
+                    eprintln!(
+                        "AUTO ENGINE: default engine for '{}': its size can't be estimated",
+                        lp.name()
+                    );
+                }
+            },
+        }
+    }
+
+    for node in to_stream {
+        insert_streaming_nodes(node, lp_arena, expr_arena, scratch, fmt, true, state)?;
+    }
+    Ok(())
+}
+
+/// Estimated number of bytes the default engine holds at once while executing the subplan at
+/// `node`: the largest sum of the result of a node and the results of its inputs.
+fn peak_size(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    estimates: &PlHashMap<Node, PlanEstimate>,
+    peaks: &mut PlHashMap<Node, Option<usize>>,
+) -> Option<usize> {
+    if let Some(peak) = peaks.get(&node) {
+        return *peak;
+    }
+    let size = |node: Node| estimates.get(&node).and_then(|e| e.byte_size());
+
+    let mut inputs = vec![];
+    lp_arena.get(node).copy_inputs(&mut inputs);
+    let held = std::iter::once(node)
+        .chain(inputs.iter().copied())
+        .filter_map(size)
+        .reduce(|a, b| a + b);
+    let peak = inputs
+        .into_iter()
+        .filter_map(|input| peak_size(input, lp_arena, estimates, peaks))
+        .chain(held)
+        .max();
+    peaks.insert(node, peak);
+    peak
+}
+
+/// Whether the streaming engine has to split its pipelines at this node, e.g. because it joins
+/// several inputs in a way the streaming engine doesn't support.
+fn splits_pipelines(lp: &ALogicalPlan) -> bool {
+    use ALogicalPlan::*;
+    match lp {
+        Join { options, .. } => !streamable_join(&options.args),
+        HConcat { .. } | ExtContext { .. } => true,
+        _ => false,
+    }
+}
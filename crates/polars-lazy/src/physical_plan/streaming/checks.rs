@@ -4,10 +4,11 @@ use polars_plan::prelude::*;
 pub(super) fn is_streamable_sort(args: &SortArguments) -> bool {
     // check if slice is positive or maintain order is true
     match args {
+        // only the top-k sink keeps equal rows in the order they were streamed
         SortArguments {
             maintain_order: true,
             ..
-        } => false,
+        } => polars_pipe::pipeline::is_bounded_top_k(args),
         SortArguments {
             slice: Some((offset, _)),
             ..
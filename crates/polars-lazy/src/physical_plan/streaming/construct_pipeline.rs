@@ -7,7 +7,9 @@ use polars_core::prelude::*;
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
 use polars_pipe::expressions::PhysicalPipedExpr;
 use polars_pipe::operators::chunks::DataChunk;
-use polars_pipe::pipeline::{create_pipeline, get_dummy_operator, get_operator, PipeLine};
+use polars_pipe::pipeline::{
+    create_pipeline, get_dummy_operator, get_operator, MemoryManager, PipeLine, ProgressTracker,
+};
 use polars_pipe::SExecutionContext;
 use polars_utils::IdxSize;
 
@@ -102,6 +104,7 @@ pub(super) fn construct(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    state: &ExecutionState,
 ) -> PolarsResult<Option<Node>> {
     use ALogicalPlan::*;
 
@@ -234,7 +237,7 @@ pub(super) fn construct(
         .get(insertion_location)
         .schema(lp_arena)
         .into_owned();
-    let pipeline_node = get_pipeline_node(lp_arena, most_left, schema, original_lp);
+    let pipeline_node = get_pipeline_node(lp_arena, most_left, schema, original_lp, state);
     lp_arena.replace(insertion_location, pipeline_node);
 
     Ok(Some(final_sink))
@@ -248,6 +251,18 @@ impl SExecutionContext for ExecutionState {
     fn should_stop(&self) -> PolarsResult<()> {
         ExecutionState::should_stop(self)
     }
+
+    fn memory_manager(&self) -> Option<Arc<MemoryManager>> {
+        ExecutionState::memory_manager(self)
+    }
+
+    fn progress_tracker(&self) -> Option<Arc<ProgressTracker>> {
+        ExecutionState::progress_tracker(self)
+    }
+
+    fn plan_profiler(&self) -> Option<Arc<PlanProfiler>> {
+        ExecutionState::plan_profiler(self).cloned()
+    }
 }
 
 fn get_pipeline_node(
@@ -255,6 +270,7 @@ fn get_pipeline_node(
     mut pipeline: PipeLine,
     schema: SchemaRef,
     original_lp: Option<LogicalPlan>,
+    state: &ExecutionState,
 ) -> ALogicalPlan {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -266,10 +282,12 @@ fn get_pipeline_node(
         selection: None,
     });
 
+    let template = state.streaming_state();
+
     ALogicalPlan::MapFunction {
         function: FunctionNode::Pipeline {
             function: Arc::new(move |_df: DataFrame| {
-                let mut state = ExecutionState::new();
+                let mut state = template.streaming_state();
                 if state.verbose() {
                     eprintln!("RUN STREAMING PIPELINE")
                 }
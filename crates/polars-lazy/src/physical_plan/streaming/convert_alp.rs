@@ -3,6 +3,7 @@ use polars_pipe::pipeline::swap_join_order;
 use polars_plan::prelude::*;
 
 use super::checks::*;
+use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::streaming::tree::*;
 
 // The index of the pipeline tree we are building at this moment
@@ -105,6 +106,9 @@ pub(crate) fn insert_streaming_nodes(
     // whether the full plan needs to be translated
     // to streaming
     allow_partial: bool,
+    // the pipelines share the stop token, memory budget and
+    // progress tracker of this state
+    state: &ExecutionState,
 ) -> PolarsResult<bool> {
     scratch.clear();
 
@@ -219,6 +223,15 @@ pub(crate) fn insert_streaming_nodes(
                 state.streamable = true;
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            // The row index needs all preceding rows, so it is a sink
+            MapFunction {
+                input,
+                function: FunctionNode::RowIndex { .. },
+            } => {
+                state.streamable = true;
+                state.operators_sinks.push(PipelineNode::Sink(root));
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
             // Streamable functions will be converted
             lp @ MapFunction { input, function } => {
                 if function.is_streamable() {
@@ -243,8 +256,12 @@ pub(crate) fn insert_streaming_nodes(
             } if scan_type.streamable() => {
                 if state.streamable {
                     #[cfg(feature = "csv")]
-                    if matches!(scan_type, FileScan::Csv { .. }) {
-                        // the batched csv reader doesn't stop exactly at n_rows
+                    let is_csv = matches!(scan_type, FileScan::Csv { .. });
+                    #[cfg(not(feature = "csv"))]
+                    let is_csv = false;
+                    // the batched csv reader and the batches of an anonymous scan
+                    // don't stop exactly at n_rows
+                    if is_csv || matches!(scan_type, FileScan::Anonymous { .. }) {
                         if let Some(n_rows) = options.n_rows {
                             insert_slice(root, 0, n_rows as IdxSize, lp_arena, &mut state);
                         }
@@ -363,8 +380,7 @@ pub(crate) fn insert_streaming_nodes(
                 }
             },
             Distinct { input, options }
-                if !options.maintain_order
-                    && !matches!(options.keep_strategy, UniqueKeepStrategy::None) =>
+                if !matches!(options.keep_strategy, UniqueKeepStrategy::None) =>
             {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Sink(root));
@@ -477,7 +493,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, state)?
+                .is_some()
         {
             inserted = true;
         }
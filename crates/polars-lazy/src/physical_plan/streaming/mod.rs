@@ -1,6 +1,8 @@
+mod auto;
 mod checks;
 mod construct_pipeline;
 mod convert_alp;
 mod tree;
 
+pub(crate) use auto::insert_streaming_nodes_auto;
 pub(crate) use convert_alp::insert_streaming_nodes;
@@ -1,9 +1,27 @@
+#[cfg(feature = "array_distance")]
+pub use polars_ops::chunked_array::array::VectorMetric;
+#[cfg(feature = "fuzzy_join")]
+pub use polars_ops::prelude::FuzzyJoinOptions;
+#[cfg(feature = "string_similarity")]
+pub use polars_ops::prelude::StringDistance;
+#[cfg(feature = "asof_join")]
+pub use polars_ops::prelude::{AsOfOptions, AsofStrategy};
+#[cfg(feature = "iejoin")]
+pub use polars_ops::prelude::{IEJoinOptions, JoinOperator};
+#[cfg(feature = "interval_join")]
+pub use polars_ops::prelude::{IntervalJoinMode, IntervalJoinOptions};
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "serde")]
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan,
-    Null, NULL,
+    register_anonymous_scan, register_dataframe_udf, register_series_udf, register_udf_schema,
+    DataFrameUdf, PlanFormat, UdfSchema, MIN_PLAN_FORMAT_VERSION, PLAN_FORMAT_VERSION,
+};
+pub use polars_plan::logical_plan::{
+    AnonymousBatchIter, AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, ColumnEstimate,
+    ColumnLineage, Literal, LiteralValue, LogicalPlan, NodeProfile, Null, PlanEstimate,
+    PlanProfile, NULL,
 };
 #[cfg(feature = "csv")]
 pub use polars_plan::prelude::CsvWriterOptions;
@@ -23,4 +41,5 @@ pub(crate) use polars_utils::arena::{Arena, Node};
 pub use crate::dsl::*;
 pub use crate::frame::*;
 pub use crate::physical_plan::expressions::*;
+pub use crate::physical_plan::result_cache::ResultCache;
 pub(crate) use crate::scan::*;
@@ -1,9 +1,25 @@
+use polars_core::series::IsSorted;
 use polars_io::RowIndex;
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 
 use super::*;
 
+/// Write `df` to IPC and to parquet in memory and read both back.
+#[cfg(feature = "ipc")]
+pub(super) fn ipc_and_parquet_round_trip(df: &DataFrame) -> PolarsResult<[DataFrame; 2]> {
+    let mut buf = Cursor::new(vec![]);
+    IpcWriter::new(&mut buf).finish(&mut df.clone())?;
+    buf.set_position(0);
+    let ipc = IpcReader::new(buf).finish()?;
+
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf).finish(&mut df.clone())?;
+    buf.set_position(0);
+    let parquet = ParquetReader::new(buf).finish()?;
+    Ok([ipc, parquet])
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 fn test_parquet_exec() -> PolarsResult<()> {
@@ -355,6 +371,156 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_runtime_join_filter() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    // none of the keys of the build side are in the file, so it is never parsed
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let dim = df![
+        "calories" => [2000i64, 3000],
+        "label" => ["a", "b"],
+    ]?;
+    let out = scan_foods_parquet(true)
+        .inner_join(dim.lazy(), col("calories"), col("calories"))
+        .collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.shape(), (0, 5));
+
+    let dim = df![
+        "calories" => [100i64, 150],
+        "label" => ["a", "b"],
+    ]?;
+    for (par, runtime_join_filters) in [(true, true), (false, true), (false, false)] {
+        let out = scan_foods_parquet(par)
+            .inner_join(dim.clone().lazy(), col("calories"), col("calories"))
+            .with_runtime_join_filters(runtime_join_filters)
+            .collect()?;
+        assert_eq!(out.shape(), (4, 5));
+    }
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let out = scan_foods_parquet(false)
+            .join(
+                dim.lazy(),
+                [col("calories")],
+                [col("calories")],
+                JoinType::Semi.into(),
+            )
+            .collect()?;
+        assert_eq!(out.shape(), (4, 4));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "cum_agg"))]
+fn test_parquet_runtime_join_filter_below_aggregation() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    // the filter must not be applied below expressions that see all rows of the scan
+    let dim = df![
+        "calories" => [100i64, 150],
+        "label" => ["a", "b"],
+    ]?;
+    for par in [true, false] {
+        let q = scan_foods_parquet(par)
+            .with_column(col("fats_g").cum_sum(false).alias("cum_fats"))
+            .filter(col("sugars_g").lt_eq(col("sugars_g").mean()))
+            .inner_join(dim.clone().lazy(), col("calories"), col("calories"));
+        let out = q.clone().with_runtime_join_filters(true).collect()?;
+        let expected = q.with_runtime_join_filters(false).collect()?;
+        assert!(out.height() > 0);
+        assert!(out.equals_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimate_scans() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+
+    // parquet files know their size
+    let estimate = scan_foods_parquet(false).estimate()?;
+    assert_eq!(estimate.rows, Some(27));
+    let calories = estimate.column("calories").unwrap();
+    assert_eq!(calories.null_count, Some(0));
+    assert!(calories.byte_size.is_some());
+
+    // CSV files are sampled
+    let estimate = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .estimate()?;
+    let rows = estimate.rows.unwrap();
+    assert!(rows > 0 && rows < 27);
+    assert!(estimate.byte_size().is_some());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_result_cache() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    let q = || {
+        scan_foods_parquet(false)
+            .filter(col("calories").gt(lit(100)))
+            .select([col("category"), col("calories")])
+            .with_result_cache(true)
+    };
+    let expected = q().collect()?;
+    assert!(!ResultCache::global().is_empty());
+
+    // the second query is served from the cache, so the file is never parsed
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = q().collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert!(out?.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_result_cache_unseeded_shuffle() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    let q = |seed: Option<u64>| {
+        scan_foods_parquet(false)
+            .select([col("calories").shuffle(seed)])
+            .with_result_cache(true)
+            .collect()
+    };
+    ResultCache::global().clear();
+    q(None)?;
+    assert!(ResultCache::global().is_empty());
+    q(Some(0))?;
+    assert!(!ResultCache::global().is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_result_cache_runtime_join_filter() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    // the scan is filtered by the keys of the other side, so its result must not be reused
+    let q = |calories: &[i64]| {
+        let dim = df!["calories" => calories]?;
+        scan_foods_parquet(false)
+            .inner_join(dim.lazy(), col("calories"), col("calories"))
+            .with_result_cache(true)
+            .collect()
+    };
+    assert_eq!(q(&[100])?.height(), 3);
+    assert_eq!(q(&[100, 150])?.height(), 4);
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -660,6 +826,68 @@ fn scan_anonymous_fn() -> PolarsResult<()> {
     Ok(())
 }
 
+struct PartitionedScan {
+    partitions: Vec<DataFrame>,
+}
+
+impl AnonymousScan for PartitionedScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        unreachable!("reads are batched")
+    }
+
+    fn n_partitions(&self) -> Option<usize> {
+        Some(self.partitions.len())
+    }
+
+    fn scan_batches(
+        &self,
+        partition: usize,
+        _scan_opts: AnonymousScanArgs,
+    ) -> PolarsResult<AnonymousBatchIter> {
+        let df = self.partitions[partition].clone();
+        Ok(Box::new(std::iter::once(Ok(df))))
+    }
+
+    fn num_rows(&self) -> Option<usize> {
+        Some(self.partitions.iter().map(|df| df.height()).sum())
+    }
+
+    fn sorted_columns(&self) -> Vec<(String, IsSorted)> {
+        vec![("a".into(), IsSorted::Ascending)]
+    }
+}
+
+#[test]
+fn scan_anonymous_batched() -> PolarsResult<()> {
+    let function = Arc::new(PartitionedScan {
+        partitions: vec![
+            df!["a" => [1, 2], "b" => ["x", "y"]]?,
+            df!["a" => [3], "b" => ["z"]]?,
+        ],
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(Arc::new(function.partitions[0].schema())),
+        ..ScanArgsAnonymous::default()
+    };
+    let lf = LazyFrame::anonymous_scan(function, args)?;
+
+    let df = lf.clone().collect()?;
+    assert_eq!(df.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+    assert!(df.equals(&df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]]?));
+
+    #[cfg(feature = "streaming")]
+    {
+        let out = lf.clone().with_streaming(true).collect()?;
+        assert!(optimization_checks::is_pipeline(lf.with_streaming(true)));
+        assert!(out.equals(&df));
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
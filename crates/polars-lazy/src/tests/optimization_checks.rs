@@ -552,3 +552,106 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_join_reorder_star_schema() -> PolarsResult<()> {
+    let fact = df! {
+        "a_id" => (0..100).map(|i| i % 10).collect::<Vec<i32>>(),
+        "b_id" => (0..100).map(|i| i % 20).collect::<Vec<i32>>(),
+        "value" => (0..100).collect::<Vec<i32>>(),
+    }?
+    .lazy();
+    let dim_a = df! {
+        "a_id" => (0..10).collect::<Vec<i32>>(),
+        "a_name" => (0..10).map(|i| format!("a{i}")).collect::<Vec<_>>(),
+    }?
+    .lazy();
+    let dim_b = df! {
+        "b_id" => (0..20).collect::<Vec<i32>>(),
+        "b_label" => (0..20).map(|i| i * 2).collect::<Vec<i32>>(),
+    }?
+    .lazy();
+
+    // the fact table is first and the selective dimension last
+    let q = fact
+        .inner_join(dim_a, col("a_id"), col("a_id"))
+        .inner_join(
+            dim_b.filter(col("b_id").eq(lit(3))),
+            col("b_id"),
+            col("b_id"),
+        )
+        .with_join_reorder(true);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // the filtered dimension is joined with the fact table first
+    assert!((&lp_arena).iter(root).any(|(_, lp)| match lp {
+        ALogicalPlan::Join {
+            input_left,
+            input_right,
+            ..
+        } => {
+            let left = lp_arena.get(*input_left).schema(&lp_arena);
+            let right = lp_arena.get(*input_right).schema(&lp_arena);
+            left.contains("value") && !left.contains("a_name") && right.contains("b_label")
+        },
+        _ => false,
+    }));
+
+    let out = q.clone().sort("value", Default::default()).collect()?;
+    let expected = q
+        .with_join_reorder(false)
+        .sort("value", Default::default())
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["a_id", "b_id", "value", "a_name", "b_label"]
+    );
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 5);
+    Ok(())
+}
+
+#[test]
+fn test_redundant_sort_removed() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [3, 1, 2, 5, 4],
+        "b" => [1, 2, 3, 4, 5],
+    }?
+    .sort(["a"], false, false)?;
+
+    let has_sort = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let root = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena)
+            .iter(root)
+            .any(|(_, lp)| matches!(lp, ALogicalPlan::Sort { .. }))
+    };
+
+    // the sortedness is kept through the filter and the rename
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("b").gt(lit(1)))
+        .select([col("a").alias("x"), col("b")])
+        .sort("x", Default::default());
+    assert!(!has_sort(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(1), Some(2), Some(4), Some(5)]
+    );
+
+    // sorting in another order is not redundant
+    let q = df.clone().lazy().sort(
+        "a",
+        SortOptions {
+            descending: true,
+            ..Default::default()
+        },
+    );
+    assert!(has_sort(q));
+    let q = df.lazy().sort("b", Default::default());
+    assert!(has_sort(q));
+    Ok(())
+}
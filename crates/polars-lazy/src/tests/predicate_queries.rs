@@ -118,6 +118,45 @@ fn filter_blocked_by_map() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn filter_pushed_past_map_with_lineage() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let enrich = |mut df: DataFrame| -> PolarsResult<DataFrame> {
+        let doubled = df.column("A")? * 2;
+        df.with_column(doubled.with_name("doubled"))?;
+        Ok(df)
+    };
+    let schema = |input: &Schema| {
+        let mut schema = input.clone();
+        schema.with_column("doubled".into(), DataType::Int32);
+        Ok(Arc::new(schema))
+    };
+    let lineage = ColumnLineage::new(["A", "fruits", "B", "cars"], ["A"]);
+    let q = df
+        .lazy()
+        .map_with_lineage(enrich, lineage, Some(Arc::new(schema)), None)
+        .filter(col("A").gt(lit(2i32)))
+        .filter(col("doubled").lt(lit(10i32)))
+        .select([col("A"), col("doubled")]);
+
+    assert!(predicate_at_scan(q.clone()));
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        ALogicalPlan::DataFrameScan { projection: Some(projection), .. } if projection.as_slice() == ["A"]
+    )));
+
+    let out = q.collect()?;
+    let expected = df![
+        "A" => [3, 4],
+        "doubled" => [6, 8],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "temporal", feature = "strings"))]
 fn test_strptime_block_predicate() -> PolarsResult<()> {
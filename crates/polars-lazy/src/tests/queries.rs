@@ -1184,6 +1184,245 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "iejoin")]
+#[test]
+fn test_join_where() -> PolarsResult<()> {
+    let sessions = df![
+        "user" => [1, 1, 2, 2, 3],
+        "start" => [Some(0), Some(10), Some(0), None, Some(5)],
+        "end" => [5, 20, 10, 30, 5],
+    ]?
+    .lazy();
+    let events = df![
+        "user" => [1, 2, 2, 1, 3, 1],
+        "ts" => [Some(3), Some(5), Some(10), Some(15), Some(5), None],
+        "v" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    ]?
+    .lazy();
+
+    // the result is ordered as the filtered Cartesian product
+    let check = |predicates: &[Expr]| -> PolarsResult<()> {
+        let out = sessions
+            .clone()
+            .join_where(events.clone(), predicates)
+            .collect()?;
+        let predicate = predicates.iter().cloned().reduce(|a, b| a.and(b)).unwrap();
+        let expected = sessions
+            .clone()
+            .cross_join(events.clone())
+            .filter(predicate)
+            .collect()?;
+        assert!(out.equals_missing(&expected), "{out}\n{expected}");
+        Ok(())
+    };
+    check(&[col("start").lt_eq(col("ts"))])?;
+    check(&[col("ts").gt(col("start"))])?;
+    check(&[col("start").lt_eq(col("ts")), col("end").gt(col("ts"))])?;
+    check(&[col("start").lt(col("ts")), col("end").gt_eq(col("ts"))])?;
+    check(&[
+        col("user").eq(col("user_right")),
+        col("start").lt_eq(col("ts")),
+        col("end").gt(col("ts")),
+    ])?;
+    check(&[col("user")
+        .eq(col("user_right"))
+        .and(col("ts").lt(col("end")))])?;
+    check(&[
+        col("start").lt_eq(col("ts")),
+        col("end").gt(col("ts")),
+        (col("v") * lit(2.0)).gt(col("end")),
+        col("v").gt(lit(1.5)),
+    ])?;
+    #[cfg(feature = "is_between")]
+    check(&[col("ts").is_between(
+        col("start"),
+        col("end"),
+        polars_ops::prelude::ClosedInterval::Left,
+    )])?;
+
+    let q = sessions
+        .clone()
+        .join_where(events.clone(), [col("start").lt_eq(col("ts"))])
+        .select([col("user_right"), col("v")]);
+    let expected = sessions
+        .cross_join(events)
+        .filter(col("start").lt_eq(col("ts")))
+        .select([col("user_right"), col("v")]);
+    assert_eq!(q.collect()?, expected.collect()?);
+    Ok(())
+}
+
+#[cfg(feature = "interval_join")]
+#[test]
+fn test_interval_join() -> PolarsResult<()> {
+    let genes = df![
+        "chrom" => ["1", "1", "2", "1"],
+        "start" => [100, 500, 100, 900],
+        "end" => [200, 800, 300, 950],
+        "gene" => ["a", "b", "c", "d"],
+    ]?
+    .lazy();
+    let reads = df![
+        "chrom" => ["1", "2", "1", "1"],
+        "start" => [150, 250, 600, 180],
+        "end" => [160, 400, 700, 520],
+        "score" => [1, 2, 3, 4],
+    ]?
+    .lazy();
+    let join = |mode: IntervalJoinMode| {
+        let mut options = IntervalJoinOptions::new(mode);
+        options.left_by = Some(vec!["chrom".into()]);
+        options.right_by = Some(vec!["chrom".into()]);
+        genes
+            .clone()
+            .join_builder()
+            .with(reads.clone())
+            .left_on([col("start"), col("end")])
+            .right_on([col("start"), col("end")])
+            .how(JoinType::Interval(options))
+            .finish()
+    };
+
+    let out = join(IntervalJoinMode::Overlaps).collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &[
+            "chrom",
+            "start",
+            "end",
+            "gene",
+            "start_right",
+            "end_right",
+            "score"
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("gene")?.str()?),
+        &[Some("a"), Some("a"), Some("b"), Some("b"), Some("c")]
+    );
+    assert_eq!(
+        Vec::from(out.column("score")?.i32()?),
+        &[Some(1), Some(4), Some(3), Some(4), Some(2)]
+    );
+
+    let out = join(IntervalJoinMode::Contains).collect()?;
+    assert_eq!(Vec::from(out.column("score")?.i32()?), &[Some(1), Some(3)]);
+
+    // the projections and predicates are pushed down as far as they can be
+    for mode in [IntervalJoinMode::Overlaps, IntervalJoinMode::Nearest] {
+        let q = join(mode)
+            .filter(col("score").gt(lit(1)))
+            .select([col("gene"), col("score")]);
+        let expected = q.clone().without_optimizations().collect()?;
+        assert!(q.collect()?.equals_missing(&expected));
+    }
+    let out = join(IntervalJoinMode::Nearest).collect()?;
+    assert_eq!(
+        Vec::from(out.column("score")?.i32()?),
+        &[Some(1), Some(4), Some(2), Some(3)]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "asof_join")]
+#[test]
+fn test_asof_join_tolerance_expr() -> PolarsResult<()> {
+    let trades = df![
+        "time" => [10i64, 20, 30],
+        "ticker" => ["A", "A", "B"],
+        "tick" => [1i64, 2, 0],
+    ]?
+    .lazy();
+    let quotes = df![
+        "time" => [5i64, 10, 20, 27],
+        "ticker" => ["A", "A", "A", "B"],
+        "bid" => [1, 2, 3, 4],
+    ]?
+    .lazy();
+
+    let q = trades
+        .join_builder()
+        .with(quotes)
+        .on([col("time")])
+        .how(JoinType::AsOf(AsOfOptions {
+            left_by: Some(vec!["ticker".into()]),
+            right_by: Some(vec!["ticker".into()]),
+            allow_exact_matches: false,
+            ..Default::default()
+        }))
+        .asof_tolerance(col("tick") * lit(5))
+        .finish();
+
+    let out = q.clone().collect()?;
+    assert_eq!(out.get_column_names(), &["time", "ticker", "tick", "bid"]);
+    assert_eq!(
+        Vec::from(out.column("bid")?.i32()?),
+        &[Some(1), Some(2), None]
+    );
+
+    let q = q.select([col("bid")]);
+    let expected = q.clone().without_optimizations().collect()?;
+    assert!(q.collect()?.equals_missing(&expected));
+    Ok(())
+}
+
+#[cfg(feature = "fuzzy_join")]
+#[test]
+fn test_fuzzy_join() -> PolarsResult<()> {
+    let customers = df![
+        "name" => ["Jonathan Smith", "Ann", "Bob"],
+        "id" => [1, 2, 3],
+    ]?
+    .lazy();
+    let orders = df![
+        "name" => ["jonathon smith", "anne", "Rob", "carl"],
+        "order" => [10, 20, 30, 40],
+    ]?
+    .lazy();
+
+    let q = customers
+        .join_builder()
+        .with(orders)
+        .left_on([col("name").str().to_lowercase()])
+        .right_on([col("name")])
+        .how(JoinType::Fuzzy(FuzzyJoinOptions::new(
+            StringDistance::Levenshtein,
+            1.0,
+        )))
+        .finish()
+        .with_column(
+            col("name")
+                .str()
+                .to_lowercase()
+                .str()
+                .levenshtein_distance(col("name_right"))
+                .alias("distance"),
+        );
+
+    let out = q.clone().collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["name", "id", "name_right", "order", "distance"]
+    );
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("order")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+    assert_eq!(
+        Vec::from(out.column("distance")?.u32()?),
+        &[Some(1), Some(1), Some(1)]
+    );
+
+    let q = q.select([col("order")]);
+    let expected = q.clone().without_optimizations().collect()?;
+    assert!(q.collect()?.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1922,3 +2161,421 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_explain_analyze() -> PolarsResult<()> {
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .group_by([col("fruits")])
+        .agg([col("A").sum()]);
+
+    let (out, profile) = q.clone().profile_plan()?;
+    let root = profile.nodes()[0].as_ref().unwrap();
+    assert_eq!(root.rows_out, out.height());
+    assert_eq!(root.rows_in, Some(4));
+
+    // the filter is pushed down into the scan
+    let scan = profile.nodes().last().unwrap().as_ref().unwrap();
+    assert_eq!(scan.rows_in, None);
+    assert_eq!(scan.rows_out, 4);
+
+    assert!(profile.describe_tree_format().contains("rows: 4 -> 2"));
+    assert!(profile.to_dot()?.contains("peak memory:"));
+    assert!(q.explain_analyze()?.contains("time:"));
+    Ok(())
+}
+
+#[test]
+fn test_estimate() -> PolarsResult<()> {
+    let a = (0..1000).collect::<Vec<i32>>();
+    let b = a.iter().map(|v| v % 10).collect::<Vec<_>>();
+    let c = a
+        .iter()
+        .map(|v| (v % 2 == 0).then_some(*v))
+        .collect::<Vec<_>>();
+    let df = df![
+        "a" => a,
+        "b" => b,
+        "c" => c,
+    ]?;
+
+    let estimate = df.clone().lazy().estimate()?;
+    assert_eq!(estimate.rows, Some(1000));
+    assert_eq!(estimate.column("a").unwrap().distinct_count, Some(1000));
+    assert_eq!(estimate.column("b").unwrap().distinct_count, Some(10));
+    assert_eq!(estimate.column("c").unwrap().null_count, Some(500));
+    assert_eq!(estimate.column("a").unwrap().byte_size, Some(4000));
+
+    // the groups are bounded by the distinct keys
+    let estimate = df
+        .clone()
+        .lazy()
+        .group_by([col("b")])
+        .agg([col("a").sum()])
+        .estimate()?;
+    assert_eq!(estimate.rows, Some(10));
+
+    let estimate = df
+        .lazy()
+        .filter(col("a").gt(lit(500)))
+        .select([col("a"), col("c")])
+        .estimate()?;
+    let rows = estimate.rows.unwrap();
+    assert!(rows > 0 && rows < 1000);
+    assert_eq!(estimate.columns.len(), 2);
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_plan_round_trip() -> PolarsResult<()> {
+    struct AddN(i32);
+
+    impl SeriesUdf for AddN {
+        fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+            Ok(Some(&s[0] + self.0))
+        }
+
+        fn try_serialize(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+            buf.extend_from_slice(&self.0.to_le_bytes());
+            Ok(())
+        }
+
+        fn registry_name(&self) -> Option<&str> {
+            Some("add_n")
+        }
+
+        fn get_output(&self) -> Option<GetOutput> {
+            Some(GetOutput::same_type())
+        }
+    }
+
+    register_series_udf("add_n", |buf| {
+        let n = buf
+            .try_into()
+            .map_err(|_| polars_err!(ComputeError: "invalid add_n payload"))?;
+        Ok(Arc::new(AddN(i32::from_le_bytes(n))))
+    });
+
+    let add_n = Expr::AnonymousFunction {
+        input: vec![col("A")],
+        function: SpecialEq::new(Arc::new(AddN(10))),
+        output_type: GetOutput::same_type(),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            fmt_str: "add_n",
+            ..Default::default()
+        },
+    };
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("fruits").eq(lit("banana")))
+        .select([add_n, col("B")]);
+    let expected = q.clone().collect()?;
+
+    for format in [PlanFormat::Binary, PlanFormat::Json] {
+        let mut buf = vec![];
+        q.serialize_plan(&mut buf, format)?;
+        let out = LazyFrame::deserialize_plan(buf.as_slice(), format)?.collect()?;
+        assert!(out.equals(&expected));
+    }
+
+    // closures can't be serialized
+    let q = fruits_cars()
+        .lazy()
+        .select([col("A").map(|s| Ok(Some(s)), GetOutput::same_type())]);
+    assert!(q.serialize_plan(vec![], PlanFormat::Json).is_err());
+
+    let err = LazyFrame::deserialize_plan(
+        r#"{"version": 999, "plan": null}"#.as_bytes(),
+        PlanFormat::Json,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("format version 999"));
+
+    let mut buf = b"POLARS_PLAN".to_vec();
+    buf.extend_from_slice(&999u32.to_le_bytes());
+    let err = LazyFrame::deserialize_plan(buf.as_slice(), PlanFormat::Binary).unwrap_err();
+    assert!(err.to_string().contains("format version 999"));
+
+    let err =
+        LazyFrame::deserialize_plan(b"not a plan".as_slice(), PlanFormat::Binary).unwrap_err();
+    assert!(err.to_string().contains("not a serialized polars plan"));
+    Ok(())
+}
+
+#[cfg(all(feature = "dtype-array", feature = "list_eval", feature = "diff"))]
+#[test]
+fn test_array_namespace() -> PolarsResult<()> {
+    let a = Series::new(
+        "a",
+        [Series::new("", [1i64, 2, 4]), Series::new("", [3i64, 3, 9])],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Int64), 3))?;
+    let df = DataFrame::new(vec![a])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").arr().slice(-2, 2).alias("slice"),
+            col("a").arr().diff(1, NullBehavior::Drop).alias("diff"),
+            col("a").arr().mean().alias("mean"),
+            col("a").arr().n_unique().alias("n_unique"),
+            col("a")
+                .arr()
+                .concat([col("a").arr().head(1)])
+                .alias("concat"),
+            col("a").arr().eval(col("") * lit(2), false).alias("eval"),
+        ])
+        .collect()?;
+
+    let schema = out.schema();
+    let array = |width| DataType::Array(Box::new(DataType::Int64), width);
+    assert_eq!(schema.get("slice"), Some(&array(2)));
+    assert_eq!(schema.get("diff"), Some(&array(2)));
+    assert_eq!(schema.get("concat"), Some(&array(4)));
+    assert_eq!(schema.get("eval"), Some(&array(3)));
+
+    let values = |name: &str| -> PolarsResult<Vec<Option<i64>>> {
+        Ok(out
+            .column(name)?
+            .array()?
+            .get_inner()
+            .i64()?
+            .into_iter()
+            .collect())
+    };
+    assert_eq!(values("slice")?, &[Some(2), Some(4), Some(3), Some(9)]);
+    assert_eq!(values("diff")?, &[Some(1), Some(2), Some(0), Some(6)]);
+    assert_eq!(
+        values("concat")?,
+        &[
+            Some(1),
+            Some(2),
+            Some(4),
+            Some(1),
+            Some(3),
+            Some(3),
+            Some(9),
+            Some(3)
+        ]
+    );
+    assert_eq!(
+        values("eval")?,
+        &[Some(2), Some(4), Some(8), Some(6), Some(6), Some(18)]
+    );
+    let mean = out.column("mean")?.f64()?;
+    assert!((mean.get(0).unwrap() - 7.0 / 3.0).abs() < 1e-12);
+    assert_eq!(mean.get(1), Some(5.0));
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.idx()?),
+        &[Some(3), Some(2)]
+    );
+
+    // Aggregations change the number of elements.
+    let q = out
+        .lazy()
+        .select([col("slice").arr().eval(col("").sum(), false)]);
+    assert!(q.collect().is_err());
+    Ok(())
+}
+
+#[cfg(feature = "array_distance")]
+#[test]
+fn test_array_distance() -> PolarsResult<()> {
+    let vectors = Series::new(
+        "v",
+        [
+            Series::new("", [1.0f32, 0.0]),
+            Series::new("", [0.0f32, 2.0]),
+            Series::new("", [3.0f32, 4.0]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float32), 2))?;
+    let query = Series::new("", [Series::new("", [1.0f32, 1.0])])
+        .cast(&DataType::Array(Box::new(DataType::Float32), 2))?;
+    let df = DataFrame::new(vec![vectors])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v").arr().dot(lit(query.clone())).alias("dot"),
+            col("v").arr().cosine_similarity(col("v")).alias("cosine"),
+            col("v").arr().l2_distance(lit(query.clone())).alias("l2"),
+            col("v").arr().normalize().alias("normalized"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("dot")?.f32()?),
+        &[Some(1.0), Some(2.0), Some(7.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cosine")?.f32()?),
+        &[Some(1.0), Some(1.0), Some(1.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("l2")?.f32()?),
+        &[Some(1.0), Some(2.0f32.sqrt()), Some(13.0f32.sqrt())]
+    );
+    let normalized = out.column("normalized")?.array()?.get_inner();
+    assert_eq!(
+        Vec::from(normalized.f32()?),
+        &[
+            Some(1.0),
+            Some(0.0),
+            Some(0.0),
+            Some(1.0),
+            Some(0.6),
+            Some(0.8)
+        ]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("v")
+            .arr()
+            .nearest_neighbours(lit(query), 2, VectorMetric::L2)])
+        .collect()?;
+    let neighbours = out.column("v")?.struct_()?;
+    let index = neighbours.field_by_name("index")?;
+    assert_eq!(
+        Vec::from(index.list()?.get_as_series(0).unwrap().idx()?),
+        &[Some(0), Some(1)]
+    );
+    let distance = neighbours.field_by_name("distance")?;
+    assert_eq!(
+        Vec::from(distance.list()?.get_as_series(0).unwrap().f32()?),
+        &[Some(1.0), Some(2.0f32.sqrt())]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "dtype-map")]
+#[test]
+fn test_map_namespace() -> PolarsResult<()> {
+    use polars_ops::prelude::MapNameSpace;
+
+    let entries = |keys: &[&str], values: &[i32]| {
+        StructChunked::new("", &[Series::new("k", keys), Series::new("v", values)])
+            .unwrap()
+            .into_series()
+    };
+    let attrs = Series::new(
+        "attrs",
+        [entries(&["x", "y"], &[1, 2]), entries(&["y"], &[3])],
+    );
+    let attrs = attrs.list()?.clone().into_map()?.into_series();
+    assert_eq!(
+        attrs.dtype(),
+        &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+    );
+    let df = DataFrame::new(vec![attrs])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("attrs").map_().get(lit("x")).alias("x"),
+            col("attrs").map_().contains_key(lit("x")).alias("has_x"),
+            col("attrs").map_().keys().alias("keys"),
+            col("attrs").map_().values().alias("values"),
+            col("attrs")
+                .map_()
+                .to_struct(vec!["x".into(), "y".into()])
+                .alias("struct"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(1), None]);
+    assert_eq!(
+        Vec::from(out.column("has_x")?.bool()?),
+        &[Some(true), Some(false)]
+    );
+    let keys = out.column("keys")?.list()?.get_as_series(0).unwrap();
+    assert_eq!(Vec::from(keys.str()?), &[Some("x"), Some("y")]);
+    let values = out.column("values")?.list()?.get_as_series(1).unwrap();
+    assert_eq!(Vec::from(values.i32()?), &[Some(3)]);
+    let y = out.column("struct")?.struct_()?.field_by_name("y")?;
+    assert_eq!(Vec::from(y.i32()?), &[Some(2), Some(3)]);
+
+    // Structs become maps from the field names to the values.
+    let out = df!("a" => [1, 2], "b" => [3, 4])?
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")])
+            .struct_()
+            .to_map()
+            .map_()
+            .get(lit("b"))])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(4)]);
+
+    // The map annotation survives a round-trip through IPC and parquet.
+    #[cfg(all(feature = "ipc", feature = "parquet"))]
+    for out in super::io::ipc_and_parquet_round_trip(&df)? {
+        let attrs = out.column("attrs")?;
+        assert_eq!(attrs.dtype(), df.column("attrs")?.dtype());
+        let y = attrs.map()?.map_get(&Series::new("", ["y"]))?;
+        assert_eq!(Vec::from(y.i32()?), &[Some(2), Some(3)]);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "dtype-union")]
+#[test]
+fn test_union_namespace() -> PolarsResult<()> {
+    let df = df!(
+        "int" => [Some(1i64), None, None],
+        "str" => [None, Some("a"), None]
+    )?;
+    let df = df
+        .lazy()
+        .select([as_union(vec![col("int"), col("str")]).alias("value")])
+        .collect()?;
+    let dtype = DataType::Union(vec![
+        Field::new("int", DataType::Int64),
+        Field::new("str", DataType::String),
+    ]);
+    let value = df.column("value")?;
+    assert_eq!(value.dtype(), &dtype);
+    assert_eq!(value.null_count(), 1);
+    assert_eq!(value.get(0)?, AnyValue::Int64(1));
+    assert_eq!(value.get(1)?, AnyValue::String("a"));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("value").union_().tag().alias("tag"),
+            col("value").union_().field("str").alias("str"),
+            col("value").cast(DataType::String).alias("cast"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("tag")?.str()?),
+        &[Some("int"), Some("str"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("str")?.str()?),
+        &[None, Some("a"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("cast")?.str()?),
+        &[Some("1"), Some("a"), None]
+    );
+
+    // Other columns become the variant of their dtype.
+    let s = Series::new("", [2i64]).cast(&dtype)?;
+    assert_eq!(Vec::from(&s.union()?.tag()), &[Some("int")]);
+
+    // The union survives a round-trip through IPC and parquet.
+    #[cfg(all(feature = "ipc", feature = "parquet"))]
+    for out in super::io::ipc_and_parquet_round_trip(&df)? {
+        let value = out.column("value")?;
+        assert_eq!(value.dtype(), &dtype);
+        let tag = value.union()?.tag();
+        assert_eq!(Vec::from(&tag), &[Some("int"), Some("str"), None]);
+        assert_eq!(value.get(1)?, AnyValue::String("a"));
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::sync::Mutex;
+
 use super::*;
 
 fn get_csv_file() -> LazyFrame {
@@ -388,3 +390,227 @@ fn test_sort_maintain_order_streaming() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_streaming_sort_memory_limit() -> PolarsResult<()> {
+    // a budget of a single byte forces the sort to go out-of-core
+    let q = get_csv_glob().sort("sugars_g", Default::default());
+    let out = q
+        .clone()
+        .with_memory_limit(Some(1))
+        .with_streaming(true)
+        .collect()?;
+    let expected = q.collect()?;
+    assert!(out.column("sugars_g")?.equals(expected.column("sugars_g")?));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sort_multiple_memory_limit() -> PolarsResult<()> {
+    let q = df![
+        "a" => [Some(1), None, Some(3), Some(1), None, Some(2)],
+        "b" => ["a", "b", "c", "d", "e", "f"],
+        "c" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    ]?
+    .lazy()
+    .sort_by_exprs([col("a"), col("b")], [false, true], true, false);
+
+    // a budget of a single byte forces the sort to go out-of-core
+    let out = q
+        .clone()
+        .with_memory_limit(Some(1))
+        .with_streaming(true)
+        .collect()?;
+    let expected = q.collect()?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sort_multiple_memory_limit_repeated_first_key() -> PolarsResult<()> {
+    // few distinct categories, so the partitions must also split on the next keys
+    let q = get_csv_glob().sort_by_exprs(
+        [col("category"), col("sugars_g"), col("calories")],
+        [false, true, false],
+        false,
+        false,
+    );
+    let out = q
+        .clone()
+        .with_memory_limit(Some(1))
+        .with_streaming(true)
+        .collect()?;
+    let expected = q.collect()?;
+    for name in ["category", "sugars_g", "calories"] {
+        assert!(out.column(name)?.equals(expected.column(name)?));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_join_memory_limit() -> PolarsResult<()> {
+    let lf_left = df![
+        "a" => [0, 1, 2, 3],
+        "b" => [0, 1, 2, 3],
+    ]?
+    .lazy();
+    let lf_right = df![
+        "a" => [0, 1, 1, 2],
+        "c" => [0, 1, 2, 3],
+    ]?
+    .lazy();
+
+    // the build side cannot spill, so it must fit in the budget
+    let q = lf_left.inner_join(lf_right, col("a"), col("a"));
+    assert!(q
+        .clone()
+        .with_memory_limit(Some(1))
+        .with_streaming(true)
+        .collect()
+        .is_err());
+    assert_streaming_with_default(q.with_memory_limit(Some(1 << 20)), true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_progress() -> PolarsResult<()> {
+    let progress = Arc::new(Mutex::new(None));
+    let progress_c = progress.clone();
+    let hooks = CollectHooks::new().with_progress(move |p| {
+        *progress_c.lock().unwrap() = Some(p.clone());
+    });
+
+    let out = get_csv_file()
+        .filter(col("sugars_g").gt(lit(1)))
+        .with_streaming(true)
+        .collect_with_hooks(hooks)?;
+
+    let progress = progress.lock().unwrap().take().unwrap();
+    assert_eq!(progress.rows_read[0].rows, 27);
+    assert_eq!(progress.rows_emitted[0].rows, out.height());
+    Ok(())
+}
+
+#[test]
+fn test_streaming_cancelled() -> PolarsResult<()> {
+    let token = CancellationToken::new();
+    token.cancel();
+    let hooks = CollectHooks::new().with_cancellation_token(token);
+
+    let out = get_csv_file()
+        .sort("sugars_g", Default::default())
+        .with_streaming(true)
+        .collect_with_hooks(hooks);
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_streaming_unique_maintain_order() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .select([col("sugars_g"), col("calories")])
+        .unique_stable(Some(vec!["sugars_g".into()]), UniqueKeepStrategy::First);
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_explode() -> PolarsResult<()> {
+    let q = df![
+        "a" => [1, 2, 3],
+        "b" => [
+            Series::new("", [1, 2]),
+            Series::new("", [3]),
+            Series::new("", [4, 5, 6]),
+        ],
+    ]?
+    .lazy()
+    .explode([col("b")]);
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_row_index_after_filter() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .filter(col("calories").gt(lit(50)))
+        .with_row_index("index", Some(10));
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_top_k() -> PolarsResult<()> {
+    let by = [
+        col("calories"),
+        col("fats_g"),
+        col("sugars_g"),
+        col("category"),
+    ];
+
+    let q = get_csv_glob().top_k(5, by.clone(), [false], false, false);
+    assert_streaming_with_default(q, true, false);
+
+    let q = get_csv_glob().bottom_k(5, by.clone(), [false], true, false);
+    assert_streaming_with_default(q, true, false);
+
+    // a sorted slice with an offset
+    let q = get_csv_glob()
+        .sort_by_exprs(by, [true], false, false)
+        .slice(3, 4);
+    assert_streaming_with_default(q, true, false);
+
+    // equal keys keep the order in which they were streamed
+    let q = get_csv_glob()
+        .sort_by_exprs([col("category")], [false], false, true)
+        .slice(2, 20);
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+fn test_streaming_explain_analyze() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .select([col("sugars_g"), col("calories")])
+        .filter(col("sugars_g").gt(lit(10)))
+        .group_by([col("sugars_g")])
+        .agg([col("calories").sum()])
+        .with_streaming(true);
+    assert!(optimization_checks::is_pipeline(q.clone()));
+
+    let (out, profile) = q.profile_plan()?;
+    let root = profile.nodes()[0].as_ref().unwrap();
+    assert_eq!(root.rows_out, out.height());
+
+    let scan = profile.nodes().last().unwrap().as_ref().unwrap();
+    assert_eq!(scan.rows_in, None);
+    assert!(scan.rows_out >= out.height());
+    assert!(profile.describe_tree_format().contains("peak memory:"));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_auto() -> PolarsResult<()> {
+    let q = get_csv_glob()
+        .filter(col("sugars_g").gt(lit(1)))
+        .sort("calories", Default::default());
+
+    // the query fits easily in the free memory
+    let q_auto = q.clone().with_auto_streaming(true);
+    assert!(!optimization_checks::has_pipeline(q_auto.clone()));
+    assert_eq!(q_auto.collect()?, q.clone().collect()?);
+
+    // a budget of a single byte moves it to the streaming engine
+    let q_auto = q
+        .clone()
+        .with_auto_streaming(true)
+        .with_memory_limit(Some(1));
+    assert!(optimization_checks::is_pipeline(q_auto.clone()));
+    let out = q_auto.collect()?;
+    let expected = q.collect()?;
+    assert!(out.column("calories")?.equals(expected.column("calories")?));
+    Ok(())
+}
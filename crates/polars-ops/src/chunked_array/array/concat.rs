@@ -0,0 +1,58 @@
+use arrow::bitmap::Bitmap;
+use polars_core::utils::try_get_supertype;
+
+use super::slice::{from_values, values_and_validity};
+use super::*;
+
+/// Concatenate the sub-arrays of `ca` and `other` row by row. Arrays of length 1 are broadcast and
+/// a row is null if it is null in any of the arrays.
+pub fn array_concat(ca: &ArrayChunked, other: &[Series]) -> PolarsResult<ArrayChunked> {
+    let mut arrays = vec![ca.clone()];
+    for s in other {
+        arrays.push(s.array()?.clone());
+    }
+    let len = arrays.iter().map(|ca| ca.len()).max().unwrap();
+    let mut inner_dtype = ca.inner_dtype();
+    for ca in &arrays {
+        polars_ensure!(
+            ca.len() == len || ca.len() == 1,
+            ShapeMismatch: "`arr.concat` got arrays of length {} and {}", ca.len(), len
+        );
+        inner_dtype = try_get_supertype(&inner_dtype, &ca.inner_dtype())?;
+    }
+
+    let mut values = Series::new_empty(ca.name(), &inner_dtype);
+    let mut parts = Vec::with_capacity(arrays.len());
+    for ca in &arrays {
+        let (inner, validity) = values_and_validity(ca);
+        let start = values.len() as IdxSize;
+        values.append(&inner.cast(&inner_dtype)?)?;
+        parts.push((start, ca.width() as IdxSize, ca.len() == 1, validity));
+    }
+
+    let width: IdxSize = parts.iter().map(|(_, width, _, _)| width).sum();
+    let mut idx = Vec::with_capacity(len * width as usize);
+    for row in 0..len as IdxSize {
+        for (start, width, broadcast, _) in &parts {
+            let row = if *broadcast { 0 } else { row };
+            let start = start + row * width;
+            idx.extend(start..start + width);
+        }
+    }
+    let validity = parts
+        .iter()
+        .any(|(_, _, _, validity)| validity.is_some())
+        .then(|| {
+            (0..len)
+                .map(|row| {
+                    parts.iter().all(|(_, _, broadcast, validity)| {
+                        let row = if *broadcast { 0 } else { row };
+                        validity.as_ref().map_or(true, |v| v.get_bit(row))
+                    })
+                })
+                .collect::<Bitmap>()
+        });
+
+    let values = values.take(&IdxCa::from_vec("", idx))?;
+    from_values(ca.name(), &values, width as usize, validity)
+}
@@ -0,0 +1,42 @@
+use polars_core::series::ops::NullBehavior;
+
+use super::slice::{from_values, select_columns, values_and_validity};
+use super::*;
+use crate::prelude::diff;
+
+/// The differences within every sub-array, computed on the inner values at once. The elements
+/// that would take their predecessor from another sub-array are null or dropped.
+pub(super) fn array_diff(
+    ca: &ArrayChunked,
+    n: i64,
+    null_behavior: NullBehavior,
+) -> PolarsResult<ArrayChunked> {
+    let width = ca.width();
+    let (values, validity) = values_and_validity(ca);
+    let diffed = diff(&values, n, NullBehavior::Ignore)?;
+
+    let shift = n.unsigned_abs() as usize;
+    let has_predecessor = |col: usize| {
+        if n >= 0 {
+            col >= shift
+        } else {
+            col + shift < width
+        }
+    };
+    match null_behavior {
+        NullBehavior::Ignore => {
+            let idx: IdxCa = (0..values.len())
+                .map(|i| has_predecessor(i % width).then_some(i as IdxSize))
+                .collect();
+            from_values(ca.name(), &diffed.take(&idx)?, width, validity)
+        },
+        NullBehavior::Drop => {
+            polars_ensure!(n > 0, InvalidOperation: "only positive integer allowed if nulls are dropped in 'diff' operation");
+            let columns: Vec<IdxSize> = (0..width)
+                .filter(|col| has_predecessor(*col))
+                .map(|col| col as IdxSize)
+                .collect();
+            select_columns(ca, &diffed, validity, &columns)
+        },
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::types::NativeType;
+use num_traits::{Float, One, Zero};
+use polars_compute::distance::DistanceKernel;
+use polars_core::{with_match_physical_float_polars_type, POOL};
+use polars_utils::total_ord::TotalOrd;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::slice::from_values;
+use super::*;
+
+/// The distance that nearest neighbours are ranked by.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VectorMetric {
+    /// The Euclidean distance.
+    #[default]
+    L2,
+    /// One minus the cosine similarity.
+    Cosine,
+}
+
+impl Display for VectorMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            VectorMetric::L2 => "l2",
+            VectorMetric::Cosine => "cosine",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The sub-arrays of an array column as float slices. Sub-arrays that are null or contain a null
+/// are missing.
+struct Vectors<T: NativeType> {
+    values: Buffer<T>,
+    validity: Option<Bitmap>,
+    width: usize,
+    len: usize,
+}
+
+impl<T: NativeType> Vectors<T> {
+    fn new<P: PolarsNumericType<Native = T>>(ca: &ArrayChunked) -> PolarsResult<Self> {
+        let width = ca.width();
+        let ca = ca.cast(&DataType::Array(Box::new(P::get_dtype()), width))?;
+        let ca = ca.array()?.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let values = arr
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap();
+
+        let mut validity = arr.validity().cloned();
+        if let Some(inner) = values.validity().filter(|v| v.unset_bits() > 0) {
+            validity = Some(
+                (0..arr.len())
+                    .map(|row| {
+                        validity.as_ref().map_or(true, |v| v.get_bit(row))
+                            && (row * width..(row + 1) * width).all(|i| inner.get_bit(i))
+                    })
+                    .collect(),
+            );
+        }
+        Ok(Self {
+            values: values.values().clone(),
+            validity,
+            width,
+            len: arr.len(),
+        })
+    }
+
+    fn get(&self, row: usize) -> Option<&[T]> {
+        self.validity
+            .as_ref()
+            .map_or(true, |v| v.get_bit(row))
+            .then(|| &self.values[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Get a row of a column that is broadcast if it has a single row.
+    fn get_broadcast(&self, row: usize) -> Option<&[T]> {
+        self.get(if self.len == 1 { 0 } else { row })
+    }
+}
+
+fn norm<T: DistanceKernel + Float>(a: &[T]) -> T {
+    T::dot_kernel(a, a).sqrt()
+}
+
+fn cosine_similarity<T: DistanceKernel + Float>(a: &[T], b: &[T]) -> T {
+    T::dot_kernel(a, b) / (norm(a) * norm(b))
+}
+
+fn l2_distance<T: DistanceKernel + Float>(a: &[T], b: &[T]) -> T {
+    T::squared_l2_kernel(a, b).sqrt()
+}
+
+/// The float type the kernels run in: `Float32` if all inputs are `Float32`.
+fn float_dtype(arrays: &[&ArrayChunked]) -> DataType {
+    if arrays
+        .iter()
+        .all(|ca| ca.inner_dtype() == DataType::Float32)
+    {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    }
+}
+
+fn check_widths(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        a.width() == b.width(),
+        ShapeMismatch: "vectors should have the same width, got {} and {}", a.width(), b.width()
+    );
+    Ok(())
+}
+
+fn binary_elementwise<T, F>(a: &ArrayChunked, b: &ArrayChunked, f: F) -> PolarsResult<Series>
+where
+    T: PolarsFloatType,
+    T::Native: DistanceKernel,
+    ChunkedArray<T>: IntoSeries,
+    F: Fn(&[T::Native], &[T::Native]) -> T::Native,
+{
+    check_widths(a, b)?;
+    let len = match (a.len(), b.len()) {
+        (a_len, b_len) if a_len == b_len || b_len == 1 => a_len,
+        (1, b_len) => b_len,
+        (a_len, b_len) => {
+            polars_bail!(ShapeMismatch: "cannot compare {} vectors with {} vectors", a_len, b_len)
+        },
+    };
+    let a_vectors = Vectors::new::<T>(a)?;
+    let b_vectors = Vectors::new::<T>(b)?;
+    let out: ChunkedArray<T> = (0..len)
+        .map(|row| {
+            let a = a_vectors.get_broadcast(row)?;
+            let b = b_vectors.get_broadcast(row)?;
+            Some(f(a, b))
+        })
+        .collect();
+    Ok(out.with_name(a.name()).into_series())
+}
+
+/// The dot product of the sub-arrays of `a` and `b`. A single sub-array is compared with all rows.
+pub fn array_dot(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    with_match_physical_float_polars_type!(float_dtype(&[a, b]), |$T| {
+        binary_elementwise::<$T, _>(a, b, DistanceKernel::dot_kernel)
+    })
+}
+
+/// The cosine similarity of the sub-arrays of `a` and `b`. A single sub-array is compared with
+/// all rows.
+pub fn array_cosine_similarity(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    with_match_physical_float_polars_type!(float_dtype(&[a, b]), |$T| {
+        binary_elementwise::<$T, _>(a, b, cosine_similarity)
+    })
+}
+
+/// The Euclidean distance between the sub-arrays of `a` and `b`. A single sub-array is compared
+/// with all rows.
+pub fn array_l2_distance(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    with_match_physical_float_polars_type!(float_dtype(&[a, b]), |$T| {
+        binary_elementwise::<$T, _>(a, b, l2_distance)
+    })
+}
+
+fn normalize<T>(ca: &ArrayChunked) -> PolarsResult<ArrayChunked>
+where
+    T: PolarsFloatType,
+    T::Native: DistanceKernel + Float,
+    ChunkedArray<T>: IntoSeries,
+{
+    let vectors = Vectors::new::<T>(ca)?;
+    let mut values = Vec::with_capacity(vectors.values.len());
+    for row in 0..vectors.len {
+        match vectors.get(row) {
+            Some(v) => {
+                let norm = norm(v);
+                if norm > T::Native::zero() {
+                    values.extend(v.iter().map(|x| *x / norm))
+                } else {
+                    values.extend_from_slice(v)
+                }
+            },
+            None => values.extend(std::iter::repeat(T::Native::zero()).take(vectors.width)),
+        }
+    }
+    let values = ChunkedArray::<T>::from_vec(ca.name(), values).into_series();
+    from_values(ca.name(), &values, vectors.width, vectors.validity)
+}
+
+/// Scale every sub-array to unit length. Vectors of length zero are left as they are.
+pub fn array_normalize(ca: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    with_match_physical_float_polars_type!(float_dtype(&[ca]), |$T| {
+        normalize::<$T>(ca)
+    })
+}
+
+fn nearest_neighbours<T>(
+    corpus: &ArrayChunked,
+    queries: &ArrayChunked,
+    k: usize,
+    metric: VectorMetric,
+) -> PolarsResult<StructChunked>
+where
+    T: PolarsFloatType,
+    T::Native: DistanceKernel + Float,
+{
+    check_widths(corpus, queries)?;
+    let corpus_vectors = Vectors::new::<T>(corpus)?;
+    let query_vectors = Vectors::new::<T>(queries)?;
+    // The norms of the corpus are shared by all queries.
+    let corpus_norms: Vec<T::Native> = match metric {
+        VectorMetric::L2 => vec![],
+        VectorMetric::Cosine => (0..corpus_vectors.len)
+            .map(|row| corpus_vectors.get(row).map_or(T::Native::zero(), norm))
+            .collect(),
+    };
+
+    let search = |query: &[T::Native]| {
+        let query_norm = norm(query);
+        let mut candidates: Vec<(IdxSize, T::Native)> = (0..corpus_vectors.len)
+            .filter_map(|row| {
+                let v = corpus_vectors.get(row)?;
+                let distance = match metric {
+                    // Ranking by the squared distance saves a square root per candidate.
+                    VectorMetric::L2 => T::Native::squared_l2_kernel(query, v),
+                    VectorMetric::Cosine => {
+                        T::Native::one()
+                            - T::Native::dot_kernel(query, v) / (query_norm * corpus_norms[row])
+                    },
+                };
+                Some((row as IdxSize, distance))
+            })
+            .collect();
+
+        let cmp = |a: &(IdxSize, T::Native), b: &(IdxSize, T::Native)| {
+            a.1.tot_cmp(&b.1).then(a.0.cmp(&b.0))
+        };
+        if candidates.len() > k {
+            candidates.select_nth_unstable_by(k, cmp);
+            candidates.truncate(k);
+        }
+        candidates.sort_unstable_by(cmp);
+        if metric == VectorMetric::L2 {
+            for (_, distance) in candidates.iter_mut() {
+                *distance = distance.sqrt();
+            }
+        }
+        candidates
+    };
+    let neighbours = POOL.install(|| {
+        (0..query_vectors.len)
+            .into_par_iter()
+            .map(|row| query_vectors.get(row).map(search))
+            .collect::<Vec<_>>()
+    });
+
+    let len = neighbours.len();
+    let mut indices = ListPrimitiveChunkedBuilder::<IdxType>::new("index", len, len * k, IDX_DTYPE);
+    let mut distances =
+        ListPrimitiveChunkedBuilder::<T>::new("distance", len, len * k, T::get_dtype());
+    for row in neighbours {
+        match row {
+            Some(row) => {
+                indices.append_iter_values(row.iter().map(|(idx, _)| *idx));
+                distances.append_iter_values(row.iter().map(|(_, distance)| *distance));
+            },
+            None => {
+                indices.append_null();
+                distances.append_null();
+            },
+        }
+    }
+    StructChunked::new(
+        corpus.name(),
+        &[
+            indices.finish().into_series(),
+            distances.finish().into_series(),
+        ],
+    )
+}
+
+/// Find the `k` sub-arrays of `corpus` that are nearest to every sub-array of `queries` by an
+/// exact search. Returns a struct with the `index` of the neighbours in `corpus` and their
+/// `distance`, ordered from nearest to farthest.
+pub fn array_nearest_neighbours(
+    corpus: &ArrayChunked,
+    queries: &ArrayChunked,
+    k: usize,
+    metric: VectorMetric,
+) -> PolarsResult<StructChunked> {
+    with_match_physical_float_polars_type!(float_dtype(&[corpus, queries]), |$T| {
+        nearest_neighbours::<$T>(corpus, queries, k, metric)
+    })
+}
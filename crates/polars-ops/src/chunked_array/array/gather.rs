@@ -0,0 +1,94 @@
+use arrow::array::ListArray;
+use arrow::bitmap::Bitmap;
+use arrow::offset::OffsetsBuffer;
+
+use super::slice::values_and_validity;
+use super::*;
+
+/// The position in a sub-array of `width` elements of a possibly negative index.
+fn normalize_index(idx: i64, width: usize, null_on_oob: bool) -> PolarsResult<Option<IdxSize>> {
+    let col = if idx < 0 { idx + width as i64 } else { idx };
+    if (0..width as i64).contains(&col) {
+        Ok(Some(col as IdxSize))
+    } else {
+        polars_ensure!(null_on_oob, OutOfBounds: "gather indices are out of bounds");
+        Ok(None)
+    }
+}
+
+/// Gather the elements at `idx` of every sub-array in a list. The indices are either a list per
+/// sub-array or the same integers for all of them, and may be negative to count from the end.
+pub fn array_gather(ca: &ArrayChunked, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
+    let width = ca.width();
+    let (values, validity) = values_and_validity(ca);
+
+    // The indices of every sub-array, `None` if they are null.
+    let rows: Vec<Option<Int64Chunked>> = match idx.dtype() {
+        DataType::List(_) => {
+            let idx = idx.list()?;
+            polars_ensure!(
+                idx.len() == ca.len() || idx.len() == 1,
+                ShapeMismatch: "`arr.gather` got {} lists of indices for {} sub-arrays",
+                idx.len(), ca.len()
+            );
+            let to_i64 = |cols: Option<Series>| {
+                cols.map(|cols| {
+                    cols.cast(&DataType::Int64)
+                        .map(|cols| cols.i64().unwrap().clone())
+                })
+                .transpose()
+            };
+            if idx.len() == 1 {
+                vec![to_i64(idx.get_as_series(0))?; ca.len()]
+            } else {
+                idx.into_iter().map(to_i64).collect::<PolarsResult<_>>()?
+            }
+        },
+        dt if dt.is_integer() => {
+            let cols = idx.cast(&DataType::Int64)?;
+            vec![Some(cols.i64()?.clone()); ca.len()]
+        },
+        dt => polars_bail!(ComputeError: "cannot use dtype `{}` as an index", dt),
+    };
+
+    let mut positions: Vec<Option<IdxSize>> = Vec::new();
+    let mut offsets: Vec<i64> = Vec::with_capacity(ca.len() + 1);
+    offsets.push(0);
+    for (row, cols) in rows.iter().enumerate() {
+        let start = (row * width) as IdxSize;
+        for col in cols.iter().flatten() {
+            let col = match col {
+                Some(col) => normalize_index(col, width, null_on_oob)?,
+                None => None,
+            };
+            positions.push(col.map(|col| start + col));
+        }
+        offsets.push(positions.len() as i64);
+    }
+
+    let validity = if rows.iter().any(|cols| cols.is_none()) {
+        let valid = rows.iter().map(|cols| cols.is_some());
+        Some(match validity {
+            Some(validity) => validity.iter().zip(valid).map(|(a, b)| a && b).collect(),
+            None => valid.collect::<Bitmap>(),
+        })
+    } else {
+        validity
+    };
+
+    let gathered = values.take(&positions.into_iter().collect::<IdxCa>())?;
+    let inner_dtype = gathered.dtype().clone();
+    let gathered = gathered.to_physical_repr().rechunk();
+    let arrow_dtype = ListArray::<i64>::default_datatype(gathered.dtype().to_arrow(true));
+    let offsets: OffsetsBuffer<i64> = offsets.try_into()?;
+    let arr = ListArray::<i64>::new(arrow_dtype, offsets, gathered.chunks()[0].clone(), validity);
+    // SAFETY: the values are the physical representation of the inner dtype.
+    let out = unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name(),
+            vec![arr.boxed()],
+            DataType::List(Box::new(inner_dtype)),
+        )
+    };
+    Ok(out.into_series())
+}
@@ -1,15 +1,25 @@
 #[cfg(feature = "array_any_all")]
 mod any_all;
+mod concat;
 mod count;
+#[cfg(feature = "diff")]
+mod diff;
 mod dispersion;
+#[cfg(feature = "array_distance")]
+mod distance;
+#[cfg(feature = "list_gather")]
+mod gather;
 mod get;
 mod join;
 mod min_max;
 mod namespace;
+mod slice;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
+#[cfg(feature = "array_distance")]
+pub use distance::VectorMetric;
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
 #[cfg(feature = "array_to_struct")]
@@ -1,9 +1,29 @@
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
+
 use super::min_max::AggType;
 use super::*;
+use crate::chunked_array::array::concat::array_concat;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
-use crate::chunked_array::array::sum_mean::sum_with_nulls;
+#[cfg(feature = "diff")]
+use crate::chunked_array::array::diff::array_diff;
+#[cfg(feature = "array_distance")]
+use crate::chunked_array::array::distance::{
+    array_cosine_similarity, array_dot, array_l2_distance, array_nearest_neighbours,
+    array_normalize,
+};
+#[cfg(feature = "list_gather")]
+use crate::chunked_array::array::gather::array_gather;
+use crate::chunked_array::array::slice::{array_gather_every, array_slice};
+#[cfg(feature = "list_sample")]
+use crate::chunked_array::array::slice::{from_values, values_and_validity};
+use crate::chunked_array::array::sum_mean::{
+    mean_array_numerical, mean_with_nulls, sum_with_nulls,
+};
+#[cfg(feature = "list_sets")]
+use crate::chunked_array::list::{list_set_operation, SetOperation};
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
@@ -51,6 +71,19 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+
+        if has_inner_nulls(ca) {
+            return mean_with_nulls(ca);
+        };
+
+        match ca.inner_dtype() {
+            dt if dt.is_numeric() => mean_array_numerical(ca, &dt),
+            _ => mean_with_nulls(ca),
+        }
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
@@ -76,6 +109,18 @@ pub trait ArrayNameSpace: AsArray {
         ca.try_apply_amortized_to_list(|s| s.as_ref().unique_stable())
     }
 
+    fn array_n_unique(&self) -> PolarsResult<IdxCa> {
+        let ca = self.as_array();
+        let out: IdxCa = ca
+            .amortized_iter()
+            .map(|s| {
+                s.map(|s| s.as_ref().n_unique().map(|n| n as IdxSize))
+                    .transpose()
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(out.with_name(ca.name()))
+    }
+
     #[cfg(feature = "array_any_all")]
     fn array_any(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
@@ -162,6 +207,125 @@ pub trait ArrayNameSpace: AsArray {
         };
         Ok(out.into_series())
     }
+
+    #[cfg(feature = "diff")]
+    fn array_diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_diff(ca, n, null_behavior)
+    }
+
+    /// Slice every sub-array, the result has the same width for all of them.
+    fn array_slice(&self, offset: i64, length: usize) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_slice(ca, offset, length)
+    }
+
+    /// Take every `n`th element of every sub-array, starting at `offset`.
+    fn array_gather_every(&self, n: usize, offset: usize) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_gather_every(ca, n, offset)
+    }
+
+    #[cfg(feature = "list_gather")]
+    fn array_gather(&self, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_gather(ca, idx, null_on_oob)
+    }
+
+    #[cfg(feature = "list_drop_nulls")]
+    fn array_drop_nulls(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        ca.try_apply_amortized_to_list(|s| Ok(s.as_ref().drop_nulls()))
+    }
+
+    /// Sample `n` elements of every sub-array.
+    #[cfg(feature = "list_sample")]
+    fn array_sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let width = ca.width() as IdxSize;
+        let positions = IdxCa::from_vec("", (0..width).collect()).into_series();
+        let mut idx = Vec::with_capacity(ca.len() * n);
+        for row in 0..ca.len() as IdxSize {
+            let sampled = positions.sample_n(n, with_replacement, shuffle, seed)?;
+            idx.extend(
+                sampled
+                    .idx()?
+                    .into_no_null_iter()
+                    .map(|col| row * width + col),
+            );
+        }
+        let (values, validity) = values_and_validity(ca);
+        let values = values.take(&IdxCa::from_vec("", idx))?;
+        from_values(ca.name(), &values, n, validity)
+    }
+
+    fn array_concat(&self, other: &[Series]) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_concat(ca, other)
+    }
+
+    /// A set operation between the elements of every sub-array and the list or sub-array in
+    /// `other`.
+    #[cfg(feature = "list_sets")]
+    fn array_set_operation(
+        &self,
+        other: &Series,
+        set_op: SetOperation,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        let to_list = |s: &Series| match s.dtype() {
+            DataType::Array(inner, _) => s.cast(&DataType::List(inner.clone())),
+            _ => Ok(s.clone()),
+        };
+        let a = to_list(&ca.clone().into_series())?;
+        let b = to_list(other)?;
+        list_set_operation(a.list()?, b.list()?, set_op)
+    }
+
+    /// The dot product with the sub-arrays of `other`, or with its single sub-array.
+    #[cfg(feature = "array_distance")]
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_dot(ca, other)
+    }
+
+    /// The cosine similarity with the sub-arrays of `other`, or with its single sub-array.
+    #[cfg(feature = "array_distance")]
+    fn array_cosine_similarity(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_cosine_similarity(ca, other)
+    }
+
+    /// The Euclidean distance to the sub-arrays of `other`, or to its single sub-array.
+    #[cfg(feature = "array_distance")]
+    fn array_l2_distance(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_l2_distance(ca, other)
+    }
+
+    #[cfg(feature = "array_distance")]
+    fn array_normalize(&self) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_normalize(ca)
+    }
+
+    /// Search the `k` nearest sub-arrays to every sub-array of `queries`.
+    #[cfg(feature = "array_distance")]
+    fn array_nearest_neighbours(
+        &self,
+        queries: &ArrayChunked,
+        k: usize,
+        metric: VectorMetric,
+    ) -> PolarsResult<StructChunked> {
+        let ca = self.as_array();
+        array_nearest_neighbours(ca, queries, k, metric)
+    }
 }
 
 impl ArrayNameSpace for ArrayChunked {}
@@ -0,0 +1,77 @@
+use arrow::array::FixedSizeListArray;
+use arrow::bitmap::Bitmap;
+use polars_core::utils::slice_offsets;
+
+use super::*;
+
+/// The inner values of `ca` with the validity of its sub-arrays.
+pub(super) fn values_and_validity(ca: &ArrayChunked) -> (Series, Option<Bitmap>) {
+    let ca = ca.rechunk();
+    let validity = ca
+        .downcast_iter()
+        .next()
+        .and_then(|arr| arr.validity().cloned());
+    (ca.get_inner(), validity)
+}
+
+/// Split flat `values` into sub-arrays of `width` elements.
+pub(super) fn from_values(
+    name: &str,
+    values: &Series,
+    width: usize,
+    validity: Option<Bitmap>,
+) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(width > 0, ComputeError: "sub-arrays should keep at least one element");
+    let inner_dtype = values.dtype().clone();
+    let values = values.to_physical_repr().rechunk();
+    let arrow_dtype = FixedSizeListArray::default_datatype(values.dtype().to_arrow(true), width);
+    let arr = FixedSizeListArray::new(arrow_dtype, values.chunks()[0].clone(), validity);
+    // SAFETY: the values are the physical representation of the inner dtype.
+    Ok(unsafe {
+        ArrayChunked::from_chunks_and_dtype(
+            name,
+            vec![arr.boxed()],
+            DataType::Array(Box::new(inner_dtype), width),
+        )
+    })
+}
+
+/// Keep the elements at `columns` of every sub-array.
+pub(super) fn select_columns(
+    ca: &ArrayChunked,
+    values: &Series,
+    validity: Option<Bitmap>,
+    columns: &[IdxSize],
+) -> PolarsResult<ArrayChunked> {
+    let width = ca.width() as IdxSize;
+    let idx: Vec<IdxSize> = (0..ca.len() as IdxSize)
+        .flat_map(|row| columns.iter().map(move |col| row * width + col))
+        .collect();
+    let values = values.take(&IdxCa::from_vec("", idx))?;
+    from_values(ca.name(), &values, columns.len(), validity)
+}
+
+pub(super) fn array_slice(
+    ca: &ArrayChunked,
+    offset: i64,
+    length: usize,
+) -> PolarsResult<ArrayChunked> {
+    let (start, length) = slice_offsets(offset, length, ca.width());
+    let columns: Vec<IdxSize> = (start..start + length).map(|col| col as IdxSize).collect();
+    let (values, validity) = values_and_validity(ca);
+    select_columns(ca, &values, validity, &columns)
+}
+
+pub(super) fn array_gather_every(
+    ca: &ArrayChunked,
+    n: usize,
+    offset: usize,
+) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(n > 0, ComputeError: "`gather_every` should have a positive step");
+    let columns: Vec<IdxSize> = (offset..ca.width())
+        .step_by(n)
+        .map(|col| col as IdxSize)
+        .collect();
+    let (values, validity) = values_and_validity(ca);
+    select_columns(ca, &values, validity, &columns)
+}
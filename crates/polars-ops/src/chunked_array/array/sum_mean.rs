@@ -116,3 +116,29 @@ pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> Polar
     out.rename(ca.name());
     Ok(out)
 }
+
+pub(super) fn mean_array_numerical(
+    ca: &ArrayChunked,
+    inner_type: &DataType,
+) -> PolarsResult<Series> {
+    let width = ca.width();
+    let float_type = match inner_type {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let ca = ca.cast(&DataType::Array(Box::new(float_type.clone()), width))?;
+    let summed = sum_array_numerical(ca.array()?, &float_type);
+    Ok(&summed / width)
+}
+
+pub(super) fn mean_with_nulls(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let out: Float64Chunked = ca
+        .amortized_iter()
+        .map(|s| s.and_then(|s| s.as_ref().mean()))
+        .collect();
+    let out = out.with_name(ca.name()).into_series();
+    match ca.inner_dtype() {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
@@ -0,0 +1,174 @@
+use arrow::array::{Array, ListArray};
+use arrow::bitmap::Bitmap;
+use arrow::offset::OffsetsBuffer;
+use polars_core::utils::try_get_supertype;
+
+use super::*;
+
+/// The offsets, validity, keys and values of the (rechunked) map entries.
+struct Entries {
+    offsets: OffsetsBuffer<i64>,
+    validity: Option<Bitmap>,
+    keys: Series,
+    values: Series,
+}
+
+fn entries(ca: &MapChunked) -> Entries {
+    let physical = ca.physical().rechunk();
+    let arr = physical.downcast_iter().next().unwrap();
+    let entries = physical.get_inner();
+    let fields = entries.struct_().unwrap().fields();
+    Entries {
+        offsets: arr.offsets().clone(),
+        validity: arr.validity().cloned(),
+        keys: fields[0].clone(),
+        values: fields[1].clone(),
+    }
+}
+
+/// Find the index of the first entry of every map whose key equals `key`.
+/// `key` is either a scalar or has one key per map.
+fn find_key(ca: &MapChunked, key: &Series) -> PolarsResult<(Entries, Vec<Option<IdxSize>>)> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        ShapeMismatch: "the key should be a scalar or have the same length as the map, got {} and {}",
+        key.len(), ca.len()
+    );
+    let entries = entries(ca);
+    let key = key.cast(ca.key_dtype())?;
+    let offsets = entries.offsets.as_slice();
+    let start = offsets[0] as usize;
+    let end = offsets[offsets.len() - 1] as usize;
+    let keys = entries.keys.slice(start as i64, end - start);
+
+    let mask = if key.len() == 1 {
+        keys.equal(&key)?
+    } else {
+        // Broadcast the key of every map to its entries.
+        let rows = offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(|(row, w)| std::iter::repeat(row as IdxSize).take((w[1] - w[0]) as usize))
+            .collect::<Vec<_>>();
+        keys.equal(&key.take_slice(&rows)?)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_iter().next().unwrap();
+
+    let idx = offsets
+        .windows(2)
+        .map(|w| {
+            (w[0] as usize..w[1] as usize)
+                .find(|&i| mask.is_valid(i - start) && mask.value(i - start))
+                .map(|i| i as IdxSize)
+        })
+        .collect();
+    Ok((entries, idx))
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value belonging to `key` in every map, or null if the key is missing.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let (entries, idx) = find_key(ca, key)?;
+        let idx = IdxCa::from_slice_options("", &idx);
+        let mut out = entries.values.take(&idx)?;
+        out.rename(ca.name());
+        Ok(out)
+    }
+
+    /// Whether every map holds `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let (entries, idx) = find_key(ca, key)?;
+        let out: BooleanChunked = idx
+            .iter()
+            .enumerate()
+            .map(|(row, idx)| {
+                let valid = entries.validity.as_ref().map_or(true, |v| v.get_bit(row));
+                valid.then_some(idx.is_some())
+            })
+            .collect();
+        Ok(out.with_name(ca.name()))
+    }
+
+    /// The keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields()[0].clone()))
+    }
+
+    /// The values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields()[1].clone()))
+    }
+
+    /// Create a struct with a field for each of the given keys, holding the values of that key.
+    fn map_to_struct(&self, keys: &[String]) -> PolarsResult<StructChunked> {
+        let ca = self.as_map();
+        polars_ensure!(!keys.is_empty(), ComputeError: "cannot create a struct with 0 fields");
+        let fields = keys
+            .iter()
+            .map(|name| {
+                let key = Series::new("", &[name.as_str()]);
+                let mut s = self.map_get(&key)?;
+                s.rename(name);
+                Ok(s)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        StructChunked::new(ca.name(), &fields)
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Turn every row of a struct into a map from the field names to the field values. The values
+/// are cast to the supertype of the fields.
+pub fn struct_to_map(ca: &StructChunked) -> PolarsResult<MapChunked> {
+    let fields = ca.fields();
+    let value_dtype = fields
+        .iter()
+        .try_fold(DataType::Null, |acc, s| try_get_supertype(&acc, s.dtype()))?;
+    let n_fields = fields.len();
+    let len = ca.len();
+
+    let mut values = Series::new_empty(MAP_VALUE_NAME, &value_dtype);
+    for s in fields {
+        values.append(&s.cast(&value_dtype)?)?;
+    }
+    // The fields are stacked, gather them row by row.
+    let idx = (0..len)
+        .flat_map(|row| (0..n_fields).map(move |field| (field * len + row) as IdxSize))
+        .collect::<Vec<_>>();
+    let mut values = values.take_slice(&idx)?;
+    values.rename(MAP_VALUE_NAME);
+    let keys = StringChunked::from_iter_values(
+        MAP_KEY_NAME,
+        (0..len).flat_map(|_| fields.iter().map(|s| s.name())),
+    )
+    .into_series();
+    let entries = StructChunked::new(ca.name(), &[keys, values])?.into_series();
+    let values = entries.array_ref(0).clone();
+
+    let offsets = (0..=len as i64)
+        .map(|row| row * n_fields as i64)
+        .collect::<Vec<_>>();
+    // SAFETY: the offsets are monotonically increasing.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.data_type().clone()),
+        offsets,
+        values,
+        None,
+    );
+    let mut out = ListChunked::with_chunk(ca.name(), arr);
+    // SAFETY: the entries are named and typed as the map expects and the keys are field names.
+    let ca = unsafe {
+        out.to_logical(map_entries_dtype(&DataType::String, &value_dtype));
+        out.into_map_unchecked(DataType::String, value_dtype)
+    };
+    Ok(ca)
+}
@@ -6,6 +6,8 @@ pub mod datetime;
 #[cfg(feature = "interpolate")]
 mod interpolate;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -39,6 +41,8 @@ pub use hist::*;
 #[cfg(feature = "interpolate")]
 pub use interpolate::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
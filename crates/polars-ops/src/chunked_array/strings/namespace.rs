@@ -24,6 +24,15 @@ where
     f
 }
 
+#[cfg(feature = "string_similarity")]
+fn check_broadcast_lengths(ca: &StringChunked, other: &StringChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+        ShapeMismatch: "cannot compare strings of lengths {} and {}", ca.len(), other.len()
+    );
+    Ok(())
+}
+
 pub trait StringNameSpaceImpl: AsString {
     #[cfg(not(feature = "binary_encoding"))]
     fn hex_decode(&self) -> PolarsResult<StringChunked> {
@@ -578,6 +587,38 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// The Levenshtein distance to the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn levenshtein_distance(&self, other: &StringChunked) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_string();
+        check_broadcast_lengths(ca, other)?;
+        Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+            levenshtein_distance(a, b) as u32
+        }))
+    }
+
+    /// The Jaro-Winkler distance to the strings of `other`, see [`StringDistance::JaroWinkler`].
+    #[cfg(feature = "string_similarity")]
+    fn jaro_winkler_distance(&self, other: &StringChunked) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_string();
+        check_broadcast_lengths(ca, other)?;
+        Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+            1.0 - jaro_winkler_similarity(a, b)
+        }))
+    }
+
+    /// The Jaccard distance of the `n` character long substrings to those of the strings of
+    /// `other`, see [`StringDistance::Jaccard`].
+    #[cfg(feature = "string_similarity")]
+    fn jaccard_distance(&self, other: &StringChunked, n: usize) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_string();
+        polars_ensure!(n > 0, InvalidOperation: "the substrings of a Jaccard distance should have at least one character");
+        check_broadcast_lengths(ca, other)?;
+        Ok(broadcast_binary_elementwise_values(ca, other, |a, b| {
+            1.0 - jaccard_similarity(a, b, n)
+        }))
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
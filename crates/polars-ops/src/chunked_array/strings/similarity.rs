@@ -0,0 +1,172 @@
+//! Edit and similarity distances between strings. All of them work on characters rather than
+//! bytes.
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A distance between two strings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StringDistance {
+    /// The number of single character insertions, deletions and substitutions that turn one
+    /// string into the other.
+    Levenshtein,
+    /// One minus the Jaro-Winkler similarity, between 0 for equal strings and 1 for strings
+    /// without common characters.
+    JaroWinkler,
+    /// One minus the Jaccard similarity of the sets of `n` character long substrings.
+    Jaccard { n: usize },
+}
+
+impl StringDistance {
+    /// The distance between `a` and `b`.
+    pub fn distance(&self, a: &str, b: &str) -> f64 {
+        match self {
+            Self::Levenshtein => levenshtein_distance(a, b) as f64,
+            Self::JaroWinkler => 1.0 - jaro_winkler_similarity(a, b),
+            Self::Jaccard { n } => 1.0 - jaccard_similarity(a, b, *n),
+        }
+    }
+}
+
+impl Display for StringDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Levenshtein => write!(f, "levenshtein"),
+            Self::JaroWinkler => write!(f, "jaro_winkler"),
+            Self::Jaccard { n } => write!(f, "jaccard({n})"),
+        }
+    }
+}
+
+/// The substrings of `n` characters of `s`. A non-empty string shorter than `n` characters is its
+/// only substring.
+pub(crate) fn char_ngrams(s: &str, n: usize) -> impl Iterator<Item = &str> {
+    let n = n.max(1);
+    let bounds: Vec<usize> = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect();
+    let n_grams = if bounds.len() > n {
+        bounds.len() - n
+    } else {
+        (!s.is_empty()) as usize
+    };
+    (0..n_grams).map(move |i| &s[bounds[i]..bounds[(i + n).min(bounds.len() - 1)]])
+}
+
+pub(crate) fn levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + (ca != cb) as usize;
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+pub(crate) fn jaro_winkler_chars(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return (a.is_empty() && b.is_empty()) as u8 as f64;
+    }
+    // Characters match if they are equal and not further apart than the window.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    let m = a_matches.len();
+    if m == 0 {
+        return 0.0;
+    }
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, matched)| **matched);
+    let transpositions = b_matches
+        .zip(&a_matches)
+        .filter(|((cb, _), ca)| cb != ca)
+        .count()
+        / 2;
+
+    let m = m as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// The Levenshtein distance between `a` and `b`.
+pub fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    levenshtein_chars(&a, &b)
+}
+
+/// The Jaro-Winkler similarity between `a` and `b`, with a prefix scale of 0.1 for common
+/// prefixes of up to 4 characters. Two empty strings are equal.
+pub fn jaro_winkler_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    jaro_winkler_chars(&a, &b)
+}
+
+/// The Jaccard similarity between the sets of `n` character long substrings of `a` and `b`. Two
+/// empty strings are equal.
+pub fn jaccard_similarity(a: &str, b: &str, n: usize) -> f64 {
+    let a: PlHashSet<&str> = char_ngrams(a, n).collect();
+    let b: PlHashSet<&str> = char_ngrams(b, n).collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let common = a.intersection(&b).count();
+    common as f64 / (a.len() + b.len() - common) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_distances() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("straße", "strasse"), 2);
+
+        let jw = jaro_winkler_similarity("MARTHA", "MARHTA");
+        assert!((jw - 0.9611).abs() < 1e-4);
+        let jw = jaro_winkler_similarity("DIXON", "DICKSONX");
+        assert!((jw - 0.8133).abs() < 1e-4);
+        assert_eq!(jaro_winkler_similarity("", ""), 1.0);
+        assert_eq!(jaro_winkler_similarity("abc", "xyz"), 0.0);
+
+        assert_eq!(
+            char_ngrams("abcd", 2).collect::<Vec<_>>(),
+            &["ab", "bc", "cd"]
+        );
+        assert_eq!(char_ngrams("a", 2).collect::<Vec<_>>(), &["a"]);
+        assert_eq!(char_ngrams("", 2).count(), 0);
+        // {ab, bc, cd} and {ab, bc, ce}
+        assert_eq!(jaccard_similarity("abcd", "abce", 2), 0.5);
+    }
+}
@@ -73,6 +73,15 @@ pub enum JoinType {
     Semi,
     #[cfg(feature = "semi_anti_join")]
     Anti,
+    /// Join on comparisons such as `<` and `<=` between the keys, see [`IEJoinOptions`].
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinOptions),
+    /// Join `[start, end)` intervals, see [`IntervalJoinOptions`].
+    #[cfg(feature = "interval_join")]
+    Interval(IntervalJoinOptions),
+    /// Join strings that are within a distance of each other, see [`FuzzyJoinOptions`].
+    #[cfg(feature = "fuzzy_join")]
+    Fuzzy(FuzzyJoinOptions),
 }
 
 impl JoinType {
@@ -82,6 +91,12 @@ impl JoinType {
             // Merges them if they are equal
             #[cfg(feature = "asof_join")]
             Self::AsOf(_) => false,
+            #[cfg(feature = "iejoin")]
+            Self::IEJoin(_) => false,
+            #[cfg(feature = "interval_join")]
+            Self::Interval(_) => false,
+            #[cfg(feature = "fuzzy_join")]
+            Self::Fuzzy(_) => false,
             _ => true,
         }
     }
@@ -107,6 +122,12 @@ impl Display for JoinType {
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
             Anti => "ANTI",
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => "IEJOIN",
+            #[cfg(feature = "interval_join")]
+            Interval(_) => "INTERVAL",
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => "FUZZY",
         };
         write!(f, "{val}")
     }
@@ -114,7 +135,15 @@ impl Display for JoinType {
 
 impl Debug for JoinType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self}")
+        match self {
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(options) => write!(f, "{self} {:?}", options.operators),
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(options) => write!(f, "{self} {}", options.mode),
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(options) => write!(f, "{self} {options}"),
+            _ => write!(f, "{self}"),
+        }
     }
 }
 
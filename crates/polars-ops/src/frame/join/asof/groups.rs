@@ -1,11 +1,10 @@
 use std::hash::Hash;
 
 use ahash::RandomState;
-use num_traits::Zero;
-use polars_core::hashing::{_df_rows_to_hashes_threaded_vertical, _HASHMAP_INIT_SIZE};
-use polars_core::utils::{split_ca, split_df};
+use polars_core::hashing::_HASHMAP_INIT_SIZE;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::utils::split_ca;
 use polars_core::{with_match_physical_float_polars_type, POOL};
-use polars_utils::abs_diff::AbsDiff;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -30,6 +29,7 @@ fn asof_in_group<'a, T, A, F>(
     right_val_arr: &'a T::Array,
     right_grp_idxs: &[IdxSize],
     group_states: &mut PlHashMap<IdxSize, A>,
+    allow_exact_matches: bool,
     filter: F,
 ) -> Option<IdxSize>
 where
@@ -40,7 +40,9 @@ where
     // We use the index of the first element in a group as an identifier to
     // associate with the group state.
     let id = right_grp_idxs.first()?;
-    let grp_state = group_states.entry(*id).or_default();
+    let grp_state = group_states
+        .entry(*id)
+        .or_insert_with(|| A::new(allow_exact_matches));
 
     unsafe {
         let r_grp_idx = grp_state.next(
@@ -66,6 +68,7 @@ fn asof_join_by_numeric<T, S, A, F>(
     by_right: &ChunkedArray<S>,
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
+    allow_exact_matches: bool,
     filter: F,
 ) -> PolarsResult<Vec<Option<IdxSize>>>
 where
@@ -130,6 +133,7 @@ where
                     right_val_arr,
                     right_grp_idxs.as_slice(),
                     &mut group_states,
+                    allow_exact_matches,
                     &filter,
                 ));
             }
@@ -144,6 +148,7 @@ fn asof_join_by_binary<T, A, F>(
     by_right: &BinaryChunked,
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
+    allow_exact_matches: bool,
     filter: F,
 ) -> Vec<Option<IdxSize>>
 where
@@ -197,6 +202,7 @@ where
                         right_val_arr,
                         right_grp_idxs.as_slice(),
                         &mut group_states,
+                        allow_exact_matches,
                         &filter,
                     ));
                 }
@@ -206,101 +212,32 @@ where
     })
 }
 
-fn asof_join_by_multiple<T, A, F>(
-    by_left: &mut DataFrame,
-    by_right: &mut DataFrame,
-    left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
-    filter: F,
-) -> Vec<Option<IdxSize>>
-where
-    T: PolarsDataType,
-    A: for<'a> AsofJoinState<T::Physical<'a>>,
-    F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
-{
-    let left_asof = left_asof.rechunk();
-    let right_asof = right_asof.rechunk();
-    let left_val_arr = left_asof.downcast_iter().next().unwrap();
-    let right_val_arr = right_asof.downcast_iter().next().unwrap();
-
-    let n_threads = POOL.current_num_threads();
-    let split_by_left = split_df(by_left, n_threads).unwrap();
-    let split_by_right = split_df(by_right, n_threads).unwrap();
-
-    let (build_hashes, random_state) =
-        _df_rows_to_hashes_threaded_vertical(&split_by_right, None).unwrap();
-    let (probe_hashes, _) =
-        _df_rows_to_hashes_threaded_vertical(&split_by_left, Some(random_state)).unwrap();
-
-    let hash_tbls = mk::create_probe_table(&build_hashes, by_right);
-    drop(build_hashes); // Early drop to reduce memory pressure.
-    let offsets = mk::get_offsets(&probe_hashes);
-    let n_tables = hash_tbls.len();
-
-    // Now we probe the right hand side for each left hand side.
-    POOL.install(|| {
-        probe_hashes
-            .into_par_iter()
-            .zip(offsets)
-            .flat_map(|(hash_by_left, offset)| {
-                let mut results = Vec::with_capacity(hash_by_left.len());
-                let mut group_states: PlHashMap<_, A> =
-                    PlHashMap::with_capacity(_HASHMAP_INIT_SIZE);
-
-                let mut ctr = 0;
-                for by_left_view in hash_by_left.data_views() {
-                    for h_left in by_left_view.iter().copied() {
-                        let idx_left = offset + ctr;
-                        ctr += 1;
-                        let opt_left_val = left_val_arr.get(idx_left);
-
-                        let Some(left_val) = opt_left_val else {
-                            results.push(None);
-                            continue;
-                        };
-
-                        let group_probe_table =
-                            unsafe { hash_tbls.get_unchecked(hash_to_partition(h_left, n_tables)) };
-
-                        let entry = group_probe_table.raw_entry().from_hash(h_left, |idx_hash| {
-                            let idx_right = idx_hash.idx;
-                            // SAFETY: indices in a join operation are always in bounds.
-                            unsafe {
-                                mk::compare_df_rows2(
-                                    by_left,
-                                    by_right,
-                                    idx_left,
-                                    idx_right as usize,
-                                    false,
-                                )
-                            }
-                        });
-                        let Some((_, right_grp_idxs)) = entry else {
-                            results.push(None);
-                            continue;
-                        };
-
-                        results.push(asof_in_group::<T, A, &F>(
-                            left_val,
-                            right_val_arr,
-                            &right_grp_idxs[..],
-                            &mut group_states,
-                            &filter,
-                        ));
-                    }
-                }
-                results
-            })
-            .collect()
-    })
+/// Encodes the rows of the `by` columns as binary keys, so that multiple `by` columns are joined
+/// like a single binary column. Rows with a null in any of the columns are null.
+fn encode_by_rows(by: &DataFrame) -> PolarsResult<BinaryChunked> {
+    let columns = by.get_columns();
+    let rows = _get_rows_encoded_ca("", columns, &vec![false; columns.len()], false)?
+        .cast(&DataType::Binary)?;
+    let rows = rows.binary().unwrap();
+    let has_null = columns
+        .iter()
+        .map(|s| s.is_null())
+        .reduce(|acc, is_null| &acc | &is_null)
+        .unwrap();
+    if has_null.any() {
+        rows.set(&has_null, None)
+    } else {
+        Ok(rows.clone())
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn dispatch_join_by_type<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
+    allow_exact_matches: bool,
     filter: F,
 ) -> PolarsResult<Vec<Option<IdxSize>>>
 where
@@ -320,12 +257,26 @@ where
             DataType::String => {
                 let left_by = &left_by_s.str().unwrap().as_binary();
                 let right_by = right_by_s.str().unwrap().as_binary();
-                asof_join_by_binary::<T, A, F>(left_by, &right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by,
+                    &right_by,
+                    left_asof,
+                    right_asof,
+                    allow_exact_matches,
+                    filter,
+                )
             },
             DataType::Binary => {
                 let left_by = &left_by_s.binary().unwrap();
                 let right_by = right_by_s.binary().unwrap();
-                asof_join_by_binary::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+                asof_join_by_binary::<T, A, F>(
+                    left_by,
+                    right_by,
+                    left_asof,
+                    right_asof,
+                    allow_exact_matches,
+                    filter,
+                )
             },
             _ => {
                 if left_by_s.dtype().is_float() {
@@ -333,20 +284,30 @@ where
                         let left_by: &ChunkedArray<$T> = left_by_s.as_ref().as_ref().as_ref();
                         let right_by: &ChunkedArray<$T> = right_by_s.as_ref().as_ref().as_ref();
                         asof_join_by_numeric::<T, $T, A, F>(
-                            left_by, right_by, left_asof, right_asof, filter,
+                            left_by, right_by, left_asof, right_asof, allow_exact_matches, filter,
                         )?
                     })
                 } else if left_by_s.bit_repr_is_large() {
                     let left_by = left_by_s.bit_repr_large();
                     let right_by = right_by_s.bit_repr_large();
                     asof_join_by_numeric::<T, UInt64Type, A, F>(
-                        &left_by, &right_by, left_asof, right_asof, filter,
+                        &left_by,
+                        &right_by,
+                        left_asof,
+                        right_asof,
+                        allow_exact_matches,
+                        filter,
                     )?
                 } else {
                     let left_by = left_by_s.bit_repr_small();
                     let right_by = right_by_s.bit_repr_small();
                     asof_join_by_numeric::<T, UInt32Type, A, F>(
-                        &left_by, &right_by, left_asof, right_asof, filter,
+                        &left_by,
+                        &right_by,
+                        left_asof,
+                        right_asof,
+                        allow_exact_matches,
+                        filter,
                     )?
                 }
             },
@@ -359,7 +320,16 @@ where
             #[cfg(feature = "dtype-categorical")]
             _check_categorical_src(lhs.dtype(), rhs.dtype())?;
         }
-        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+        let left_by = encode_by_rows(left_by)?;
+        let right_by = encode_by_rows(right_by)?;
+        asof_join_by_binary::<T, A, F>(
+            &left_by,
+            &right_by,
+            left_asof,
+            right_asof,
+            allow_exact_matches,
+            filter,
+        )
     };
     Ok(out)
 }
@@ -368,9 +338,10 @@ where
 fn dispatch_join_strategy<T: PolarsDataType>(
     left_asof: &ChunkedArray<T>,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
+    allow_exact_matches: bool,
 ) -> PolarsResult<Vec<Option<IdxSize>>>
 where
     for<'a> T::Physical<'a>: PartialOrd,
@@ -380,10 +351,20 @@ where
     let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
     match strategy {
         AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof,
+            right_asof,
+            left_by,
+            right_by,
+            allow_exact_matches,
+            filter,
         ),
         AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof,
+            right_asof,
+            left_by,
+            right_by,
+            allow_exact_matches,
+            filter,
         ),
         AsofStrategy::Nearest => unimplemented!(),
     }
@@ -393,10 +374,11 @@ where
 fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
     left_asof: &ChunkedArray<T>,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_exact_matches: bool,
 ) -> PolarsResult<Vec<Option<IdxSize>>> {
     let right_ca = left_asof.unpack_series_matching_type(right_asof)?;
 
@@ -406,26 +388,56 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
         let filter = |a: T::Native, b: T::Native| a.abs_diff(b) <= abs_tolerance;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof,
+                right_ca,
+                left_by,
+                right_by,
+                allow_exact_matches,
+                filter,
             ),
         }
     }
@@ -435,43 +447,106 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
 fn dispatch_join_type(
     left_asof: &Series,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_exact_matches: bool,
 ) -> PolarsResult<Vec<Option<IdxSize>>> {
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::Int32 => {
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::UInt64 => {
             let ca = left_asof.u64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::UInt32 => {
             let ca = left_asof.u32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::Float32 => {
             let ca = left_asof.f32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::Float64 => {
             let ca = left_asof.f64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
         DataType::Boolean => {
             let ca = left_asof.bool().unwrap();
-            dispatch_join_strategy::<BooleanType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BooleanType>(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                allow_exact_matches,
+            )
         },
         DataType::Binary => {
             let ca = left_asof.binary().unwrap();
-            dispatch_join_strategy::<BinaryType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BinaryType>(
+                ca,
+                right_asof,
+                left_by,
+                right_by,
+                strategy,
+                allow_exact_matches,
+            )
         },
         DataType::String => {
             let ca = left_asof.str().unwrap();
@@ -482,13 +557,22 @@ fn dispatch_join_type(
                 left_by,
                 right_by,
                 strategy,
+                allow_exact_matches,
             )
         },
         _ => {
             let left_asof = left_asof.cast(&DataType::Int32).unwrap();
             let right_asof = right_asof.cast(&DataType::Int32).unwrap();
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, &right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                &right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_exact_matches,
+            )
         },
     }
 }
@@ -505,6 +589,8 @@ pub trait AsofJoinBy: IntoDf {
         right_by: Vec<SmartString>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        allow_exact_matches: bool,
+        tolerance_column: Option<&str>,
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
@@ -520,14 +606,16 @@ pub trait AsofJoinBy: IntoDf {
             other_df = other;
         }
 
-        let left_asof = self_df.column(left_on)?.to_physical_repr();
-        let right_asof = other_df.column(right_on)?.to_physical_repr();
+        let left_asof_logical = self_df.column(left_on)?;
+        let right_asof_logical = other_df.column(right_on)?;
+        let left_asof = left_asof_logical.to_physical_repr();
+        let right_asof = right_asof_logical.to_physical_repr();
         let right_asof_name = right_asof.name();
         let left_asof_name = left_asof.name();
         check_asof_columns(
             &left_asof,
             &right_asof,
-            tolerance.is_some(),
+            tolerance.is_some() || tolerance_column.is_some(),
             left_by.is_empty() && right_by.is_empty(),
         )?;
 
@@ -550,11 +638,21 @@ pub trait AsofJoinBy: IntoDf {
         let right_join_tuples = dispatch_join_type(
             &left_asof,
             &right_asof,
-            &mut left_by,
-            &mut right_by,
+            &left_by,
+            &right_by,
             strategy,
             tolerance,
+            allow_exact_matches,
         )?;
+        let mut right_join_tuples: IdxCa = right_join_tuples.into_iter().collect_ca("");
+        if let Some(tolerance_column) = tolerance_column {
+            right_join_tuples = filter_by_tolerance_column(
+                &right_join_tuples,
+                left_asof_logical,
+                right_asof_logical,
+                self_df.column(tolerance_column)?,
+            )?;
+        }
 
         let mut drop_these = right_by.get_column_names();
         if left_asof_name == right_asof_name {
@@ -570,12 +668,9 @@ pub trait AsofJoinBy: IntoDf {
         let proj_other_df = unsafe { DataFrame::new_no_checks(cols) };
 
         let left = self_df.clone();
-        let right_join_tuples = &*right_join_tuples;
 
         // SAFETY: join tuples are in bounds.
-        let right_df = unsafe {
-            proj_other_df.take_unchecked(&right_join_tuples.iter().copied().collect_ca(""))
-        };
+        let right_df = unsafe { proj_other_df.take_unchecked(&right_join_tuples) };
 
         _finish_join(left, right_df, suffix)
     }
@@ -603,13 +698,90 @@ pub trait AsofJoinBy: IntoDf {
         let left_by = left_by.into_iter().map(|s| s.as_ref().into()).collect();
         let right_by = right_by.into_iter().map(|s| s.as_ref().into()).collect();
         self_df._join_asof_by(
-            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, None,
+            other, left_on, right_on, left_by, right_by, strategy, tolerance, true, None, None,
+            None,
         )
     }
 }
 
 impl AsofJoinBy for DataFrame {}
 
+/// The rows of both tables per combination of values of the `by` columns, for the combinations
+/// that occur in both tables. Rows with a null in the `by` columns are in no group.
+#[cfg(feature = "interval_join")]
+pub(crate) fn join_by_groups(
+    left_by: &mut DataFrame,
+    right_by: &mut DataFrame,
+) -> PolarsResult<Vec<(Vec<IdxSize>, Vec<IdxSize>)>> {
+    polars_ensure!(
+        left_by.width() == right_by.width(),
+        ComputeError: "the number of 'by' columns should be equal on both sides, got {} and {}",
+        left_by.width(), right_by.width()
+    );
+    unsafe {
+        for (l, r) in left_by
+            .get_columns_mut()
+            .iter_mut()
+            .zip(right_by.get_columns_mut().iter_mut())
+        {
+            polars_ensure!(l.dtype() == r.dtype(),
+                ComputeError: "mismatching dtypes in 'by' parameter: `{}` and `{}`", l.dtype(), r.dtype()
+            );
+            #[cfg(feature = "dtype-categorical")]
+            _check_categorical_src(l.dtype(), r.dtype())?;
+            *l = l.to_physical_repr().into_owned();
+            *r = r.to_physical_repr().into_owned();
+        }
+    }
+
+    let left_by = encode_by_rows(left_by)?;
+    let right_by = encode_by_rows(right_by)?;
+
+    let n_threads = POOL.current_num_threads();
+    let split_by_left = split_ca(&left_by, n_threads).unwrap();
+    let split_by_right = split_ca(&right_by, n_threads).unwrap();
+
+    let hb = RandomState::default();
+    let prep_by_left = prepare_bytes(&split_by_left, &hb);
+    let prep_by_right = prepare_bytes(&split_by_right, &hb);
+    let hash_tbls = build_tables(prep_by_right, false);
+    let n_tables = hash_tbls.len();
+
+    // The right group of every left row.
+    let right_groups: Vec<_> = POOL.install(|| {
+        prep_by_left
+            .par_iter()
+            .flat_map(|by_left| {
+                by_left
+                    .iter()
+                    .map(|by_left_k| {
+                        let group_probe_table = unsafe {
+                            hash_tbls
+                                .get_unchecked(hash_to_partition(by_left_k.dirty_hash(), n_tables))
+                        };
+                        group_probe_table.get(by_left_k)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    });
+
+    // As in the asof join, the first right row identifies a group.
+    let mut group_ids = PlHashMap::with_capacity(_HASHMAP_INIT_SIZE);
+    let mut groups: Vec<(Vec<IdxSize>, Vec<IdxSize>)> = vec![];
+    for (idx_left, right_grp_idxs) in right_groups.into_iter().enumerate() {
+        let Some(right_grp_idxs) = right_grp_idxs else {
+            continue;
+        };
+        let group = *group_ids.entry(right_grp_idxs[0]).or_insert_with(|| {
+            groups.push((vec![], right_grp_idxs[..].to_vec()));
+            groups.len() - 1
+        });
+        groups[group].0.push(idx_left as IdxSize);
+    }
+    Ok(groups)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -821,4 +993,45 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_multiple_exclusive() -> PolarsResult<()> {
+        let trades = df![
+            "time" => [10i64, 20, 20, 30],
+            "venue" => ["X", "X", "Y", "X"],
+            "ticker" => ["A", "A", "A", "B"],
+            "tolerance" => [Some(5i64), None, Some(100), Some(1)]
+        ]?;
+        let quotes = df![
+            "time" => [0i64, 10, 15, 5, 20, 29],
+            "venue" => ["X", "X", "X", "Y", "Y", "X"],
+            "ticker" => ["A", "A", "A", "A", "A", "B"],
+            "bid" => [1, 2, 3, 4, 5, 6]
+        ]?;
+        let by: Vec<SmartString> = vec!["venue".into(), "ticker".into()];
+        let join = |allow_exact_matches, tolerance_column| {
+            let out = trades._join_asof_by(
+                &quotes,
+                "time",
+                "time",
+                by.clone(),
+                by.clone(),
+                AsofStrategy::Backward,
+                None,
+                allow_exact_matches,
+                tolerance_column,
+                None,
+                None,
+            )?;
+            Ok::<_, PolarsError>(Vec::from(out.column("bid")?.i32()?))
+        };
+
+        assert_eq!(join(true, None)?, &[Some(2), Some(3), Some(5), Some(6)]);
+        assert_eq!(join(false, None)?, &[Some(1), Some(3), Some(4), Some(6)]);
+        assert_eq!(
+            join(false, Some("tolerance"))?,
+            &[None, Some(3), Some(4), Some(6)]
+        );
+        Ok(())
+    }
 }
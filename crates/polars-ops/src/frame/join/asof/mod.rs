@@ -3,19 +3,28 @@ mod groups;
 use std::borrow::Cow;
 
 use default::*;
+#[cfg(feature = "interval_join")]
+pub(super) use groups::join_by_groups;
 pub use groups::AsofJoinBy;
+use num_traits::Zero;
 use polars_core::prelude::*;
 use polars_core::utils::ensure_sorted_arg;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::abs_diff::AbsDiff;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "dtype-categorical")]
 use super::_check_categorical_src;
-use super::{_finish_join, build_tables, multiple_keys as mk, prepare_bytes};
+use super::{_finish_join, build_tables, prepare_bytes};
 use crate::frame::IntoDf;
 
-trait AsofJoinState<T>: Default {
+trait AsofJoinState<T> {
+    /// The state before the first left value. If `allow_exact_matches` is false, right values
+    /// equal to the left value don't match.
+    fn new(allow_exact_matches: bool) -> Self;
+
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
         left_val: &T,
@@ -24,12 +33,19 @@ trait AsofJoinState<T>: Default {
     ) -> Option<IdxSize>;
 }
 
-#[derive(Default)]
 struct AsofJoinForwardState {
     scan_offset: IdxSize,
+    allow_exact_matches: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
+    fn new(allow_exact_matches: bool) -> Self {
+        Self {
+            scan_offset: 0,
+            allow_exact_matches,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -39,7 +55,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     ) -> Option<IdxSize> {
         while (self.scan_offset) < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val >= *left_val {
+                if right_val > *left_val || (self.allow_exact_matches && right_val == *left_val) {
                     return Some(self.scan_offset);
                 }
             }
@@ -49,14 +65,22 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinBackwardState {
-    // best_bound is the greatest right index <= left_val.
+    // best_bound is the greatest right index <= left_val, or < left_val without exact matches.
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    allow_exact_matches: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
+    fn new(allow_exact_matches: bool) -> Self {
+        Self {
+            best_bound: None,
+            scan_offset: 0,
+            allow_exact_matches,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -66,7 +90,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     ) -> Option<IdxSize> {
         while self.scan_offset < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val <= *left_val {
+                if right_val < *left_val || (self.allow_exact_matches && right_val == *left_val) {
                     self.best_bound = Some(self.scan_offset);
                 } else {
                     break;
@@ -78,14 +102,29 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinNearestState {
     // best_bound is the nearest value to left_val, with ties broken towards the last element.
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    // Without exact matches the nearest value is the nearest of the backward and the forward
+    // match.
+    exclusive: Option<(AsofJoinBackwardState, AsofJoinForwardState)>,
 }
 
 impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
+    fn new(allow_exact_matches: bool) -> Self {
+        Self {
+            best_bound: None,
+            scan_offset: 0,
+            exclusive: (!allow_exact_matches).then(|| {
+                (
+                    <AsofJoinBackwardState as AsofJoinState<T>>::new(false),
+                    <AsofJoinForwardState as AsofJoinState<T>>::new(false),
+                )
+            }),
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -93,6 +132,24 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        if let Some((backward, forward)) = &mut self.exclusive {
+            let backward = backward.next(left_val, &mut right, n_right);
+            let forward = forward.next(left_val, &mut right, n_right);
+            return match (backward, forward) {
+                (Some(b), Some(f)) => {
+                    // SAFETY: the matches are valid, non-null right values.
+                    let (b_val, f_val) =
+                        unsafe { (right(b).unwrap_unchecked(), right(f).unwrap_unchecked()) };
+                    if left_val.abs_diff(f_val) <= left_val.abs_diff(b_val) {
+                        Some(f)
+                    } else {
+                        Some(b)
+                    }
+                },
+                (b, f) => b.or(f),
+            };
+        }
+
         // Skipping ahead to the first value greater than left_val. This is
         // cheaper than computing differences.
         while self.scan_offset < n_right {
@@ -142,7 +199,7 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AsOfOptions {
     pub strategy: AsofStrategy,
@@ -154,8 +211,86 @@ pub struct AsOfOptions {
     /// - "1d6h"
     /// etc
     pub tolerance_str: Option<SmartString>,
+    /// A column of the left table with the tolerance of every row, in the same unit as the asof
+    /// column or as a duration for temporal asof columns. Rows with a null tolerance aren't
+    /// restricted.
+    pub tolerance_column: Option<SmartString>,
     pub left_by: Option<Vec<SmartString>>,
     pub right_by: Option<Vec<SmartString>>,
+    /// Whether right rows with an asof key equal to the left key match. If `false`, the backward
+    /// strategy matches strictly smaller keys and the forward strategy strictly larger keys.
+    pub allow_exact_matches: bool,
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        Self {
+            strategy: AsofStrategy::default(),
+            tolerance: None,
+            tolerance_str: None,
+            tolerance_column: None,
+            left_by: None,
+            right_by: None,
+            allow_exact_matches: true,
+        }
+    }
+}
+
+/// Converts a tolerance column to the physical type of the asof keys. Durations are converted to
+/// the unit of temporal keys.
+fn tolerance_to_key_type(tolerance: &Series, key_dtype: &DataType) -> PolarsResult<Series> {
+    use DataType::*;
+    let tolerance = match (key_dtype, tolerance.dtype()) {
+        (Datetime(tu, _) | Duration(tu), Duration(_)) => tolerance.cast(&Duration(*tu))?,
+        (Time, Duration(_)) => tolerance.cast(&Duration(TimeUnit::Nanoseconds))?,
+        (Date, Duration(_)) => {
+            use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+            let ms = tolerance.cast(&Duration(TimeUnit::Milliseconds))?;
+            ms.to_physical_repr().as_ref() / MILLISECONDS_IN_DAY
+        },
+        _ => tolerance.clone(),
+    };
+    tolerance.to_physical_repr().cast(&key_dtype.to_physical())
+}
+
+/// Removes the matches of left rows whose right key is further from the left key than the
+/// tolerance of the row. A null tolerance doesn't restrict the match.
+fn filter_by_tolerance_column(
+    take_idx: &IdxCa,
+    left_key: &Series,
+    right_key: &Series,
+    tolerance: &Series,
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        tolerance.len() == left_key.len(),
+        ShapeMismatch: "the tolerance column should have the length of the left table"
+    );
+    let tolerance = tolerance_to_key_type(tolerance, left_key.dtype())?;
+    let left_key = left_key.to_physical_repr();
+    let right_key = right_key.to_physical_repr().rechunk();
+
+    let out: IdxCa = with_match_physical_numeric_polars_type!(left_key.dtype(), |$T| {
+        let left: &ChunkedArray<$T> = left_key.as_ref().as_ref().as_ref();
+        let right: &ChunkedArray<$T> = right_key.as_ref().as_ref().as_ref();
+        let tolerance: &ChunkedArray<$T> = tolerance.as_ref().as_ref().as_ref();
+        let right = right.downcast_iter().next().unwrap();
+        take_idx
+            .iter()
+            .zip(left.iter())
+            .zip(tolerance.iter())
+            .map(|((opt_idx, opt_left), opt_tolerance)| {
+                let idx = opt_idx?;
+                let Some(tolerance) = opt_tolerance else {
+                    return Some(idx);
+                };
+                // SAFETY: matches are in bounds and point to non-null right keys.
+                let right_val = unsafe { right.value_unchecked(idx as usize) };
+                let abs_tolerance = tolerance.abs_diff(Zero::zero());
+                (opt_left?.abs_diff(right_val) <= abs_tolerance).then_some(idx)
+            })
+            .collect_ca("")
+    });
+    Ok(out)
 }
 
 fn check_asof_columns(
@@ -213,62 +348,82 @@ pub trait AsofJoin: IntoDf {
         right_on: &str,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        allow_exact_matches: bool,
+        tolerance_column: Option<&str>,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
         let self_df = self.to_df();
-        let left_key = self_df.column(left_on)?;
-        let right_key = other.column(right_on)?;
+        let left_key_logical = self_df.column(left_on)?;
+        let right_key_logical = other.column(right_on)?;
 
-        check_asof_columns(left_key, right_key, tolerance.is_some(), true)?;
-        let left_key = left_key.to_physical_repr();
-        let right_key = right_key.to_physical_repr();
+        check_asof_columns(
+            left_key_logical,
+            right_key_logical,
+            tolerance.is_some() || tolerance_column.is_some(),
+            true,
+        )?;
+        let left_key = left_key_logical.to_physical_repr();
+        let right_key = right_key_logical.to_physical_repr();
 
         let mut take_idx = match left_key.dtype() {
             DataType::Int64 => {
                 let ca = left_key.i64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::Int32 => {
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::UInt64 => {
                 let ca = left_key.u64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::UInt32 => {
                 let ca = left_key.u32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::Float32 => {
                 let ca = left_key.f32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::Float64 => {
                 let ca = left_key.f64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
             DataType::Boolean => {
                 let ca = left_key.bool().unwrap();
-                join_asof::<BooleanType>(ca, &right_key, strategy)
+                join_asof::<BooleanType>(ca, &right_key, strategy, allow_exact_matches)
             },
             DataType::Binary => {
                 let ca = left_key.binary().unwrap();
-                join_asof::<BinaryType>(ca, &right_key, strategy)
+                join_asof::<BinaryType>(ca, &right_key, strategy, allow_exact_matches)
             },
             DataType::String => {
                 let ca = left_key.str().unwrap();
                 let right_binary = right_key.cast(&DataType::Binary).unwrap();
-                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy)
+                join_asof::<BinaryType>(
+                    &ca.as_binary(),
+                    &right_binary,
+                    strategy,
+                    allow_exact_matches,
+                )
             },
             _ => {
                 let left_key = left_key.cast(&DataType::Int32).unwrap();
                 let right_key = right_key.cast(&DataType::Int32).unwrap();
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_exact_matches)
             },
         }?;
+        if let Some(tolerance_column) = tolerance_column {
+            take_idx = filter_by_tolerance_column(
+                &take_idx,
+                left_key_logical,
+                right_key_logical,
+                self_df.column(tolerance_column)?,
+            )?;
+        }
 
         // Drop right join column.
         let other = if left_on == right_on {
@@ -300,7 +455,9 @@ pub trait AsofJoin: IntoDf {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame> {
-        self._join_asof(other, left_on, right_on, strategy, tolerance, suffix, None)
+        self._join_asof(
+            other, left_on, right_on, strategy, tolerance, true, None, suffix, None,
+        )
     }
 }
 
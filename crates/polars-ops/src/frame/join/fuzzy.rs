@@ -0,0 +1,365 @@
+//! Joins of string keys that are within a distance of each other.
+//!
+//! Comparing every pair of keys is quadratic, so the right keys are indexed by their q-grams
+//! first: their substrings of `q` characters. For every left key the q-grams it shares with the
+//! right keys are counted in the index, and only right keys that share enough q-grams to be
+//! within the distance are compared. How many that is depends on the distance:
+//! - Levenshtein: an edit changes at most `q` q-grams of the keys padded by `q - 1` characters.
+//! - Jaccard: the shared n-grams follow from the similarity and the number of n-grams.
+//! - Jaro-Winkler: the shared characters bound the number of matching characters.
+//!
+//! Left keys that may be within the distance without sharing any q-gram, such as very short keys,
+//! are compared with all right keys.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::chunked_array::strings::{
+    char_ngrams, jaro_winkler_chars, levenshtein_chars, StringDistance,
+};
+
+/// The length of the q-grams that index the keys of a Levenshtein join.
+const LEVENSHTEIN_Q: usize = 2;
+
+/// Options of a [`JoinType::Fuzzy`].
+///
+/// A left and a right row match if the distance between their string keys is at most
+/// `max_distance`. Null keys never match.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuzzyJoinOptions {
+    pub distance: StringDistance,
+    pub max_distance: f64,
+}
+
+impl Eq for FuzzyJoinOptions {}
+
+impl FuzzyJoinOptions {
+    pub fn new(distance: StringDistance, max_distance: f64) -> Self {
+        Self {
+            distance,
+            max_distance,
+        }
+    }
+}
+
+impl Display for FuzzyJoinOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <= {}", self.distance, self.max_distance)
+    }
+}
+
+/// A key with its characters and q-grams.
+struct Key<'a> {
+    s: &'a str,
+    chars: Vec<char>,
+    /// The hashes of the distinct q-grams with their number of occurrences.
+    grams: Vec<(u64, u32)>,
+    n_grams: u32,
+}
+
+struct Matcher {
+    distance: StringDistance,
+    max_distance: f64,
+    random_state: RandomState,
+}
+
+impl Matcher {
+    fn q(&self) -> usize {
+        match self.distance {
+            StringDistance::Levenshtein => LEVENSHTEIN_Q,
+            StringDistance::JaroWinkler => 1,
+            StringDistance::Jaccard { n } => n,
+        }
+    }
+
+    fn min_similarity(&self) -> f64 {
+        1.0 - self.max_distance
+    }
+
+    fn key<'a>(&self, s: &'a str) -> Key<'a> {
+        let q = self.q();
+        let mut grams: Vec<u64> = match self.distance {
+            StringDistance::Levenshtein => {
+                let mut padded = String::with_capacity(s.len() + 2 * (q - 1));
+                padded.extend(std::iter::repeat('\u{0}').take(q - 1));
+                padded.push_str(s);
+                padded.extend(std::iter::repeat('\u{1}').take(q - 1));
+                char_ngrams(&padded, q)
+                    .map(|g| self.random_state.hash_one(g))
+                    .collect()
+            },
+            _ => char_ngrams(s, q)
+                .map(|g| self.random_state.hash_one(g))
+                .collect(),
+        };
+        grams.sort_unstable();
+        let mut counted: Vec<(u64, u32)> = Vec::with_capacity(grams.len());
+        for h in grams {
+            match counted.last_mut() {
+                Some((last, count)) if *last == h => *count += 1,
+                _ => counted.push((h, 1)),
+            }
+        }
+        // Jaccard distances are defined on sets of n-grams.
+        if matches!(self.distance, StringDistance::Jaccard { .. }) {
+            counted.iter_mut().for_each(|(_, count)| *count = 1);
+        }
+        let n_grams = counted.iter().map(|(_, count)| count).sum();
+        Key {
+            s,
+            chars: s.chars().collect(),
+            grams: counted,
+            n_grams,
+        }
+    }
+
+    /// Whether a left key may be within the distance of right keys it shares no q-gram with.
+    fn needs_full_scan(&self, left: &Key) -> bool {
+        match self.distance {
+            StringDistance::Levenshtein => {
+                let q = LEVENSHTEIN_Q as f64;
+                (left.chars.len() + LEVENSHTEIN_Q - 1) as f64 <= self.max_distance.floor() * q
+            },
+            _ => self.min_similarity() <= 0.0 || left.chars.is_empty(),
+        }
+    }
+
+    /// Whether keys that share `common` q-grams may be within the distance.
+    fn may_match(&self, left: &Key, right: &Key, common: u32) -> bool {
+        let common = common as f64;
+        match self.distance {
+            StringDistance::Levenshtein => {
+                let k = self.max_distance.floor();
+                let (l, r) = (left.chars.len(), right.chars.len());
+                l.abs_diff(r) as f64 <= k
+                    && common >= (l.max(r) + LEVENSHTEIN_Q - 1) as f64 - k * LEVENSHTEIN_Q as f64
+            },
+            StringDistance::JaroWinkler => {
+                // The prefix scale adds at most 0.4 * (1 - jaro), and the matching characters
+                // can't be more than the shared characters.
+                let min_jaro = (self.min_similarity() - 0.4) / 0.6;
+                let (l, r) = (left.chars.len() as f64, right.chars.len() as f64);
+                common >= 1.0 && common * (1.0 / l + 1.0 / r) >= 3.0 * min_jaro - 1.0 - 1e-9
+            },
+            StringDistance::Jaccard { .. } => {
+                let s = self.min_similarity();
+                let (l, r) = (left.n_grams as f64, right.n_grams as f64);
+                common >= s * (l + r) / (1.0 + s) - 1e-9
+            },
+        }
+    }
+
+    fn matches(&self, left: &Key, right: &Key) -> bool {
+        let distance = match self.distance {
+            StringDistance::Levenshtein => levenshtein_chars(&left.chars, &right.chars) as f64,
+            StringDistance::JaroWinkler => 1.0 - jaro_winkler_chars(&left.chars, &right.chars),
+            StringDistance::Jaccard { .. } => self.distance.distance(left.s, right.s),
+        };
+        distance <= self.max_distance
+    }
+}
+
+/// The matching pairs of rows, ordered by the left and then the right row.
+fn join_keys(
+    left: &StringChunked,
+    right: &StringChunked,
+    matcher: &Matcher,
+) -> Vec<(IdxSize, IdxSize)> {
+    let right_keys: Vec<Option<Key>> = POOL.install(|| {
+        right
+            .par_iter()
+            .map(|opt_s| opt_s.map(|s| matcher.key(s)))
+            .collect()
+    });
+    let mut index: PlHashMap<u64, Vec<(IdxSize, u32)>> = PlHashMap::default();
+    for (idx, key) in right_keys.iter().enumerate() {
+        if let Some(key) = key {
+            for &(h, count) in &key.grams {
+                index.entry(h).or_default().push((idx as IdxSize, count));
+            }
+        }
+    }
+
+    let n_threads = POOL.current_num_threads();
+    let left = left.rechunk();
+    let Some(left_arr) = left.downcast_iter().next() else {
+        return vec![];
+    };
+    let chunk_size = left.len().div_ceil(n_threads).max(1);
+    POOL.install(|| {
+        (0..left.len())
+            .into_par_iter()
+            .step_by(chunk_size)
+            .flat_map(|offset| {
+                let mut pairs = vec![];
+                let mut common = vec![0u32; right_keys.len()];
+                let mut candidates = vec![];
+                for idx_left in offset..(offset + chunk_size).min(left_arr.len()) {
+                    let Some(s) = left_arr.get(idx_left) else {
+                        continue;
+                    };
+                    let key = matcher.key(s);
+                    let idx_left = idx_left as IdxSize;
+
+                    if matcher.needs_full_scan(&key) {
+                        for (idx_right, right_key) in right_keys.iter().enumerate() {
+                            if let Some(right_key) = right_key {
+                                if matcher.matches(&key, right_key) {
+                                    pairs.push((idx_left, idx_right as IdxSize));
+                                }
+                            }
+                        }
+                        continue;
+                    }
+
+                    for (h, count) in &key.grams {
+                        for &(idx_right, right_count) in index.get(h).into_iter().flatten() {
+                            let c = &mut common[idx_right as usize];
+                            if *c == 0 {
+                                candidates.push(idx_right);
+                            }
+                            *c += right_count.min(*count);
+                        }
+                    }
+                    candidates.sort_unstable();
+                    for idx_right in candidates.drain(..) {
+                        let c = std::mem::take(&mut common[idx_right as usize]);
+                        let right_key = right_keys[idx_right as usize].as_ref().unwrap();
+                        if matcher.may_match(&key, right_key, c) && matcher.matches(&key, right_key)
+                        {
+                            pairs.push((idx_left, idx_right));
+                        }
+                    }
+                }
+                pairs
+            })
+            .collect()
+    })
+}
+
+pub(super) fn fuzzy_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &FuzzyJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        selected_left.len() == 1 && selected_right.len() == 1,
+        ComputeError: "a fuzzy join needs a single key on both sides, got {} left and {} right keys",
+        selected_left.len(), selected_right.len()
+    );
+    if let StringDistance::Jaccard { n } = options.distance {
+        polars_ensure!(n > 0, ComputeError: "the n-grams of a Jaccard distance should have at least one character");
+    }
+    let left_key = selected_left[0].cast(&DataType::String)?;
+    let right_key = selected_right[0].cast(&DataType::String)?;
+    let matcher = Matcher {
+        distance: options.distance,
+        max_distance: options.max_distance,
+        random_state: RandomState::new(),
+    };
+    let pairs = join_keys(left_key.str()?, right_key.str()?, &matcher);
+
+    let (join_idx_left, join_idx_right): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let mut join_idx_left = &*join_idx_left;
+    let mut join_idx_right = &*join_idx_right;
+    if let Some((offset, len)) = slice {
+        join_idx_left = slice_slice(join_idx_left, offset, len);
+        join_idx_right = slice_slice(join_idx_right, offset, len);
+    }
+
+    let (df_left, df_right) = POOL.join(
+        // SAFETY: join indices are known to be in bounds
+        || unsafe { left._take_unchecked_slice_sorted(join_idx_left, true, IsSorted::Ascending) },
+        || unsafe { right._take_unchecked_slice(join_idx_right, true) },
+    );
+    _finish_join(df_left, df_right, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn join(distance: StringDistance, max_distance: f64) -> PolarsResult<Vec<(String, String)>> {
+        let left = df![
+            "name" => [Some("Jonathan Smith"), Some("Ann"), None, Some("Maria Garcia"), Some("")],
+        ]?;
+        let right = df![
+            "name" => ["Jonathon Smith", "Anne", "Jon Smith", "Marie Garcia", "", "Bob"],
+        ]?;
+        let out = left.join(
+            &right,
+            ["name"],
+            ["name"],
+            JoinArgs::new(JoinType::Fuzzy(FuzzyJoinOptions::new(
+                distance,
+                max_distance,
+            ))),
+        )?;
+        let l = out.column("name")?.str()?;
+        let r = out.column("name_right")?.str()?;
+        Ok(l.into_iter()
+            .zip(r)
+            .map(|(l, r)| (l.unwrap().to_string(), r.unwrap().to_string()))
+            .collect())
+    }
+
+    /// The pairs within the distance, found by comparing all of them.
+    fn expected(distance: StringDistance, max_distance: f64) -> Vec<(String, String)> {
+        let left = ["Jonathan Smith", "Ann", "Maria Garcia", ""];
+        let right = [
+            "Jonathon Smith",
+            "Anne",
+            "Jon Smith",
+            "Marie Garcia",
+            "",
+            "Bob",
+        ];
+        let mut out = vec![];
+        for l in left {
+            for r in right {
+                if distance.distance(l, r) <= max_distance {
+                    out.push((l.to_string(), r.to_string()));
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_fuzzy_join() -> PolarsResult<()> {
+        let cases = [
+            (StringDistance::Levenshtein, 0.0),
+            (StringDistance::Levenshtein, 1.0),
+            (StringDistance::Levenshtein, 5.0),
+            (StringDistance::JaroWinkler, 0.1),
+            (StringDistance::JaroWinkler, 0.5),
+            (StringDistance::Jaccard { n: 2 }, 0.3),
+            (StringDistance::Jaccard { n: 3 }, 0.8),
+            (StringDistance::Jaccard { n: 2 }, 1.0),
+        ];
+        for (distance, max_distance) in cases {
+            assert_eq!(
+                join(distance, max_distance)?,
+                expected(distance, max_distance),
+                "{distance} <= {max_distance}"
+            );
+        }
+
+        let pairs = join(StringDistance::Levenshtein, 1.0)?;
+        assert_eq!(
+            pairs,
+            &[
+                ("Jonathan Smith".into(), "Jonathon Smith".into()),
+                ("Ann".into(), "Anne".into()),
+                ("Maria Garcia".into(), "Marie Garcia".into()),
+                ("".into(), "".into()),
+            ]
+        );
+        Ok(())
+    }
+}
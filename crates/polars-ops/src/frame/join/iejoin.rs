@@ -0,0 +1,349 @@
+//! Joins on conjunctions of comparisons between the keys of both tables.
+//!
+//! The keys of both tables are ranked together first, so that the join itself only compares
+//! integers. Rows are then partitioned by their equality keys. Within a partition a single
+//! inequality is solved by a binary search in the right rows sorted by their key, two or more
+//! inequalities by an IEJoin: the rows are swept in the order of the second key while the
+//! right rows that passed are marked in a bitset ordered by the first key.
+use polars_core::utils::try_get_supertype;
+use polars_utils::slice::GetSaferUnchecked;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Comparison between a key of the left and a key of the right table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinOperator {
+    Eq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl JoinOperator {
+    /// The operator that holds if the operands are swapped.
+    pub fn swap_operands(self) -> Self {
+        use JoinOperator::*;
+        match self {
+            Eq => Eq,
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+}
+
+impl Display for JoinOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use JoinOperator::*;
+        let s = match self {
+            Eq => "==",
+            Lt => "<",
+            LtEq => "<=",
+            Gt => ">",
+            GtEq => ">=",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Options of a [`JoinType::IEJoin`].
+///
+/// A left and a right row match if `left_on[i] operators[i] right_on[i]` holds for every key
+/// `i`. Null keys never match.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IEJoinOptions {
+    pub operators: Vec<JoinOperator>,
+}
+
+impl IEJoinOptions {
+    pub fn new(operators: Vec<JoinOperator>) -> Self {
+        Self { operators }
+    }
+}
+
+/// `left < right` if `strict`, otherwise `left <= right`, on the ranks of a pair of keys.
+struct Inequality {
+    strict: bool,
+    left: Vec<IdxSize>,
+    right: Vec<IdxSize>,
+}
+
+impl Inequality {
+    #[inline]
+    fn holds(&self, l: IdxSize, r: IdxSize) -> bool {
+        // SAFETY: the rows are in bounds of the keys of their table.
+        let (l, r) = unsafe {
+            (
+                *self.left.get_unchecked_release(l as usize),
+                *self.right.get_unchecked_release(r as usize),
+            )
+        };
+        if self.strict {
+            l < r
+        } else {
+            l <= r
+        }
+    }
+
+    /// The first position in `sorted` (right rows sorted by this key) that matches the left
+    /// row `l`. All rows from there on match.
+    fn first_match(&self, l: IdxSize, sorted: &[IdxSize]) -> usize {
+        let l = self.left[l as usize];
+        if self.strict {
+            sorted.partition_point(|r| self.right[*r as usize] <= l)
+        } else {
+            sorted.partition_point(|r| self.right[*r as usize] < l)
+        }
+    }
+}
+
+type DenseRanks = Vec<Option<IdxSize>>;
+
+/// Dense ranks of the values of `left` and `right` in their common order, with `None` for
+/// null values.
+fn dense_ranks(left: &Series, right: &Series) -> PolarsResult<(DenseRanks, DenseRanks)> {
+    let dtype = try_get_supertype(left.dtype(), right.dtype())?;
+    let mut values = left.cast(&dtype)?;
+    values.append(&right.cast(&dtype)?.with_name(left.name()))?;
+
+    let order = values.arg_sort(SortOptions {
+        nulls_last: true,
+        ..Default::default()
+    });
+    // SAFETY: the sort indices are in bounds.
+    let sorted = unsafe { values.take_unchecked(&order) };
+    // A new rank starts wherever a value differs from its predecessor.
+    let starts = sorted.not_equal_missing(&sorted.shift(1))?;
+
+    let mut ranks = vec![None; values.len()];
+    let mut rank: IdxSize = 0;
+    for (i, (idx, start)) in order.into_no_null_iter().zip(&starts).enumerate() {
+        if i > 0 && start == Some(true) {
+            rank += 1;
+        }
+        ranks[idx as usize] = Some(rank);
+    }
+    if values.null_count() > 0 {
+        let validity = values.is_not_null();
+        for (rank, valid) in ranks.iter_mut().zip(&validity) {
+            if valid != Some(true) {
+                *rank = None;
+            }
+        }
+    }
+    let right_ranks = ranks.split_off(left.len());
+    Ok((ranks, right_ranks))
+}
+
+/// Set of right rows, addressed by their position in the order of the first inequality.
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    #[inline]
+    fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Call `f` with the members that are `>= start`.
+    #[inline]
+    fn for_each_from(&self, start: usize, mut f: impl FnMut(usize)) {
+        let first = start / 64;
+        for (w, word) in self.words.iter().enumerate().skip(first) {
+            let mut word = *word;
+            if w == first {
+                word &= u64::MAX << (start % 64);
+            }
+            while word != 0 {
+                f(w * 64 + word.trailing_zeros() as usize);
+                word &= word - 1;
+            }
+        }
+    }
+}
+
+/// Join the rows of one partition.
+fn join_partition(
+    left_rows: &[IdxSize],
+    right_rows: &mut [IdxSize],
+    inequalities: &[Inequality],
+) -> Vec<(IdxSize, IdxSize)> {
+    let mut out = vec![];
+    let Some((first, rest)) = inequalities.split_first() else {
+        for l in left_rows {
+            out.extend(right_rows.iter().map(|r| (*l, *r)));
+        }
+        return out;
+    };
+    right_rows.sort_unstable_by_key(|r| first.right[*r as usize]);
+    let Some((second, rest)) = rest.split_first() else {
+        for l in left_rows {
+            let start = first.first_match(*l, right_rows);
+            out.extend(right_rows[start..].iter().map(|r| (*l, *r)));
+        }
+        return out;
+    };
+    let filter = |l: IdxSize, r: IdxSize| rest.iter().all(|ineq| ineq.holds(l, r));
+
+    // Sweep the rows from the largest to the smallest key of the second inequality, so that
+    // all right rows that match a left row on that key are in the set when it is reached. On
+    // ties the left rows go first if the inequality is strict, as the tied right rows don't
+    // match them. Right rows are identified by their position in `right_rows`.
+    let mut events = left_rows
+        .iter()
+        .map(|l| (second.left[*l as usize], true, *l))
+        .chain(
+            right_rows
+                .iter()
+                .enumerate()
+                .map(|(pos, r)| (second.right[*r as usize], false, pos as IdxSize)),
+        )
+        .collect::<Vec<_>>();
+    events.sort_unstable_by(|a, b| {
+        b.0.cmp(&a.0).then_with(|| {
+            if second.strict {
+                b.1.cmp(&a.1)
+            } else {
+                a.1.cmp(&b.1)
+            }
+        })
+    });
+
+    let mut passed = BitSet::new(right_rows.len());
+    for (_, is_left, row) in events {
+        if is_left {
+            let l = row;
+            passed.for_each_from(first.first_match(l, right_rows), |pos| {
+                let r = right_rows[pos];
+                if filter(l, r) {
+                    out.push((l, r))
+                }
+            });
+        } else {
+            passed.insert(row as usize);
+        }
+    }
+    out
+}
+
+/// Join `left` and `right` on the comparisons of [`IEJoinOptions`].
+///
+/// The result is ordered by the rows of the left table, then by the rows of the right table.
+/// The keys are not merged, all columns of both tables are kept.
+pub(super) fn iejoin(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &IEJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        options.operators.len() == selected_left.len() && !options.operators.is_empty(),
+        ComputeError: "an inequality join needs one operator per pair of keys, got {} operators for {} keys",
+        options.operators.len(), selected_left.len()
+    );
+
+    let mut valid_left = vec![true; left.height()];
+    let mut valid_right = vec![true; right.height()];
+    let mut equalities = vec![];
+    let mut inequalities = vec![];
+    for ((l, r), op) in selected_left
+        .iter()
+        .zip(selected_right)
+        .zip(&options.operators)
+    {
+        let (l, r) = dense_ranks(l, r)?;
+        let unwrap = |ranks: DenseRanks, valid: &mut [bool]| {
+            ranks
+                .into_iter()
+                .zip(valid)
+                .map(|(rank, valid)| {
+                    *valid &= rank.is_some();
+                    rank.unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+        };
+        let (l, r) = (unwrap(l, &mut valid_left), unwrap(r, &mut valid_right));
+        // Express every inequality as `left < right` or `left <= right`.
+        let reverse = |ranks: Vec<IdxSize>| ranks.into_iter().map(|v| IdxSize::MAX - v).collect();
+        match op {
+            JoinOperator::Eq => equalities.push((l, r)),
+            JoinOperator::Lt | JoinOperator::LtEq => inequalities.push(Inequality {
+                strict: *op == JoinOperator::Lt,
+                left: l,
+                right: r,
+            }),
+            JoinOperator::Gt | JoinOperator::GtEq => inequalities.push(Inequality {
+                strict: *op == JoinOperator::Gt,
+                left: reverse(l),
+                right: reverse(r),
+            }),
+        }
+    }
+
+    // Partition the rows by their equality keys, numbering the distinct combinations densely.
+    let mut partition_left = vec![0 as IdxSize; left.height()];
+    let mut partition_right = vec![0 as IdxSize; right.height()];
+    let mut n_partitions = 1;
+    for (l, r) in &equalities {
+        let mut ids = PlHashMap::new();
+        for (partition, rank) in partition_left
+            .iter_mut()
+            .zip(l)
+            .chain(partition_right.iter_mut().zip(r))
+        {
+            let n = ids.len() as IdxSize;
+            *partition = *ids.entry((*partition, *rank)).or_insert(n);
+        }
+        n_partitions = ids.len();
+    }
+    let mut partitions = vec![(vec![], vec![]); n_partitions];
+    for (row, (partition, valid)) in partition_left.iter().zip(&valid_left).enumerate() {
+        if *valid {
+            partitions[*partition as usize].0.push(row as IdxSize);
+        }
+    }
+    for (row, (partition, valid)) in partition_right.iter().zip(&valid_right).enumerate() {
+        if *valid {
+            partitions[*partition as usize].1.push(row as IdxSize);
+        }
+    }
+
+    let mut pairs = POOL.install(|| {
+        partitions
+            .into_par_iter()
+            .filter(|(l, r)| !l.is_empty() && !r.is_empty())
+            .flat_map_iter(|(l, mut r)| join_partition(&l, &mut r, &inequalities))
+            .collect::<Vec<_>>()
+    });
+    pairs.par_sort_unstable();
+    let (join_idx_left, join_idx_right): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let mut join_idx_left = &*join_idx_left;
+    let mut join_idx_right = &*join_idx_right;
+    if let Some((offset, len)) = slice {
+        join_idx_left = slice_slice(join_idx_left, offset, len);
+        join_idx_right = slice_slice(join_idx_right, offset, len);
+    }
+
+    let (df_left, df_right) = POOL.join(
+        // SAFETY: join indices are known to be in bounds
+        || unsafe { left._take_unchecked_slice_sorted(join_idx_left, true, IsSorted::Ascending) },
+        || unsafe { right._take_unchecked_slice(join_idx_right, true) },
+    );
+    _finish_join(df_left, df_right, suffix)
+}
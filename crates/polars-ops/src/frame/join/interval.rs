@@ -0,0 +1,419 @@
+//! Joins of `[start, end)` intervals.
+//!
+//! The rows are first split in the groups of the `by` columns, as in the asof join. Within a
+//! group the intervals of both tables are swept in the order of their start, while the intervals
+//! that may still match a later one are kept active. The nearest interval of a left row is found
+//! by binary searches in the right intervals sorted by their start.
+use arrow::array::{Array, PrimitiveArray};
+use arrow::types::NativeType;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use smartstring::alias::String as SmartString;
+
+use super::asof::join_by_groups;
+use super::*;
+
+/// How the intervals of a [`JoinType::Interval`] match.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntervalJoinMode {
+    /// The intervals overlap: `left.start < right.end` and `right.start < left.end`.
+    #[default]
+    Overlaps,
+    /// The left interval contains the right one: `left.start <= right.start` and
+    /// `right.end <= left.end`.
+    Contains,
+    /// The left interval lies within the right one: `right.start <= left.start` and
+    /// `left.end <= right.end`.
+    Within,
+    /// Every left row is joined with the nearest right interval, or with nulls if there is none.
+    /// Overlapping intervals are at distance zero, ties go to the interval that starts first.
+    Nearest,
+}
+
+impl IntervalJoinMode {
+    /// Whether the left interval `[ls, le)` matches the right interval `[rs, re)`.
+    #[inline]
+    fn matches<T: TotalOrd>(self, (ls, le): (T, T), (rs, re): (T, T)) -> bool {
+        use IntervalJoinMode::*;
+        match self {
+            Overlaps => ls.tot_lt(&re) && rs.tot_lt(&le),
+            Contains => ls.tot_le(&rs) && re.tot_le(&le),
+            Within => rs.tot_le(&ls) && le.tot_le(&re),
+            Nearest => unreachable!(),
+        }
+    }
+}
+
+impl Display for IntervalJoinMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IntervalJoinMode::*;
+        let s = match self {
+            Overlaps => "overlaps",
+            Contains => "contains",
+            Within => "within",
+            Nearest => "nearest",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Options of a [`JoinType::Interval`].
+///
+/// The keys are the start and the end of the intervals of both tables, e.g.
+/// `left_on = [start, end]`. Rows with a null bound or an end before the start never match.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntervalJoinOptions {
+    pub mode: IntervalJoinMode,
+    /// Only intervals with equal values in these columns match, like the `by` columns of an
+    /// asof join.
+    pub left_by: Option<Vec<SmartString>>,
+    pub right_by: Option<Vec<SmartString>>,
+}
+
+impl IntervalJoinOptions {
+    pub fn new(mode: IntervalJoinMode) -> Self {
+        Self {
+            mode,
+            left_by: None,
+            right_by: None,
+        }
+    }
+
+    /// Whether `name` is a `by` column with the same name in both tables. The output holds it
+    /// only once.
+    pub fn merges_by_column(&self, name: &str) -> bool {
+        match (&self.left_by, &self.right_by) {
+            (Some(left_by), Some(right_by)) => left_by
+                .iter()
+                .zip(right_by)
+                .any(|(l, r)| l == name && r == name),
+            _ => false,
+        }
+    }
+}
+
+/// The intervals of one table.
+struct Intervals<'a, T: NativeType> {
+    start: &'a PrimitiveArray<T>,
+    end: &'a PrimitiveArray<T>,
+}
+
+impl<T: NumericNative> Intervals<'_, T> {
+    #[inline]
+    fn get(&self, row: IdxSize) -> (T, T) {
+        let row = row as usize;
+        (self.start.value(row), self.end.value(row))
+    }
+
+    /// Whether both bounds of the row are set and the end isn't before the start.
+    fn is_valid(&self, row: IdxSize) -> bool {
+        let i = row as usize;
+        self.start.is_valid(i) && self.end.is_valid(i) && {
+            let (start, end) = self.get(row);
+            start.tot_le(&end)
+        }
+    }
+}
+
+/// Join the intervals of one group by a sweep in the order of their start. An interval stays
+/// active until an interval of the other table starts after its end, as none of the intervals
+/// that start later can match it then.
+fn sweep<T: NumericNative>(
+    mode: IntervalJoinMode,
+    left: &Intervals<T>,
+    right: &Intervals<T>,
+    left_rows: &[IdxSize],
+    right_rows: &[IdxSize],
+) -> Vec<(IdxSize, IdxSize)> {
+    let mut events = left_rows
+        .iter()
+        .map(|l| (left.get(*l).0, true, *l))
+        .chain(right_rows.iter().map(|r| (right.get(*r).0, false, *r)))
+        .collect::<Vec<_>>();
+    events.sort_unstable_by(|a, b| a.0.tot_cmp(&b.0));
+
+    let mut out = vec![];
+    let mut active_left: Vec<IdxSize> = vec![];
+    let mut active_right: Vec<IdxSize> = vec![];
+    for (start, is_left, row) in events {
+        if is_left {
+            let l = left.get(row);
+            active_right.retain(|r| right.get(*r).1.tot_ge(&start));
+            out.extend(
+                active_right
+                    .iter()
+                    .filter(|r| mode.matches(l, right.get(**r)))
+                    .map(|r| (row, *r)),
+            );
+            active_left.push(row);
+        } else {
+            let r = right.get(row);
+            active_left.retain(|l| left.get(*l).1.tot_ge(&start));
+            out.extend(
+                active_left
+                    .iter()
+                    .filter(|l| mode.matches(left.get(**l), r))
+                    .map(|l| (*l, row)),
+            );
+            active_right.push(row);
+        }
+    }
+    out
+}
+
+/// The nearest right interval of every left row of one group.
+fn nearest<T: NumericNative>(
+    left: &Intervals<T>,
+    right: &Intervals<T>,
+    left_rows: &[IdxSize],
+    right_rows: &mut [IdxSize],
+) -> Vec<(IdxSize, IdxSize)> {
+    if right_rows.is_empty() {
+        return vec![];
+    }
+    right_rows.sort_by(|a, b| right.get(*a).0.tot_cmp(&right.get(*b).0));
+
+    // The latest end of the intervals up to every position, and the first position that ends
+    // there.
+    let mut max_end = Vec::with_capacity(right_rows.len());
+    let mut argmax = Vec::with_capacity(right_rows.len());
+    let mut best = 0;
+    for (pos, r) in right_rows.iter().enumerate() {
+        if right.get(*r).1.tot_gt(&right.get(right_rows[best]).1) {
+            best = pos;
+        }
+        max_end.push(right.get(right_rows[best]).1);
+        argmax.push(best);
+    }
+
+    left_rows
+        .iter()
+        .map(|l| {
+            let (ls, le) = left.get(*l);
+            // The intervals that start before the left interval ends.
+            let n_before = right_rows.partition_point(|r| right.get(*r).0.tot_lt(&le));
+            let pos = if n_before > 0 && max_end[n_before - 1].tot_gt(&ls) {
+                // The first of them that ends after the left interval starts overlaps it.
+                max_end[..n_before].partition_point(|end| end.tot_le(&ls))
+            } else {
+                // The intervals before `n_before` end before the left interval starts, the
+                // others start after it ends.
+                let before = n_before.checked_sub(1).map(|pos| argmax[pos]);
+                let after = (n_before < right_rows.len()).then_some(n_before);
+                match (before, after) {
+                    (Some(before), Some(after)) => {
+                        let gap_before = ls - max_end[n_before - 1];
+                        let gap_after = right.get(right_rows[after]).0 - le;
+                        if gap_before.tot_le(&gap_after) {
+                            before
+                        } else {
+                            after
+                        }
+                    },
+                    (Some(pos), None) | (None, Some(pos)) => pos,
+                    (None, None) => unreachable!(),
+                }
+            };
+            (*l, right_rows[pos])
+        })
+        .collect()
+}
+
+fn join_groups<T: PolarsNumericType>(
+    keys: [&Series; 4],
+    groups: Vec<(Vec<IdxSize>, Vec<IdxSize>)>,
+    mode: IntervalJoinMode,
+) -> Vec<(IdxSize, IdxSize)> {
+    let [left_start, left_end, right_start, right_end] = keys.map(|s| {
+        let ca: &ChunkedArray<T> = s.as_ref().as_ref();
+        ca.downcast_iter().next().unwrap()
+    });
+    let left = Intervals {
+        start: left_start,
+        end: left_end,
+    };
+    let right = Intervals {
+        start: right_start,
+        end: right_end,
+    };
+
+    POOL.install(|| {
+        groups
+            .into_par_iter()
+            .flat_map_iter(|(mut left_rows, mut right_rows)| {
+                left_rows.retain(|l| left.is_valid(*l));
+                right_rows.retain(|r| right.is_valid(*r));
+                match mode {
+                    IntervalJoinMode::Nearest => {
+                        nearest(&left, &right, &left_rows, &mut right_rows)
+                    },
+                    _ => sweep(mode, &left, &right, &left_rows, &right_rows),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Join the intervals of `left` and `right` as set by [`IntervalJoinOptions`].
+///
+/// The result is ordered by the rows of the left table, then by the rows of the right table.
+/// The keys are not merged, all columns of both tables are kept except for the right `by`
+/// columns that have the same name as the left ones.
+pub(super) fn interval_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &IntervalJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        selected_left.len() == 2 && selected_right.len() == 2,
+        ComputeError: "an interval join needs the start and the end of the intervals as keys, got {} left and {} right keys",
+        selected_left.len(), selected_right.len()
+    );
+    let mut dtype = selected_left[0].dtype().clone();
+    for s in selected_left.iter().chain(selected_right).skip(1) {
+        dtype = try_get_supertype(&dtype, s.dtype())?;
+    }
+    let physical = dtype.to_physical();
+    polars_ensure!(
+        physical.is_numeric(),
+        InvalidOperation: "interval join is only supported on numeric/temporal keys, got {}", dtype
+    );
+    let mut keys = Vec::with_capacity(4);
+    for s in selected_left.iter().chain(selected_right) {
+        keys.push(s.cast(&dtype)?.to_physical_repr().rechunk());
+    }
+
+    let groups = match (&options.left_by, &options.right_by) {
+        (Some(left_by), Some(right_by)) => {
+            join_by_groups(&mut left.select(left_by)?, &mut right.select(right_by)?)?
+        },
+        (None, None) => vec![(
+            (0..left.height() as IdxSize).collect(),
+            (0..right.height() as IdxSize).collect(),
+        )],
+        _ => polars_bail!(ComputeError: "expected by arguments on both sides"),
+    };
+
+    let keys = [&keys[0], &keys[1], &keys[2], &keys[3]];
+    let mut pairs = with_match_physical_numeric_polars_type!(physical, |$T| {
+        join_groups::<$T>(keys, groups, options.mode)
+    });
+
+    let cols = right
+        .get_columns()
+        .iter()
+        .filter(|s| !options.merges_by_column(s.name()))
+        .cloned()
+        .collect();
+    let right = unsafe { DataFrame::new_no_checks(cols) };
+
+    if options.mode == IntervalJoinMode::Nearest {
+        let mut right_idx = vec![None; left.height()];
+        for (l, r) in pairs {
+            right_idx[l as usize] = Some(r);
+        }
+        let mut left = left.clone();
+        let mut right_idx = &*right_idx;
+        if let Some((offset, len)) = slice {
+            left = left.slice(offset, len);
+            right_idx = slice_slice(right_idx, offset, len);
+        }
+        // SAFETY: join tuples are in bounds.
+        let df_right = unsafe { right.take_unchecked(&right_idx.iter().copied().collect_ca("")) };
+        return _finish_join(left, df_right, suffix);
+    }
+
+    pairs.par_sort_unstable();
+    let (join_idx_left, join_idx_right): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let mut join_idx_left = &*join_idx_left;
+    let mut join_idx_right = &*join_idx_right;
+    if let Some((offset, len)) = slice {
+        join_idx_left = slice_slice(join_idx_left, offset, len);
+        join_idx_right = slice_slice(join_idx_right, offset, len);
+    }
+
+    let (df_left, df_right) = POOL.join(
+        // SAFETY: join indices are known to be in bounds
+        || unsafe { left._take_unchecked_slice_sorted(join_idx_left, true, IsSorted::Ascending) },
+        || unsafe { right._take_unchecked_slice(join_idx_right, true) },
+    );
+    _finish_join(df_left, df_right, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn join(mode: IntervalJoinMode, by: bool) -> PolarsResult<DataFrame> {
+        let left = df![
+            "chrom" => ["a", "a", "a", "b", "a"],
+            "start" => [Some(0), Some(10), Some(20), Some(0), None],
+            "end" => [Some(10), Some(15), Some(30), Some(5), Some(4)],
+        ]?;
+        let right = df![
+            "chrom" => ["a", "a", "b", "a"],
+            "start" => [2, 12, 1, 17],
+            "end" => [4, 18, 2, 19],
+        ]?;
+        let mut options = IntervalJoinOptions::new(mode);
+        if by {
+            options.left_by = Some(vec!["chrom".into()]);
+            options.right_by = Some(vec!["chrom".into()]);
+        }
+        left.join(
+            &right,
+            ["start", "end"],
+            ["start", "end"],
+            JoinArgs::new(JoinType::Interval(options)),
+        )
+    }
+
+    fn right_starts(df: &DataFrame) -> Vec<Option<i32>> {
+        df.column("start_right")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_interval_join() -> PolarsResult<()> {
+        let out = join(IntervalJoinMode::Overlaps, true)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["chrom", "start", "end", "start_right", "end_right"]
+        );
+        let left_starts: Vec<_> = out.column("start")?.i32()?.into_iter().collect();
+        assert_eq!(left_starts, &[Some(0), Some(10), Some(0)]);
+        assert_eq!(right_starts(&out), &[Some(2), Some(12), Some(1)]);
+
+        let out = join(IntervalJoinMode::Overlaps, false)?;
+        assert_eq!(
+            right_starts(&out),
+            &[Some(2), Some(1), Some(12), Some(2), Some(1)]
+        );
+
+        let out = join(IntervalJoinMode::Contains, true)?;
+        assert_eq!(right_starts(&out), &[Some(2), Some(1)]);
+
+        let out = join(IntervalJoinMode::Within, false)?;
+        assert_eq!(out.height(), 0);
+
+        let out = join(IntervalJoinMode::Nearest, true)?;
+        assert_eq!(
+            right_starts(&out),
+            &[Some(2), Some(12), Some(17), Some(1), None]
+        );
+        Ok(())
+    }
+}
@@ -4,8 +4,14 @@ mod asof;
 #[cfg(feature = "dtype-categorical")]
 mod checks;
 mod cross_join;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
+#[cfg(feature = "iejoin")]
+mod iejoin;
+#[cfg(feature = "interval_join")]
+mod interval;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -23,11 +29,17 @@ pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::FuzzyJoinOptions;
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "iejoin")]
+pub use iejoin::{IEJoinOptions, JoinOperator};
+#[cfg(feature = "interval_join")]
+pub use interval::{IntervalJoinMode, IntervalJoinOptions};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::{_df_rows_to_hashes_threaded_vertical, _HASHMAP_INIT_SIZE};
@@ -159,6 +171,45 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        #[cfg(feature = "iejoin")]
+        if let JoinType::IEJoin(options) = &args.how {
+            return iejoin::iejoin(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
+        #[cfg(feature = "interval_join")]
+        if let JoinType::Interval(options) = &args.how {
+            return interval::interval_join(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
+        #[cfg(feature = "fuzzy_join")]
+        if let JoinType::Fuzzy(options) = &args.how {
+            return fuzzy::fuzzy_join(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
         polars_ensure!(
             selected_left.len() == selected_right.len(),
             ComputeError:
@@ -232,6 +283,8 @@ pub trait DataFrameJoinOps: IntoDf {
                             right_by,
                             options.strategy,
                             options.tolerance,
+                            options.allow_exact_matches,
+                            options.tolerance_column.as_deref(),
                             args.suffix.as_deref(),
                             args.slice,
                         ),
@@ -241,6 +294,8 @@ pub trait DataFrameJoinOps: IntoDf {
                             right_on,
                             options.strategy,
                             options.tolerance,
+                            options.allow_exact_matches,
+                            options.tolerance_column.as_deref(),
                             args.suffix,
                             args.slice,
                         ),
@@ -249,6 +304,12 @@ pub trait DataFrameJoinOps: IntoDf {
                         },
                     }
                 },
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => unreachable!(),
+                #[cfg(feature = "interval_join")]
+                JoinType::Interval(_) => unreachable!(),
+                #[cfg(feature = "fuzzy_join")]
+                JoinType::Fuzzy(_) => unreachable!(),
                 JoinType::Cross => {
                     unreachable!()
                 },
@@ -367,6 +428,12 @@ pub trait DataFrameJoinOps: IntoDf {
                 // indices are in bounds
                 Ok(unsafe { left_df._finish_anti_semi_join(&idx, args.slice) })
             },
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(_) => unreachable!(),
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval(_) => unreachable!(),
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(_) => unreachable!(),
             JoinType::Cross => {
                 unreachable!()
             },
@@ -1,7 +1,9 @@
 use std::ops::{BitAnd, BitOr};
 
 use polars_core::frame::NullStrategy;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
+use polars_core::utils::{try_get_supertype, NoNull};
 use polars_core::POOL;
 use rayon::prelude::*;
 
@@ -65,6 +67,247 @@ pub fn mean_horizontal(s: &[Series]) -> PolarsResult<Option<Series>> {
         .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
 }
 
+/// The mean of the values per row, where rows with a null are null.
+pub fn mean_horizontal_propagate_nulls(s: &[Series]) -> PolarsResult<Option<Series>> {
+    let df = unsafe { DataFrame::new_no_checks(Vec::from(s)) };
+    df.mean_horizontal(NullStrategy::Propagate)
+        .map(|opt_s| opt_s.map(|res| res.with_name(s[0].name())))
+}
+
+/// The length of the output of a horizontal operation. Columns of length 1 are broadcast.
+fn horizontal_len(s: &[Series]) -> PolarsResult<usize> {
+    polars_ensure!(!s.is_empty(), ComputeError: "horizontal operations need at least one column");
+    let len = s.iter().map(|s| s.len()).max().unwrap();
+    for s in s {
+        polars_ensure!(
+            s.len() == len || s.len() == 1,
+            ShapeMismatch: "cannot combine columns of length {} and {} horizontally", s.len(), len
+        );
+    }
+    Ok(len)
+}
+
+fn broadcast(s: &Series, len: usize) -> Series {
+    if s.len() == len {
+        s.clone()
+    } else {
+        s.new_from_index(0, len)
+    }
+}
+
+/// The columns as `Float64` of the same length.
+fn float_columns(s: &[Series]) -> PolarsResult<Vec<Float64Chunked>> {
+    let len = horizontal_len(s)?;
+    s.iter()
+        .map(|s| {
+            let dtype = s.dtype();
+            polars_ensure!(
+                dtype.is_numeric() || dtype == &DataType::Boolean,
+                InvalidOperation: "horizontal aggregation not supported for dtype `{}`", dtype
+            );
+            let s = broadcast(s, len).cast(&DataType::Float64)?;
+            Ok(s.f64()?.clone())
+        })
+        .collect()
+}
+
+/// The columns cast to their supertype and of the same length.
+fn supertype_columns(s: &[Series]) -> PolarsResult<Vec<Series>> {
+    let len = horizontal_len(s)?;
+    let dtype = try_get_supertype_from_iter(s.iter().map(|s| s.dtype()))?;
+    s.iter().map(|s| broadcast(s, len).cast(&dtype)).collect()
+}
+
+fn try_get_supertype_from_iter<'a, I>(mut dtypes: I) -> PolarsResult<DataType>
+where
+    I: Iterator<Item = &'a DataType>,
+{
+    let first = dtypes.next().unwrap().clone();
+    dtypes.try_fold(first, |acc, dt| try_get_supertype(&acc, dt))
+}
+
+fn null_counts(columns: &[Series], len: usize) -> IdxCa {
+    columns.iter().fold(IdxCa::full("", 0, len), |acc, s| {
+        let is_null = s.is_null().cast(&IDX_DTYPE).unwrap();
+        &acc + is_null.idx().unwrap()
+    })
+}
+
+/// The number of null values per row.
+pub fn null_count_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    let len = horizontal_len(s)?;
+    let columns: Vec<_> = s.iter().map(|s| broadcast(s, len)).collect();
+    Ok(null_counts(&columns, len)
+        .with_name(s[0].name())
+        .into_series())
+}
+
+/// The variance of the values per row, computed column by column in two passes. Rows with fewer
+/// than `ddof + 1` values are null, as are rows with a null if nulls are propagated.
+pub fn var_horizontal(s: &[Series], ddof: u8, null_strategy: NullStrategy) -> PolarsResult<Series> {
+    let columns = float_columns(s)?;
+    let len = columns[0].len();
+    let zeros = Float64Chunked::full("", 0.0, len);
+
+    let count = columns.iter().fold(zeros.clone(), |acc, ca| {
+        let is_valid = ca.is_not_null().cast(&DataType::Float64).unwrap();
+        &acc + is_valid.f64().unwrap()
+    });
+    let sum = columns.iter().fold(zeros.clone(), |acc, ca| {
+        &acc + &ca.fill_null_with_values(0.0).unwrap()
+    });
+    let mean = &sum / &count;
+    let squared_deviations = columns.iter().fold(zeros, |acc, ca| {
+        let deviation = ca - &mean;
+        &acc + &(&deviation * &deviation)
+            .fill_null_with_values(0.0)
+            .unwrap()
+    });
+
+    let dof = &count - ddof as f64;
+    let mut invalid = dof.lt_eq(0.0);
+    if let NullStrategy::Propagate = null_strategy {
+        invalid = &invalid | &count.lt(columns.len() as f64);
+    }
+    let var = (&squared_deviations / &dof).set(&invalid, None)?;
+    Ok(var.with_name(s[0].name()).into_series())
+}
+
+/// The standard deviation of the values per row, see [`var_horizontal`].
+pub fn std_horizontal(s: &[Series], ddof: u8, null_strategy: NullStrategy) -> PolarsResult<Series> {
+    let var = var_horizontal(s, ddof, null_strategy)?;
+    Ok(var.f64()?.apply_values(|v| v.sqrt()).into_series())
+}
+
+/// The quantile of the values per row. The values of a row are gathered in a buffer that is
+/// reused for all rows.
+pub fn quantile_horizontal(
+    s: &[Series],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+    null_strategy: NullStrategy,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    let columns = float_columns(s)?;
+    let len = columns[0].len();
+    let propagate_nulls = matches!(null_strategy, NullStrategy::Propagate);
+
+    let mut iters: Vec<_> = columns.iter().map(|ca| ca.iter()).collect();
+    let mut values = Vec::with_capacity(columns.len());
+    let out: Float64Chunked = (0..len)
+        .map(|_| {
+            values.clear();
+            let mut has_nulls = false;
+            for iter in iters.iter_mut() {
+                match iter.next().unwrap() {
+                    Some(v) => values.push(v),
+                    None => has_nulls = true,
+                }
+            }
+            if has_nulls && propagate_nulls {
+                Ok(None)
+            } else {
+                quantile_slice(&mut values, quantile, interpol)
+            }
+        })
+        .collect::<PolarsResult<_>>()?;
+    Ok(out.with_name(s[0].name()).into_series())
+}
+
+/// The median of the values per row, see [`quantile_horizontal`].
+pub fn median_horizontal(s: &[Series], null_strategy: NullStrategy) -> PolarsResult<Series> {
+    quantile_horizontal(s, 0.5, QuantileInterpolOptions::Linear, null_strategy)
+}
+
+fn arg_extremum_horizontal(
+    s: &[Series],
+    null_strategy: NullStrategy,
+    is_better: fn(&Series, &Series) -> PolarsResult<BooleanChunked>,
+) -> PolarsResult<Series> {
+    let columns = supertype_columns(s)?;
+    let len = columns[0].len();
+
+    let mut best = columns[0].clone();
+    let mut idx = IdxCa::full("", 0, len).set(&best.is_null(), None)?;
+    for (i, column) in columns.iter().enumerate().skip(1) {
+        // On ties the first column wins.
+        let better = is_better(column, &best)?.fill_null_with_values(false)?;
+        let better = &better | &(&best.is_null() & &column.is_not_null());
+        best = column.zip_with(&better, &best)?;
+        idx = IdxCa::full("", i as IdxSize, len).zip_with(&better, &idx)?;
+    }
+    if let NullStrategy::Propagate = null_strategy {
+        idx = idx.set(&null_counts(&columns, len).gt(0), None)?;
+    }
+    Ok(idx.with_name(s[0].name()).into_series())
+}
+
+/// The index of the column that holds the maximum of every row.
+pub fn arg_max_horizontal(s: &[Series], null_strategy: NullStrategy) -> PolarsResult<Series> {
+    arg_extremum_horizontal(s, null_strategy, |a, b| a.gt(b))
+}
+
+/// The index of the column that holds the minimum of every row.
+pub fn arg_min_horizontal(s: &[Series], null_strategy: NullStrategy) -> PolarsResult<Series> {
+    arg_extremum_horizontal(s, null_strategy, |a, b| a.lt(b))
+}
+
+/// Up to this many columns, [`n_unique_horizontal`] compares every column with the columns
+/// before it. Wider inputs are deduplicated with a hash set per row.
+const N_UNIQUE_PAIRWISE_MAX_COLUMNS: usize = 8;
+
+/// The number of unique values per row, where null counts as a value.
+pub fn n_unique_horizontal(s: &[Series]) -> PolarsResult<Series> {
+    let columns = supertype_columns(s)?;
+    let out = if columns.len() <= N_UNIQUE_PAIRWISE_MAX_COLUMNS {
+        n_unique_pairwise(&columns)?
+    } else {
+        n_unique_hashed(&columns)?
+    };
+    Ok(out.with_name(s[0].name()).into_series())
+}
+
+/// A value is new if it differs from the values in all columns before it.
+fn n_unique_pairwise(columns: &[Series]) -> PolarsResult<IdxCa> {
+    let len = columns[0].len();
+    let mut out = IdxCa::full("", 1, len);
+    for (i, column) in columns.iter().enumerate().skip(1) {
+        let mut is_new = BooleanChunked::full("", true, len);
+        for previous in &columns[..i] {
+            is_new = &is_new & &!column.equal_missing(previous)?;
+        }
+        let is_new = is_new.cast(&IDX_DTYPE)?;
+        out = &out + is_new.idx()?;
+    }
+    Ok(out)
+}
+
+/// The row encodings of two values of a column are equal iff the values are equal, so the
+/// values of a row are deduplicated by their encoding.
+fn n_unique_hashed(columns: &[Series]) -> PolarsResult<IdxCa> {
+    let len = columns[0].len();
+    let encoded = columns
+        .iter()
+        .map(|s| _get_rows_encoded_ca("", std::slice::from_ref(s), &[false], false))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut iters: Vec<_> = encoded.iter().map(|ca| ca.into_no_null_iter()).collect();
+    let mut seen = PlHashSet::with_capacity(columns.len());
+    let out: NoNull<IdxCa> = (0..len)
+        .map(|_| {
+            seen.clear();
+            for iter in iters.iter_mut() {
+                seen.insert(iter.next().unwrap());
+            }
+            seen.len() as IdxSize
+        })
+        .collect();
+    Ok(out.into_inner())
+}
+
 pub fn coalesce_series(s: &[Series]) -> PolarsResult<Series> {
     // TODO! this can be faster if we have more than two inputs.
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
@@ -47,12 +47,7 @@ fn convert_data_type(data_type: ArrowDataType) -> ArrowDataType {
             let data_type = convert_data_type(*data_type);
             Extension(name, Box::new(data_type), metadata)
         },
-        Map(field, _ordered) => {
-            // Polars doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
-            LargeList(field)
-        },
+        Map(field, ordered) => Map(Box::new(convert_field(*field)), ordered),
         dt => dt,
     }
 }
@@ -1,4 +1,4 @@
-use parquet_format_safe::RowGroup;
+use parquet_format_safe::{RowGroup, SortingColumn};
 #[cfg(feature = "serde_types")]
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +14,8 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: usize,
     total_byte_size: usize,
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    sorting_columns: Option<Vec<SortingColumn>>,
 }
 
 impl RowGroupMetaData {
@@ -27,6 +29,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns: None,
         }
     }
 
@@ -45,6 +48,13 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// The columns this row group is sorted by, if the writer declared them.
+    ///
+    /// The `column_idx` refers to the leaf columns of the schema.
+    pub fn sorting_columns(&self) -> Option<&[SortingColumn]> {
+        self.sorting_columns.as_deref()
+    }
+
     /// Total size of all compressed column data in this row group.
     pub fn compressed_size(&self) -> usize {
         self.columns
@@ -63,6 +73,7 @@ impl RowGroupMetaData {
         }
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
+        let sorting_columns = rg.sorting_columns;
         let columns = rg
             .columns
             .into_iter()
@@ -76,6 +87,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            sorting_columns,
         })
     }
 
@@ -94,7 +106,7 @@ impl RowGroupMetaData {
             columns: self.columns.into_iter().map(|v| v.into_thrift()).collect(),
             total_byte_size: self.total_byte_size as i64,
             num_rows: self.num_rows as i64,
-            sorting_columns: None,
+            sorting_columns: self.sorting_columns,
             file_offset,
             total_compressed_size,
             ordinal: None,
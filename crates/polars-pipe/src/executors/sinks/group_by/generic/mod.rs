@@ -11,6 +11,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Mutex;
 
 use arrow::array::BinaryArray;
+use arrow::compute::aggregate::estimated_bytes_size;
 use eval::Eval;
 use hash_table::AggHashTable;
 use hashbrown::hash_map::{RawEntryMut, RawVacantEntryMut};
@@ -70,6 +71,13 @@ impl SpillPayload {
         schema
     }
 
+    fn estimated_size(&self) -> usize {
+        self.hashes.len() * std::mem::size_of::<u64>()
+            + self.chunk_idx.len() * std::mem::size_of::<IdxSize>()
+            + estimated_bytes_size(&self.keys)
+            + self.aggs.iter().map(|s| s.estimated_size()).sum::<usize>()
+    }
+
     fn into_df(self) -> DataFrame {
         debug_assert_eq!(self.hashes.len(), self.chunk_idx.len());
         debug_assert_eq!(self.hashes.len(), self.keys.len());
@@ -92,8 +92,23 @@ impl OocState {
     }
 
     pub(super) fn dump(&self, partition_no: usize, df: DataFrame) {
+        let size = df.estimated_size();
         let iot = self.io_thread.lock().unwrap();
         let iot = iot.as_ref().unwrap();
-        iot.dump_partition(partition_no as IdxSize, df)
+        iot.dump_partition(partition_no as IdxSize, df);
+        self.mem_track.spilled(size);
+    }
+
+    pub(super) fn bind(&self, context: &PExecutionContext) {
+        self.mem_track.bind(&context.memory_manager)
+    }
+
+    /// Register memory held by spill payloads in the global table.
+    pub(super) fn reserve(&self, size: usize) {
+        self.mem_track.fetch_add(size);
+    }
+
+    pub(super) fn release(&self) {
+        self.mem_track.release()
     }
 }
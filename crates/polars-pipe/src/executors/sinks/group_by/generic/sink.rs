@@ -62,6 +62,7 @@ impl Sink for GenericGroupby2 {
         if chunk.is_empty() {
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        self.ooc_state.bind(context);
         // load data and hashes
         unsafe {
             // SAFETY: we don't hold mutable refs
@@ -81,6 +82,7 @@ impl Sink for GenericGroupby2 {
                 if let Some((partition, spill_payload)) =
                     table.insert(*hash, row, &mut aggs, chunk_idx)
                 {
+                    self.ooc_state.reserve(spill_payload.estimated_size());
                     self.global_table.spill(partition, spill_payload)
                 }
             }
@@ -134,6 +136,8 @@ impl Sink for GenericGroupby2 {
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.ooc_state.bind(context);
+        self.ooc_state.release();
         let map = unsafe { &mut *self.thread_local_table.get() };
 
         // only succeeds if it hasn't spilled to global
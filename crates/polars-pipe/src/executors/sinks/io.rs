@@ -10,7 +10,7 @@ use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_io::prelude::*;
 
-use crate::executors::sinks::get_base_temp_dir;
+use crate::executors::sinks::{get_base_temp_dir, get_spill_compression};
 use crate::pipeline::morsels_per_sink;
 
 pub(in crate::executors::sinks) type DfIter =
@@ -28,6 +28,13 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
     schema: SchemaRef,
+    compression: Option<IpcCompression>,
+}
+
+fn spill_writer(file: File, compression: Option<IpcCompression>) -> IpcWriter<File> {
+    IpcWriter::new(file)
+        .with_pl_flavor(true)
+        .with_compression(compression)
 }
 
 fn get_lockfile_path(dir: &Path) -> PathBuf {
@@ -130,6 +137,7 @@ impl IOThread {
         operation_name: &'static str,
     ) -> PolarsResult<Self> {
         let dir = get_spill_dir(operation_name)?;
+        let compression = get_spill_compression()?;
 
         // make sure we create lockfile before we GC
         let lockfile_path = get_lockfile_path(&dir);
@@ -174,7 +182,7 @@ impl IOThread {
                         path.push(format!("{count}.ipc"));
 
                         let file = File::create(path).unwrap();
-                        let writer = IpcWriter::new(file).with_pl_flavor(true);
+                        let writer = spill_writer(file, compression);
                         let mut writer = writer.batched(&schema).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
@@ -185,7 +193,7 @@ impl IOThread {
                     path.push(format!("{count}.ipc"));
 
                     let file = File::create(path).unwrap();
-                    let writer = IpcWriter::new(file).with_pl_flavor(true);
+                    let writer = spill_writer(file, compression);
                     let mut writer = writer.batched(&schema).unwrap();
 
                     for df in iter {
@@ -208,6 +216,7 @@ impl IOThread {
             _lockfile: lockfile,
             thread_local_count,
             schema,
+            compression,
         })
     }
 
@@ -222,7 +231,7 @@ impl IOThread {
             path.push(format!("_{count}.ipc"));
 
             let file = File::create(path).unwrap();
-            let mut writer = IpcWriter::new(file).with_pl_flavor(true);
+            let mut writer = spill_writer(file, self.compression);
             writer.finish(&mut df).unwrap();
         } else {
             let iter = Box::new(std::iter::once(df));
@@ -254,7 +263,7 @@ impl IOThread {
         // duplicates
         path.push(format!("_{count}.ipc"));
         let file = File::create(path).unwrap();
-        let writer = IpcWriter::new(file).with_pl_flavor(true);
+        let writer = spill_writer(file, self.compression);
         let mut writer = writer.batched(&self.schema).unwrap();
         writer.write_batch(&df).unwrap();
         writer.finish().unwrap();
@@ -14,6 +14,7 @@ use crate::operators::{
     chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
     Sink, SinkResult,
 };
+use crate::pipeline::MemoryReservation;
 
 #[derive(Default)]
 pub struct CrossJoin {
@@ -21,6 +22,7 @@ pub struct CrossJoin {
     suffix: SmartString,
     swapped: bool,
     node: Node,
+    reservation: MemoryReservation,
 }
 
 impl CrossJoin {
@@ -30,6 +32,7 @@ impl CrossJoin {
             suffix,
             swapped,
             node,
+            reservation: Default::default(),
         }
     }
 }
@@ -42,7 +45,9 @@ impl Sink for CrossJoin {
         true
     }
 
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.reservation
+            .try_grow(&context.memory_manager, chunk.data.estimated_size())?;
         self.chunks.push(chunk);
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -51,6 +56,8 @@ impl Sink for CrossJoin {
         let other = other.as_any().downcast_mut::<Self>().unwrap();
         let other_chunks = std::mem::take(&mut other.chunks);
         self.chunks.extend(other_chunks);
+        self.reservation
+            .merge(std::mem::take(&mut other.reservation));
     }
 
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
@@ -71,6 +78,7 @@ impl Sink for CrossJoin {
             in_process_left_df: Default::default(),
             output_names: None,
             swapped: self.swapped,
+            _reservation: Arc::new(std::mem::take(&mut self.reservation)),
         })))
     }
 
@@ -92,6 +100,8 @@ pub struct CrossJoinProbe {
     in_process_left_df: DataFrame,
     output_names: Option<Vec<SmartString>>,
     swapped: bool,
+    // keeps the build side in the memory budget until all probe operators are dropped
+    _reservation: Arc<MemoryReservation>,
 }
 
 impl Operator for CrossJoinProbe {
@@ -19,6 +19,7 @@ use crate::executors::sinks::utils::{hash_rows, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::pipeline::MemoryReservation;
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -77,6 +78,7 @@ pub struct GenericBuild {
     swapped: bool,
     join_nulls: bool,
     node: Node,
+    reservation: MemoryReservation,
 }
 
 impl GenericBuild {
@@ -106,6 +108,7 @@ impl GenericBuild {
             hashes: vec![],
             join_nulls,
             node,
+            reservation: Default::default(),
         }
     }
 }
@@ -189,6 +192,8 @@ impl Sink for GenericBuild {
             }
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        self.reservation
+            .try_grow(&context.memory_manager, chunk.data.estimated_size())?;
         let mut hashes = std::mem::take(&mut self.hashes);
         let rows = self.set_join_series(context, &chunk)?.clone();
         hash_rows(&rows, &mut hashes, &self.hb);
@@ -237,10 +242,13 @@ impl Sink for GenericBuild {
             }
             return;
         }
-        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
         if other.is_empty() {
             return;
         }
+        self.reservation
+            .merge(std::mem::take(&mut other.reservation));
+        let other = &*other;
 
         let chunks_offset = self.chunks.len() as IdxSize;
         self.chunks.extend_from_slice(&other.chunks);
@@ -344,6 +352,7 @@ impl Sink for GenericBuild {
                     context,
                     self.join_type.clone(),
                     self.join_nulls,
+                    std::mem::take(&mut self.reservation),
                 );
                 Ok(FinalizedSink::Operator(Box::new(probe_operator)))
             },
@@ -20,6 +20,7 @@ use crate::executors::sinks::joins::generic_build::*;
 use crate::executors::sinks::utils::hash_rows;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::pipeline::MemoryReservation;
 
 #[derive(Clone)]
 pub struct GenericJoinProbe {
@@ -63,6 +64,8 @@ pub struct GenericJoinProbe {
     output_names: Option<Vec<SmartString>>,
     how: JoinType,
     join_nulls: bool,
+    // keeps the build side in the memory budget until all probe operators are dropped
+    _reservation: Arc<MemoryReservation>,
 }
 
 trait ToRow {
@@ -97,6 +100,7 @@ impl GenericJoinProbe {
         context: &PExecutionContext,
         how: JoinType,
         join_nulls: bool,
+        reservation: MemoryReservation,
     ) -> Self {
         if swapped_or_left {
             let tmp = DataChunk {
@@ -135,6 +139,7 @@ impl GenericJoinProbe {
             output_names: None,
             how,
             join_nulls,
+            _reservation: Arc::new(reservation),
         }
     }
     fn set_join_series(
@@ -1,9 +1,9 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use polars_utils::sys::MEMINFO;
 
-use crate::pipeline::FORCE_OOC;
+use crate::pipeline::{MemoryManager, FORCE_OOC};
 
 const TO_MB: usize = 2 << 19;
 
@@ -16,6 +16,8 @@ pub(super) struct MemTracker {
     thread_count: usize,
     available_at_start: usize,
     refresh_interval: usize,
+    // memory budget of the query, bound on the first `sink` call
+    memory: Arc<OnceLock<Arc<MemoryManager>>>,
 }
 
 impl MemTracker {
@@ -33,6 +35,7 @@ impl MemTracker {
             thread_count,
             available_at_start: 0,
             refresh_interval,
+            memory: Default::default(),
         };
         let available = MEMINFO.free() as usize;
         out.available_mem.store(available, Ordering::Relaxed);
@@ -46,7 +49,19 @@ impl MemTracker {
             .store(MEMINFO.free() as usize, Ordering::Relaxed);
     }
 
-    /// Get available memory of the system measured on latest refresh.
+    /// Bind the memory budget of the query. Only the first call has effect.
+    pub(super) fn bind(&self, memory: &Arc<MemoryManager>) {
+        let _ = self.memory.get_or_init(|| memory.clone());
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.memory.get().and_then(|memory| memory.limit())
+    }
+
+    /// Get available memory measured on latest refresh.
+    ///
+    /// This is the available memory of the system, capped by what is
+    /// left of the memory budget of the query.
     pub(super) fn get_available(&self) -> usize {
         // once in every n passes we fetch mem usage.
         let fetch_count = self.fetch_count.fetch_add(1, Ordering::Relaxed);
@@ -54,19 +69,51 @@ impl MemTracker {
         if fetch_count % (self.refresh_interval * self.thread_count) == 0 {
             self.refresh_memory()
         }
-        self.available_mem.load(Ordering::Relaxed)
+        let available = self.available_mem.load(Ordering::Relaxed);
+        match self.memory.get().and_then(|memory| memory.remaining()) {
+            Some(remaining) => std::cmp::min(available, remaining),
+            None => available,
+        }
     }
 
     pub(super) fn free_memory_fraction_since_start(&self) -> f64 {
+        let available_at_start = match self.limit() {
+            Some(limit) => std::cmp::min(self.available_at_start, limit),
+            None => self.available_at_start,
+        };
         // We divide first to reduce the precision loss in floats.
         // We also add 1.0 to available_at_start to prevent division by zero.
-        let available_at_start = (self.available_at_start / TO_MB) as f64 + 1.0;
+        let available_at_start = (available_at_start / TO_MB) as f64 + 1.0;
         let available = (self.get_available() / TO_MB) as f64;
         available / available_at_start
     }
 
     /// Increment the used memory and return the previous value.
     pub(super) fn fetch_add(&self, add: usize) -> usize {
+        if let Some(memory) = self.memory.get() {
+            memory.reserve(add);
+        }
         self.used_by_node.fetch_add(add, Ordering::Relaxed)
     }
+
+    /// Decrement the used memory after `sub` bytes were spilled to disk.
+    pub(super) fn spilled(&self, sub: usize) {
+        if let Some(memory) = self.memory.get() {
+            memory.release(sub);
+            memory.record_spill(sub);
+        }
+        let _ = self
+            .used_by_node
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(sub))
+            });
+    }
+
+    /// Give all memory used by this node back to the budget of the query.
+    pub(super) fn release(&self) {
+        let used = self.used_by_node.swap(0, Ordering::Relaxed);
+        if let Some(memory) = self.memory.get() {
+            memory.release(used);
+        }
+    }
 }
@@ -5,8 +5,10 @@ mod memory;
 mod ordered;
 mod output;
 mod reproject;
+mod row_index;
 mod slice;
-mod sort;
+pub(crate) mod sort;
+mod unique;
 mod utils;
 
 pub(crate) use joins::*;
@@ -18,15 +20,20 @@ pub(crate) use ordered::*;
     feature = "json"
 ))]
 pub(crate) use output::*;
+use polars_core::error::{polars_bail, PolarsResult};
+use polars_io::ipc::IpcCompression;
 pub(crate) use reproject::*;
+pub(crate) use row_index::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
+pub(crate) use unique::*;
 
 // We must strike a balance between cache coherence and resizing costs.
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
 const HASHMAP_INIT_SIZE: usize = 64;
 
 pub(crate) static POLARS_TEMP_DIR: &str = "POLARS_TEMP_DIR";
+pub(crate) static POLARS_SPILL_COMPRESSION: &str = "POLARS_SPILL_COMPRESSION";
 
 pub(crate) fn get_base_temp_dir() -> String {
     let base_dir = std::env::var(POLARS_TEMP_DIR)
@@ -38,3 +45,18 @@ pub(crate) fn get_base_temp_dir() -> String {
 
     base_dir
 }
+
+/// Compression of the files that out-of-core operations spill to disk.
+///
+/// Set `POLARS_SPILL_COMPRESSION` to `lz4` or `zstd` to trade CPU for disk IO.
+pub(crate) fn get_spill_compression() -> PolarsResult<Option<IpcCompression>> {
+    match std::env::var(POLARS_SPILL_COMPRESSION).as_deref() {
+        Err(_) | Ok("uncompressed") => Ok(None),
+        Ok("lz4") => Ok(Some(IpcCompression::LZ4)),
+        Ok("zstd") => Ok(Some(IpcCompression::ZSTD)),
+        Ok(compression) => polars_bail!(
+            InvalidOperation: "invalid {}: '{}', expected one of 'lz4', 'zstd' or 'uncompressed'",
+            POLARS_SPILL_COMPRESSION, compression
+        ),
+    }
+}
@@ -5,7 +5,8 @@ use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 
 use crate::operators::{
-    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+    chunks_to_df_unchecked, sort_chunks_by_index, DataChunk, FinalizedSink, PExecutionContext,
+    Sink, SinkResult,
 };
 
 // Ensure the data is return in the order it was streamed
@@ -24,7 +25,7 @@ impl OrderedSink {
     }
 
     fn sort(&mut self) {
-        self.chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        sort_chunks_by_index(&mut self.chunks);
     }
 }
 
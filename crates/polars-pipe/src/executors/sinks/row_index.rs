@@ -0,0 +1,77 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::IdxSize;
+use polars_core::schema::SchemaRef;
+
+use crate::operators::{
+    chunks_to_df_unchecked, sort_chunks_by_index, DataChunk, FinalizedSink, PExecutionContext,
+    Sink, SinkResult,
+};
+
+// Adds a row index once all chunks are restored in the order they were streamed.
+// The threads don't know how many rows precede their chunks, so this cannot be an operator.
+#[derive(Clone)]
+pub struct RowIndexSink {
+    chunks: Vec<DataChunk>,
+    // output schema, so including the row index
+    schema: SchemaRef,
+    name: Arc<str>,
+    offset: Option<IdxSize>,
+}
+
+impl RowIndexSink {
+    pub fn new(schema: SchemaRef, name: Arc<str>, offset: Option<IdxSize>) -> Self {
+        RowIndexSink {
+            chunks: vec![],
+            schema,
+            name,
+            offset,
+        }
+    }
+}
+
+impl Sink for RowIndexSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 {
+            self.chunks.push(chunk);
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_ref::<RowIndexSink>().unwrap();
+        self.chunks.extend_from_slice(&other.chunks);
+        sort_chunks_by_index(&mut self.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::from(
+                self.schema.as_ref(),
+            )));
+        }
+        sort_chunks_by_index(&mut self.chunks);
+
+        let chunks = std::mem::take(&mut self.chunks);
+        let df = chunks_to_df_unchecked(chunks);
+        Ok(FinalizedSink::Finished(
+            df.with_row_index(self.name.as_ref(), self.offset)?,
+        ))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "row_index_sink"
+    }
+}
@@ -8,7 +8,8 @@ use polars_core::schema::SchemaRef;
 use polars_utils::atomic::SyncCounter;
 
 use crate::operators::{
-    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+    chunks_to_df_unchecked, sort_chunks_by_index, DataChunk, FinalizedSink, PExecutionContext,
+    Sink, SinkResult,
 };
 
 // Ensure the data is return in the order it was streamed
@@ -46,7 +47,7 @@ impl SliceSink {
 
     fn sort(&mut self) {
         let mut chunks = self.chunks.lock().unwrap();
-        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        sort_chunks_by_index(&mut chunks);
     }
 }
 
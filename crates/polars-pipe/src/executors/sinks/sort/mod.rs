@@ -1,7 +1,7 @@
 mod ooc;
 mod sink;
-mod sink_multiple;
 mod source;
+pub(crate) mod top_k;
 
 pub(crate) use sink::SortSink;
-pub(crate) use sink_multiple::SortSinkMultiple;
+pub(crate) use top_k::TopKSink;
@@ -2,6 +2,7 @@ use std::path::Path;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
 use crossbeam_queue::SegQueue;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{
@@ -11,6 +12,7 @@ use polars_core::POOL;
 use polars_io::ipc::IpcReader;
 use polars_io::SerReader;
 use polars_ops::prelude::*;
+use polars_plan::prelude::SortArguments;
 use rayon::prelude::*;
 
 use crate::executors::sinks::io::{DfIter, IOThread};
@@ -109,9 +111,8 @@ pub(super) fn sort_ooc(
     // these partitions are the samples
     // these are not yet assigned to a buckets
     samples: Series,
-    idx: usize,
-    descending: bool,
-    slice: Option<(i64, usize)>,
+    sort_idx: Arc<[usize]>,
+    sort_args: SortArguments,
     verbose: bool,
 ) -> PolarsResult<FinalizedSink> {
     let samples = samples.to_physical_repr().into_owned();
@@ -137,8 +138,9 @@ pub(super) fn sort_ooc(
             }
             let df = read_df(&path)?;
 
-            let sort_col = &df.get_columns()[idx];
-            let assigned_parts = det_partitions(sort_col, &samples, descending);
+            // rows are partitioned on all sort columns, so a partition never holds more than
+            // the rows between two samples
+            let assigned_parts = det_partitions(&df, &samples, &sort_idx, &sort_args)?;
 
             // partition the dataframe into proper buckets
             let (iter, unique_assigned_parts) = partition_df(df, &assigned_parts)?;
@@ -173,14 +175,34 @@ pub(super) fn sort_ooc(
         })
         .collect::<std::io::Result<Vec<_>>>()?;
 
-    let source = SortSource::new(files, idx, descending, slice, verbose, io_thread);
+    let source = SortSource::new(files, sort_idx, sort_args, verbose, io_thread);
     Ok(FinalizedSink::Source(Box::new(source)))
 }
 
-fn det_partitions(s: &Series, partitions: &Series, descending: bool) -> IdxCa {
-    let s = s.to_physical_repr();
+/// The row encoded sort columns that are used to sample and partition on.
+///
+/// The encoding takes `descending` and `nulls_last` into account, so comparing the bytes gives
+/// the order of the sort on all its columns.
+pub(super) fn partition_key(
+    df: &DataFrame,
+    sort_idx: &[usize],
+    sort_args: &SortArguments,
+) -> PolarsResult<BinaryOffsetChunked> {
+    let by = sort_idx
+        .iter()
+        .map(|i| df.get_columns()[*i].clone())
+        .collect::<Vec<_>>();
+    _get_rows_encoded_ca("", &by, &sort_args.descending, sort_args.nulls_last)
+}
 
-    search_sorted(partitions, &s, SearchSortedSide::Any, descending).unwrap()
+fn det_partitions(
+    df: &DataFrame,
+    partitions: &Series,
+    sort_idx: &[usize],
+    sort_args: &SortArguments,
+) -> PolarsResult<IdxCa> {
+    let key = partition_key(df, sort_idx, sort_args)?.into_series();
+    search_sorted(partitions, &key, SearchSortedSide::Any, false)
 }
 
 fn partition_df(df: DataFrame, partitions: &IdxCa) -> PolarsResult<(DfIter, IdxCa)> {
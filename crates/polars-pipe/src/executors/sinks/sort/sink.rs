@@ -4,13 +4,14 @@ use std::sync::{Arc, RwLock};
 use polars_core::config::verbose;
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{AnyValue, SchemaRef, Series, SortOptions};
+use polars_core::prelude::sort::_broadcast_descending;
+use polars_core::prelude::{BinaryChunked, IntoSeries, NewChunkedArray, SchemaRef};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_plan::prelude::SortArguments;
 
 use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
 use crate::executors::sinks::memory::MemTracker;
-use crate::executors::sinks::sort::ooc::sort_ooc;
+use crate::executors::sinks::sort::ooc::{partition_key, sort_ooc};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::{morsels_per_sink, FORCE_OOC};
 
@@ -26,11 +27,11 @@ pub struct SortSink {
     // RwLock as we want to have multiple readers at once.
     io_thread: Arc<RwLock<Option<IOThread>>>,
     // location in the dataframe of the columns to sort by
-    sort_idx: usize,
+    sort_idx: Arc<[usize]>,
     sort_args: SortArguments,
     // Statistics
-    // sampled values so we can find the distribution.
-    dist_sample: Vec<AnyValue<'static>>,
+    // sampled row encoded sort keys so we can find the distribution.
+    dist_sample: Vec<Vec<u8>>,
     // total rows accumulated in current chunk
     current_chunk_rows: usize,
     // total bytes of tables in current chunks
@@ -38,7 +39,12 @@ pub struct SortSink {
 }
 
 impl SortSink {
-    pub(crate) fn new(sort_idx: usize, sort_args: SortArguments, schema: SchemaRef) -> Self {
+    pub(crate) fn new(
+        sort_idx: Vec<usize>,
+        mut sort_args: SortArguments,
+        schema: SchemaRef,
+    ) -> Self {
+        _broadcast_descending(sort_idx.len(), &mut sort_args.descending);
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC).is_ok();
         let n_morsels_per_sink = morsels_per_sink();
@@ -49,7 +55,7 @@ impl SortSink {
             mem_track: MemTracker::new(n_morsels_per_sink),
             ooc,
             io_thread: Default::default(),
-            sort_idx,
+            sort_idx: Arc::from(sort_idx),
             sort_args,
             dist_sample: vec![],
             current_chunk_rows: 0,
@@ -78,8 +84,8 @@ impl SortSink {
 
     fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
         let chunk_bytes = chunk.data.estimated_size();
+        let used = self.mem_track.fetch_add(chunk_bytes);
         if !self.ooc {
-            let used = self.mem_track.fetch_add(chunk_bytes);
             let free = self.mem_track.get_available();
 
             // we need some free memory to be able to sort
@@ -108,17 +114,14 @@ impl SortSink {
             // expensive
             let df = accumulate_dataframes_vertical_unchecked(self.chunks.drain(..));
             if df.height() > 0 {
-                // SAFETY: we just asserted height > 0
-                let sample = unsafe {
-                    let s = &df.get_columns()[self.sort_idx];
-                    s.to_physical_repr().get_unchecked(0).into_static().unwrap()
-                };
-                self.dist_sample.push(sample);
+                let key = partition_key(&df.slice(0, 1), &self.sort_idx, &self.sort_args)?;
+                self.dist_sample.push(key.get(0).unwrap().to_vec());
 
                 let iot = self.io_thread.read().unwrap();
                 let iot = iot.as_ref().unwrap();
 
                 iot.dump_chunk(df);
+                self.mem_track.spilled(self.current_chunks_size);
 
                 // reset sizes
                 self.current_chunk_rows = 0;
@@ -130,11 +133,14 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.mem_track.bind(&context.memory_manager);
         self.store_chunk(chunk)?;
 
         if self.ooc {
-            self.dump(false)?;
+            // flush early if the memory budget is exhausted
+            let force = self.mem_track.get_available() == 0;
+            self.dump(force)?;
         }
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -158,7 +164,7 @@ impl Sink for SortSink {
             mem_track: self.mem_track.clone(),
             ooc: self.ooc,
             io_thread: self.io_thread.clone(),
-            sort_idx: self.sort_idx,
+            sort_idx: self.sort_idx.clone(),
             sort_args: self.sort_args.clone(),
             dist_sample: vec![],
             current_chunk_rows: 0,
@@ -167,39 +173,32 @@ impl Sink for SortSink {
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.mem_track.bind(&context.memory_manager);
         if self.ooc {
             // spill everything
             self.dump(true).unwrap();
             let mut lock = self.io_thread.write().unwrap();
             let io_thread = lock.take().unwrap();
 
-            let dist = Series::from_any_values("", &self.dist_sample, true).unwrap();
-            let dist = dist.sort_with(SortOptions {
-                descending: self.sort_args.descending[0],
-                nulls_last: self.sort_args.nulls_last,
-                multithreaded: true,
-                maintain_order: self.sort_args.maintain_order,
-            });
+            // the row encoding already accounts for `descending` and `nulls_last`
+            self.dist_sample.sort_unstable();
+            let dist = BinaryChunked::from_slice("", &self.dist_sample).into_series();
 
             block_thread_until_io_thread_done(&io_thread);
+            self.mem_track.release();
 
             sort_ooc(
                 io_thread,
                 dist,
-                self.sort_idx,
-                self.sort_args.descending[0],
-                self.sort_args.slice,
+                self.sort_idx.clone(),
+                self.sort_args.clone(),
                 context.verbose,
             )
         } else {
             let chunks = std::mem::take(&mut self.chunks);
+            self.mem_track.release();
             let df = accumulate_dataframes_vertical_unchecked(chunks);
-            let df = sort_accumulated(
-                df,
-                self.sort_idx,
-                self.sort_args.descending[0],
-                self.sort_args.slice,
-            )?;
+            let df = sort_accumulated(df, &self.sort_idx, &self.sort_args, self.sort_args.slice)?;
             Ok(FinalizedSink::Finished(df))
         }
     }
@@ -215,18 +214,21 @@ impl Sink for SortSink {
 
 pub(super) fn sort_accumulated(
     mut df: DataFrame,
-    sort_idx: usize,
-    descending: bool,
+    sort_idx: &[usize],
+    sort_args: &SortArguments,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
     // This is needed because we can have empty blocks and we require chunks to have single chunks.
     df.as_single_chunk_par();
-    let sort_column = df.get_columns()[sort_idx].clone();
+    let sort_columns = sort_idx
+        .iter()
+        .map(|i| df.get_columns()[*i].clone())
+        .collect();
     df.sort_impl(
-        vec![sort_column],
-        vec![descending],
-        false,
-        false,
+        sort_columns,
+        sort_args.descending.clone(),
+        sort_args.nulls_last,
+        sort_args.maintain_order,
         slice,
         true,
     )
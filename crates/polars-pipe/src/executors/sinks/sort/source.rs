@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
 use polars_core::POOL;
+use polars_plan::prelude::SortArguments;
 use rayon::prelude::*;
 
 use crate::executors::sinks::io::IOThread;
@@ -14,8 +15,8 @@ use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
 pub struct SortSource {
     files: std::vec::IntoIter<(u32, PathBuf)>,
     n_threads: usize,
-    sort_idx: usize,
-    descending: bool,
+    sort_idx: Arc<[usize]>,
+    sort_args: SortArguments,
     chunk_offset: IdxSize,
     slice: Option<(i64, usize)>,
     finished: bool,
@@ -25,9 +26,8 @@ pub struct SortSource {
 impl SortSource {
     pub(super) fn new(
         mut files: Vec<(u32, PathBuf)>,
-        sort_idx: usize,
-        descending: bool,
-        slice: Option<(i64, usize)>,
+        sort_idx: Arc<[usize]>,
+        sort_args: SortArguments,
         verbose: bool,
         io_thread: IOThread,
     ) -> Self {
@@ -39,12 +39,13 @@ impl SortSource {
 
         let n_threads = POOL.current_num_threads();
         let files = files.into_iter();
+        let slice = sort_args.slice;
 
         Self {
             files,
             n_threads,
             sort_idx,
-            descending,
+            sort_args,
             chunk_offset: get_source_index(1) as IdxSize,
             slice,
             finished: false,
@@ -89,7 +90,7 @@ impl Source for SortSource {
                 // We always need to sort again!
                 let current_slice = self.slice;
                 let mut df = match &mut self.slice {
-                    None => sort_accumulated(df, self.sort_idx, self.descending, None),
+                    None => sort_accumulated(df, &self.sort_idx, &self.sort_args, None),
                     Some((offset, len)) => {
                         let df_len = df.height();
                         assert!(*offset >= 0);
@@ -97,8 +98,12 @@ impl Source for SortSource {
                             *offset -= df_len as i64;
                             Ok(df.slice(0, 0))
                         } else {
-                            let out =
-                                sort_accumulated(df, self.sort_idx, self.descending, current_slice);
+                            let out = sort_accumulated(
+                                df,
+                                &self.sort_idx,
+                                &self.sort_args,
+                                current_slice,
+                            );
                             *len = len.saturating_sub(df_len);
                             *offset = 0;
                             out
@@ -0,0 +1,242 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::sort::_broadcast_descending;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
+use polars_core::prelude::{IdxCa, IdxSize, SchemaRef};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::prelude::SortArguments;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+// Larger slices go through the `SortSink` which can go out-of-core.
+const TOP_K_MAX_ROWS: usize = 50_000;
+
+/// Whether a sort with these arguments runs in bounded memory in the `TopKSink`.
+pub fn is_bounded_top_k(sort_args: &SortArguments) -> bool {
+    match sort_args.slice {
+        Some((offset, len)) => {
+            offset >= 0 && (offset as usize).saturating_add(len) <= TOP_K_MAX_ROWS
+        },
+        None => false,
+    }
+}
+
+// A row that is kept by the `TopKSink`.
+struct TopKRow {
+    // row encoded sort columns
+    key: Box<[u8]>,
+    // chunk index and offset in that chunk, equal keys are ordered as they were streamed
+    position: (IdxSize, IdxSize),
+    // location of the row in the buffered chunks
+    df_idx: usize,
+    row: IdxSize,
+}
+
+impl Ord for TopKRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&*self.key, self.position).cmp(&(&*other.key, other.position))
+    }
+}
+
+impl PartialOrd for TopKRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TopKRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TopKRow {}
+
+/// Sink for a sort followed by a slice (`top_k`, `bottom_k`, `sort().head(n)`).
+///
+/// Every thread keeps the best `offset + len` rows in a max-heap on their row encoded sort key,
+/// so the worst kept row is replaced when a better one comes in. The chunks that hold the kept
+/// rows are gathered into a single chunk once they exceed `2 * (offset + len)` rows, so the
+/// memory doesn't depend on the size of the input. Ties are broken on the position in the
+/// stream, which maintains the order of equal rows.
+pub struct TopKSink {
+    schema: SchemaRef,
+    // chunks that hold the rows in `heap`
+    chunks: Vec<DataFrame>,
+    // total rows in `chunks`
+    rows: usize,
+    heap: BinaryHeap<TopKRow>,
+    // location in the dataframe of the columns to sort by
+    sort_idx: Arc<[usize]>,
+    sort_args: SortArguments,
+    offset: usize,
+    // number of rows we need to keep: offset + len
+    k: usize,
+}
+
+impl TopKSink {
+    pub(crate) fn new(
+        sort_idx: Vec<usize>,
+        mut sort_args: SortArguments,
+        schema: SchemaRef,
+    ) -> Self {
+        _broadcast_descending(sort_idx.len(), &mut sort_args.descending);
+        let (offset, len) = sort_args.slice.expect("top-k needs a slice");
+        debug_assert!(offset >= 0);
+
+        TopKSink {
+            schema,
+            chunks: vec![],
+            rows: 0,
+            heap: BinaryHeap::new(),
+            sort_idx: Arc::from(sort_idx),
+            offset: offset as usize,
+            k: offset as usize + len,
+            sort_args,
+        }
+    }
+
+    fn accepts(&self, key: &[u8], position: (IdxSize, IdxSize)) -> bool {
+        if self.heap.len() < self.k {
+            return true;
+        }
+        self.heap
+            .peek()
+            .is_some_and(|worst| (key, position) < (&*worst.key, worst.position))
+    }
+
+    fn push(&mut self, row: TopKRow) {
+        if self.heap.len() == self.k {
+            self.heap.pop();
+        }
+        self.heap.push(row);
+    }
+
+    /// Take the `rows` from the buffered chunks.
+    fn gather(&self, rows: &[TopKRow]) -> PolarsResult<DataFrame> {
+        let mut offsets = Vec::with_capacity(self.chunks.len());
+        let mut offset = 0;
+        for df in &self.chunks {
+            offsets.push(offset);
+            offset += df.height() as IdxSize;
+        }
+        let idx = rows
+            .iter()
+            .map(|row| offsets[row.df_idx] + row.row)
+            .collect::<Vec<_>>();
+
+        let df = accumulate_dataframes_vertical_unchecked(self.chunks.iter().cloned());
+        df.take(&IdxCa::from_vec("", idx))
+    }
+
+    /// Gather the kept rows into a single chunk, which frees the rows that were replaced.
+    fn compact(&mut self) -> PolarsResult<()> {
+        let mut rows = std::mem::take(&mut self.heap).into_vec();
+        let df = self.gather(&rows)?;
+        for (i, row) in rows.iter_mut().enumerate() {
+            row.df_idx = 0;
+            row.row = i as IdxSize;
+        }
+        self.rows = df.height();
+        self.chunks = vec![df];
+        self.heap = BinaryHeap::from(rows);
+        Ok(())
+    }
+}
+
+impl Sink for TopKSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() == 0 || self.k == 0 {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let by = self
+            .sort_idx
+            .iter()
+            .map(|i| chunk.data.get_columns()[*i].clone())
+            .collect::<Vec<_>>();
+        let keys = _get_rows_encoded_ca(
+            "",
+            &by,
+            &self.sort_args.descending,
+            self.sort_args.nulls_last,
+        )?;
+
+        let df_idx = self.chunks.len();
+        let mut kept = false;
+        for (row, key) in keys.into_no_null_iter().enumerate() {
+            let position = (chunk.chunk_index, row as IdxSize);
+            if self.accepts(key, position) {
+                self.push(TopKRow {
+                    key: key.into(),
+                    position,
+                    df_idx,
+                    row: row as IdxSize,
+                });
+                kept = true;
+            }
+        }
+
+        if kept {
+            self.rows += chunk.data.height();
+            self.chunks.push(chunk.data);
+            if self.rows > 2 * self.k {
+                self.compact()?;
+            }
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        let df_offset = self.chunks.len();
+        self.chunks.extend(std::mem::take(&mut other.chunks));
+        self.rows += std::mem::take(&mut other.rows);
+        for mut row in std::mem::take(&mut other.heap) {
+            if self.accepts(&row.key, row.position) {
+                row.df_idx += df_offset;
+                self.push(row);
+            }
+        }
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            schema: self.schema.clone(),
+            chunks: vec![],
+            rows: 0,
+            heap: BinaryHeap::new(),
+            sort_idx: self.sort_idx.clone(),
+            sort_args: self.sort_args.clone(),
+            offset: self.offset,
+            k: self.k,
+        })
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::from(
+                self.schema.as_ref(),
+            )));
+        }
+        let rows = std::mem::take(&mut self.heap).into_sorted_vec();
+        let rows = rows.get(self.offset..).unwrap_or_default();
+        let df = self.gather(rows)?;
+        self.chunks.clear();
+        self.rows = 0;
+        Ok(FinalizedSink::Finished(df))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "top_k"
+    }
+}
@@ -0,0 +1,88 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::{DataFrame, UniqueKeepStrategy};
+use polars_core::schema::SchemaRef;
+
+use crate::operators::{
+    chunks_to_df_unchecked, sort_chunks_by_index, DataChunk, FinalizedSink, PExecutionContext,
+    Sink, SinkResult,
+};
+
+// Keeps the first/last occurrence of every key in the order the data was streamed.
+// Every thread deduplicates its chunks locally, the remaining rows are deduplicated
+// again after the chunks are restored in their original order.
+#[derive(Clone)]
+pub struct OrderedUniqueSink {
+    chunks: Vec<DataChunk>,
+    schema: SchemaRef,
+    subset: Option<Arc<Vec<String>>>,
+    keep: UniqueKeepStrategy,
+    slice: Option<(i64, usize)>,
+}
+
+impl OrderedUniqueSink {
+    pub fn new(
+        schema: SchemaRef,
+        subset: Option<Arc<Vec<String>>>,
+        keep: UniqueKeepStrategy,
+        slice: Option<(i64, usize)>,
+    ) -> Self {
+        // `None` must see all occurrences at once, a local deduplication would be incorrect.
+        debug_assert!(!matches!(keep, UniqueKeepStrategy::None));
+        OrderedUniqueSink {
+            chunks: vec![],
+            schema,
+            subset,
+            keep,
+            slice,
+        }
+    }
+
+    fn unique(&self, df: &DataFrame, slice: Option<(i64, usize)>) -> PolarsResult<DataFrame> {
+        df.unique_stable(self.subset.as_ref().map(|s| s.as_slice()), self.keep, slice)
+    }
+}
+
+impl Sink for OrderedUniqueSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 {
+            let df = self.unique(&chunk.data, None)?;
+            self.chunks.push(chunk.with_data(df));
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_ref::<OrderedUniqueSink>().unwrap();
+        self.chunks.extend_from_slice(&other.chunks);
+        sort_chunks_by_index(&mut self.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::from(
+                self.schema.as_ref(),
+            )));
+        }
+        sort_chunks_by_index(&mut self.chunks);
+
+        let chunks = std::mem::take(&mut self.chunks);
+        let df = chunks_to_df_unchecked(chunks);
+        Ok(FinalizedSink::Finished(self.unique(&df, self.slice)?))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "ordered_unique_sink"
+    }
+}
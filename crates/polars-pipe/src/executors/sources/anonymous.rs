@@ -0,0 +1,115 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::POOL;
+use polars_plan::prelude::{AnonymousBatchIter, AnonymousScan, AnonymousScanArgs};
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Streams the batches of an [`AnonymousScan`] that supports batched reads.
+pub struct AnonymousSource {
+    function: Arc<dyn AnonymousScan>,
+    args: AnonymousScanArgs,
+    // only used to prune partitions with their statistics
+    predicate: Option<Arc<dyn PhysicalPipedExpr>>,
+    partitions: Range<usize>,
+    batches: Option<AnonymousBatchIter>,
+    rows_left: usize,
+    n_threads: usize,
+    verbose: bool,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        args: AnonymousScanArgs,
+        predicate: Option<Arc<dyn PhysicalPipedExpr>>,
+        verbose: bool,
+    ) -> Self {
+        let n_partitions = function.n_partitions().unwrap_or(0);
+        let rows_left = args.n_rows.unwrap_or(usize::MAX);
+        AnonymousSource {
+            function,
+            args,
+            predicate,
+            partitions: 0..n_partitions,
+            batches: None,
+            rows_left,
+            n_threads: POOL.current_num_threads(),
+            verbose,
+        }
+    }
+
+    /// Start reading the next partition that may contain matching rows.
+    fn next_partition(&mut self) -> PolarsResult<bool> {
+        let evaluator = self
+            .predicate
+            .as_ref()
+            .and_then(|predicate| predicate.as_stats_evaluator());
+
+        for partition in self.partitions.by_ref() {
+            if self.function.should_read_partition(partition, evaluator)? {
+                self.batches = Some(self.function.scan_batches(partition, self.args.clone())?);
+                return Ok(true);
+            } else if self.verbose {
+                eprintln!("anonymous scan: skipped partition {partition}");
+            }
+        }
+        Ok(false)
+    }
+}
+
+// SAFETY: the batch iterator is only accessed through `&mut self`.
+unsafe impl Sync for AnonymousSource {}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let mut dfs = Vec::with_capacity(self.n_threads);
+        while dfs.len() < self.n_threads && self.rows_left > 0 {
+            let next = match &mut self.batches {
+                Some(batches) => batches.next(),
+                None => {
+                    if self.next_partition()? {
+                        continue;
+                    }
+                    break;
+                },
+            };
+            match next {
+                Some(df) => {
+                    let mut df = df?;
+                    if df.height() == 0 {
+                        continue;
+                    }
+                    self.rows_left = self.rows_left.saturating_sub(df.height());
+                    df.as_single_chunk_par();
+                    self.function.set_sorted_flags(&mut df);
+                    dfs.push(df);
+                },
+                None => self.batches = None,
+            }
+        }
+
+        if dfs.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+        let idx_offset = get_source_index(dfs.len() as u32);
+        let chunks = dfs
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i as u32) as IdxSize,
+                data,
+            })
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
@@ -27,6 +27,13 @@ impl DataChunk {
     }
 }
 
+/// Restore the order in which the chunks were streamed.
+///
+/// The sort must be stable: an operator can produce multiple chunks with the same index.
+pub(crate) fn sort_chunks_by_index(chunks: &mut [DataChunk]) {
+    chunks.sort_by_key(|chunk| chunk.chunk_index);
+}
+
 pub(crate) fn chunks_to_df_unchecked(chunks: Vec<DataChunk>) -> DataFrame {
     accumulate_dataframes_vertical_unchecked(chunks.into_iter().map(|c| c.data))
 }
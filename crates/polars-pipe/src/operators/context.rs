@@ -1,24 +1,69 @@
 use std::any::Any;
 
 use polars_core::prelude::*;
+use polars_plan::logical_plan::PlanProfiler;
+
+use crate::pipeline::{MemoryManager, ProgressTracker};
 
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 
     fn should_stop(&self) -> PolarsResult<()>;
+
+    /// The memory budget shared by all pipelines of the query, if one was configured.
+    fn memory_manager(&self) -> Option<Arc<MemoryManager>> {
+        None
+    }
+
+    /// Tracker that reports the progress of the query, if one was registered.
+    fn progress_tracker(&self) -> Option<Arc<ProgressTracker>> {
+        None
+    }
+
+    /// Profiler that records the runtime statistics of the plan nodes, if one was registered.
+    fn plan_profiler(&self) -> Option<Arc<PlanProfiler>> {
+        None
+    }
 }
 
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: Box<dyn SExecutionContext>,
     pub(crate) verbose: bool,
+    pub(crate) memory_manager: Arc<MemoryManager>,
+    pub(crate) progress: Option<Arc<ProgressTracker>>,
+    pub(crate) profiler: Option<Arc<PlanProfiler>>,
 }
 
 impl PExecutionContext {
     pub(crate) fn new(state: Box<dyn SExecutionContext>, verbose: bool) -> Self {
+        let memory_manager = state.memory_manager().unwrap_or_default();
+        let progress = state.progress_tracker();
+        let profiler = state.plan_profiler();
         PExecutionContext {
             execution_state: state,
             verbose,
+            memory_manager,
+            progress,
+            profiler,
+        }
+    }
+
+    pub(crate) fn register_source(&self, name: &str) -> Option<usize> {
+        self.progress
+            .as_ref()
+            .map(|progress| progress.register_source(name))
+    }
+
+    pub(crate) fn register_sink(&self, name: &str) -> Option<usize> {
+        self.progress
+            .as_ref()
+            .map(|progress| progress.register_sink(name))
+    }
+
+    pub(crate) fn report_progress(&self) {
+        if let Some(progress) = &self.progress {
+            progress.report(self.memory_manager.spilled())
         }
     }
 }
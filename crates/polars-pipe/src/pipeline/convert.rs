@@ -12,6 +12,7 @@ use polars_plan::prelude::*;
 use crate::executors::operators::HstackOperator;
 use crate::executors::sinks::group_by::aggregates::convert_to_hash_agg;
 use crate::executors::sinks::group_by::GenericGroupby2;
+use crate::executors::sinks::sort::top_k::is_bounded_top_k;
 use crate::executors::sinks::*;
 use crate::executors::{operators, sources};
 use crate::expressions::PhysicalPipedExpr;
@@ -147,6 +148,24 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { function, .. } => {
+                    let pushed_predicate = predicate
+                        .filter(|_| function.allows_predicate_pushdown())
+                        .map(|predicate| node_to_expr(predicate, expr_arena));
+                    let args = AnonymousScanArgs {
+                        n_rows: file_options.n_rows,
+                        with_columns: file_options.with_columns,
+                        schema: file_info.schema,
+                        output_schema: output_schema.clone(),
+                        predicate: pushed_predicate,
+                    };
+                    let predicate = predicate
+                        .map(|predicate| to_physical(predicate, expr_arena, output_schema.as_ref()))
+                        .transpose()?;
+                    let src = sources::AnonymousSource::new(function, args, predicate, verbose);
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -297,28 +316,31 @@ where
             args,
         } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
-
-            if by_column.len() == 1 {
-                let by_column = aexpr_to_leaf_names_iter(by_column[0], expr_arena)
-                    .next()
-                    .unwrap();
-                let index = input_schema.try_index_of(by_column.as_ref())?;
-
-                let sort_sink = SortSink::new(index, args.clone(), input_schema);
-                Box::new(sort_sink) as Box<dyn SinkTrait>
+            let sort_idx = by_column
+                .iter()
+                .map(|node| {
+                    let name = aexpr_to_leaf_names_iter(*node, expr_arena).next().unwrap();
+                    input_schema.try_index_of(name.as_ref())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            if is_bounded_top_k(args) {
+                let top_k_sink = TopKSink::new(sort_idx, args.clone(), input_schema);
+                Box::new(top_k_sink) as Box<dyn SinkTrait>
             } else {
-                let sort_idx = by_column
-                    .iter()
-                    .map(|node| {
-                        let name = aexpr_to_leaf_names_iter(*node, expr_arena).next().unwrap();
-                        input_schema.try_index_of(name.as_ref())
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
-
-                let sort_sink = SortSinkMultiple::new(args.clone(), input_schema, sort_idx)?;
+                let sort_sink = SortSink::new(sort_idx, args.clone(), input_schema);
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             }
         },
+        Distinct { input, options } if options.maintain_order => {
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            Box::new(OrderedUniqueSink::new(
+                input_schema,
+                options.subset.clone(),
+                options.keep_strategy,
+                options.slice,
+            )) as Box<dyn SinkTrait>
+        },
         Distinct { input, options } => {
             // We create a Groupby.agg_first()/agg_last (depending on the keep strategy
             let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
@@ -403,6 +425,17 @@ where
 
             Box::new(ReProjectSink::new(input_schema, group_by_sink))
         },
+        MapFunction {
+            function:
+                FunctionNode::RowIndex {
+                    name,
+                    schema,
+                    offset,
+                },
+            ..
+        } => {
+            Box::new(RowIndexSink::new(schema.clone(), name.clone(), *offset)) as Box<dyn SinkTrait>
+        },
         Aggregate {
             input,
             keys,
@@ -700,6 +733,7 @@ where
 
     Ok(PipeLine::new(
         source_objects,
+        sources.to_vec(),
         operator_objects,
         operator_nodes,
         sinks,
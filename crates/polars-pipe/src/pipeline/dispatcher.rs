@@ -3,11 +3,13 @@ use std::collections::{BTreeSet, VecDeque};
 use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::POOL;
+use polars_plan::logical_plan::PlanProfiler;
 use polars_utils::arena::Node;
 use polars_utils::sync::SyncPtr;
 use rayon::prelude::*;
@@ -95,6 +97,8 @@ impl SinkNode {
 pub struct PipeLine {
     /// All the sources of this pipeline
     sources: Vec<Box<dyn Source>>,
+    /// The nodes of the sources, empty once a sink became the source
+    source_nodes: Vec<Node>,
     /// All the operators of this pipeline. Some may be placeholders that will be replaced during
     /// execution
     operators: Vec<Vec<Box<dyn Operator>>>,
@@ -119,12 +123,20 @@ pub struct PipeLine {
     operator_offset: usize,
     /// Log runtime info to stderr
     verbose: bool,
+    /// Index of the sink that currently receives data in the progress report
+    progress_sink: Option<usize>,
+    /// Whether the nodes are nodes of the plan and their runtime statistics are recorded.
+    /// Pipelines that are created by a sink itself are not part of the plan.
+    profile_nodes: bool,
+    /// Node of the sink that currently receives data
+    profile_sink: Option<Node>,
 }
 
 impl PipeLine {
     #[allow(clippy::type_complexity)]
     pub(super) fn new(
         sources: Vec<Box<dyn Source>>,
+        source_nodes: Vec<Node>,
         operators: Vec<Box<dyn Operator>>,
         operator_nodes: Vec<Node>,
         sinks: Vec<SinkNode>,
@@ -145,6 +157,7 @@ impl PipeLine {
 
         PipeLine {
             sources,
+            source_nodes,
             operators,
             operator_nodes,
             sinks,
@@ -152,6 +165,9 @@ impl PipeLine {
             other_branches: Default::default(),
             operator_offset,
             verbose,
+            progress_sink: None,
+            profile_nodes: true,
+            profile_sink: None,
         }
     }
 
@@ -163,8 +179,9 @@ impl PipeLine {
         verbose: bool,
     ) -> Self {
         let operators_len = operators.len();
-        Self::new(
+        let mut pipeline = Self::new(
             sources,
+            vec![],
             operators,
             vec![],
             vec![SinkNode::new(
@@ -175,7 +192,9 @@ impl PipeLine {
             )],
             0,
             verbose,
-        )
+        );
+        pipeline.profile_nodes = false;
+        pipeline
     }
 
     /// Add a parent
@@ -198,10 +217,108 @@ impl PipeLine {
         }
     }
 
+    /// The node of the operator at `idx`, `None` if it was inserted by a source.
+    fn operator_node(&self, idx: usize) -> Option<Node> {
+        idx.checked_sub(self.operator_offset)
+            .and_then(|idx| self.operator_nodes.get(idx).copied())
+    }
+
+    fn profiler(&self, ec: &PExecutionContext) -> Option<Arc<PlanProfiler>> {
+        if self.profile_nodes {
+            ec.profiler.clone()
+        } else {
+            None
+        }
+    }
+
+    fn execute_operator(
+        &self,
+        op: &mut Box<dyn Operator>,
+        idx: usize,
+        ec: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        match (self.profiler(ec), self.operator_node(idx)) {
+            (Some(profiler), Some(node)) => {
+                let start = Instant::now();
+                let out = op.execute(ec, chunk)?;
+                profiler.record_batch(node, start.elapsed(), operator_output(&out));
+                Ok(out)
+            },
+            _ => op.execute(ec, chunk),
+        }
+    }
+
+    fn flush_operator(
+        &self,
+        op: &mut Box<dyn Operator>,
+        idx: usize,
+        ec: &PExecutionContext,
+    ) -> PolarsResult<OperatorResult> {
+        match (self.profiler(ec), self.operator_node(idx)) {
+            (Some(profiler), Some(node)) => {
+                let start = Instant::now();
+                let out = op.flush()?;
+                profiler.record_batch(node, start.elapsed(), operator_output(&out));
+                Ok(out)
+            },
+            _ => op.flush(),
+        }
+    }
+
+    /// Pull the next batches from a source.
+    fn pull_batches(
+        &self,
+        src: &mut dyn Source,
+        node: Option<Node>,
+        ec: &PExecutionContext,
+    ) -> PolarsResult<SourceResult> {
+        let (Some(profiler), Some(node)) = (self.profiler(ec), node) else {
+            return src.get_batches(ec);
+        };
+        let start = Instant::now();
+        let out = src.get_batches(ec)?;
+        profiler.record_batch(node, start.elapsed(), None);
+        if let SourceResult::GotMoreData(chunks) = &out {
+            for chunk in chunks {
+                profiler.record_batch(node, Duration::ZERO, Some(&chunk.data));
+            }
+        }
+        Ok(out)
+    }
+
+    fn finalize_sink(
+        &self,
+        sink: &mut dyn Sink,
+        ec: &PExecutionContext,
+    ) -> PolarsResult<FinalizedSink> {
+        self.finalize_sink_node(sink, self.profile_sink, ec)
+    }
+
+    fn finalize_sink_node(
+        &self,
+        sink: &mut dyn Sink,
+        node: Option<Node>,
+        ec: &PExecutionContext,
+    ) -> PolarsResult<FinalizedSink> {
+        let (Some(profiler), Some(node)) = (self.profiler(ec), node) else {
+            return sink.finalize(ec);
+        };
+        let start = Instant::now();
+        let out = sink.finalize(ec)?;
+        let time = start.elapsed();
+        match &out {
+            FinalizedSink::Finished(df) => profiler.record_materialized(node, time, df),
+            _ => profiler.record_batch(node, time, None),
+        }
+        Ok(out)
+    }
+
     /// Take data chunks from the sources and pushes them into the operators + sink. Every operator
     /// works thread local.
     /// The caller passes an `operator_start`/`operator_end` to indicate which part of the pipeline
     /// branch should be executed.
+    #[allow(clippy::too_many_arguments)]
     fn par_process_chunks(
         &mut self,
         chunks: Vec<DataChunk>,
@@ -210,6 +327,7 @@ impl PipeLine {
         operator_start: usize,
         operator_end: usize,
         src: &mut Box<dyn Source>,
+        src_node: Option<Node>,
     ) -> PolarsResult<(Option<SinkResult>, SourceResult)> {
         debug_assert!(chunks.len() <= sink.len());
 
@@ -226,9 +344,9 @@ impl PipeLine {
             let operator_pipe = &mut operator_pipe[operator_start..operator_end];
 
             if operator_pipe.is_empty() {
-                sink.sink(ec, chunk)
+                pipe.sink_chunk(chunk, ec, sink)
             } else {
-                pipe.push_operators(chunk, ec, operator_pipe, sink)
+                pipe.push_operators(chunk, ec, operator_pipe, operator_start, sink)
             }
         }
         let sink_results = Arc::new(Mutex::new(None));
@@ -280,7 +398,7 @@ impl PipeLine {
             // already get batches on the thread pool
             // if one job is finished earlier we can already start that work
             s.spawn(|_| {
-                let out = src.get_batches(ec);
+                let out = pipeline.pull_batches(&mut **src, src_node, ec);
                 unsafe {
                     let ptr = next_batches_ptr.get();
                     *ptr = Some(out);
@@ -305,6 +423,7 @@ impl PipeLine {
         chunk: DataChunk,
         ec: &PExecutionContext,
         operators: &mut [Box<dyn Operator>],
+        operator_start: usize,
         sink: &mut Box<dyn Sink>,
     ) -> PolarsResult<SinkResult> {
         debug_assert!(!operators.is_empty());
@@ -318,12 +437,12 @@ impl PipeLine {
         while let Some((op_i, chunk)) = in_process.pop() {
             match operators.get_mut(op_i) {
                 None => {
-                    if let SinkResult::Finished = sink.sink(ec, chunk)? {
+                    if let SinkResult::Finished = self.sink_chunk(chunk, ec, sink)? {
                         return Ok(SinkResult::Finished);
                     }
                 },
                 Some(op) => {
-                    match op.execute(ec, &chunk)? {
+                    match self.execute_operator(op, operator_start + op_i, ec, &chunk)? {
                         OperatorResult::Finished(chunk) => {
                             if op.must_flush() {
                                 let _ = needs_flush.insert(op_i);
@@ -368,7 +487,7 @@ impl PipeLine {
                         // The branch for flushing.
                         None => {
                             let op = operators.get_mut(op_i).unwrap();
-                            match op.flush()? {
+                            match self.flush_operator(op, operator_start + op_i, ec)? {
                                 OperatorResult::Finished(chunk) => {
                                     // Push the chunk in the next operator.
                                     in_process.push((op_i + 1, Some(chunk)))
@@ -388,12 +507,19 @@ impl PipeLine {
                         Some(chunk) => {
                             match operators.get_mut(op_i) {
                                 None => {
-                                    if let SinkResult::Finished = sink.sink(ec, chunk)? {
+                                    if let SinkResult::Finished =
+                                        self.sink_chunk(chunk, ec, sink)?
+                                    {
                                         return Ok(SinkResult::Finished);
                                     }
                                 },
                                 Some(op) => {
-                                    match op.execute(ec, &chunk)? {
+                                    match self.execute_operator(
+                                        op,
+                                        operator_start + op_i,
+                                        ec,
+                                        &chunk,
+                                    )? {
                                         OperatorResult::Finished(chunk) => {
                                             in_process.push((op_i + 1, Some(chunk)))
                                         },
@@ -422,6 +548,26 @@ impl PipeLine {
         Ok(SinkResult::CanHaveMoreInput)
     }
 
+    fn sink_chunk(
+        &self,
+        chunk: DataChunk,
+        ec: &PExecutionContext,
+        sink: &mut Box<dyn Sink>,
+    ) -> PolarsResult<SinkResult> {
+        if let (Some(progress), Some(sink_idx)) = (&ec.progress, self.progress_sink) {
+            progress.add_rows_emitted(sink_idx, chunk.data.height())
+        }
+        match (self.profiler(ec), self.profile_sink) {
+            (Some(profiler), Some(node)) => {
+                let start = Instant::now();
+                let out = sink.sink(ec, chunk)?;
+                profiler.record_batch(node, start.elapsed(), None);
+                Ok(out)
+            },
+            _ => sink.sink(ec, chunk),
+        }
+    }
+
     /// Replace the current sources with a [`DataFrameSource`].
     fn set_df_as_sources(&mut self, df: DataFrame) {
         let src = Box::new(DataFrameSource::from_df(df)) as Box<dyn Source>;
@@ -431,6 +577,7 @@ impl PipeLine {
     /// Replace the current sources.
     fn set_sources(&mut self, src: Box<dyn Source>) {
         self.sources.clear();
+        self.source_nodes.clear();
         self.sources.push(src);
     }
 
@@ -450,13 +597,23 @@ impl PipeLine {
         let mut sink_finished = false;
 
         for (i, mut sink) in std::mem::take(&mut self.sinks).into_iter().enumerate() {
-            for src in &mut std::mem::take(&mut self.sources) {
-                let mut next_batches = src.get_batches(ec)?;
+            self.progress_sink = ec.register_sink(sink.sinks[0].fmt());
+            self.profile_sink = Some(sink.node);
+            let source_nodes = std::mem::take(&mut self.source_nodes);
+            for (src_idx, src) in std::mem::take(&mut self.sources).iter_mut().enumerate() {
+                let progress_source = ec.register_source(src.fmt());
+                let src_node = source_nodes.get(src_idx).copied();
+                let mut next_batches = self.pull_batches(&mut **src, src_node, ec)?;
 
                 while let SourceResult::GotMoreData(chunks) = next_batches {
                     // Every batches iteration we check if we must continue.
                     ec.execution_state.should_stop()?;
 
+                    if let (Some(progress), Some(source_idx)) = (&ec.progress, progress_source) {
+                        let rows = chunks.iter().map(|chunk| chunk.data.height()).sum();
+                        progress.add_rows_read(source_idx, rows);
+                    }
+
                     let (sink_result, next_batches2) = self.par_process_chunks(
                         chunks,
                         &mut sink.sinks,
@@ -464,8 +621,10 @@ impl PipeLine {
                         operator_start,
                         sink.operator_end,
                         src,
+                        src_node,
                     )?;
                     next_batches = next_batches2;
+                    ec.report_progress();
 
                     if let Some(SinkResult::Finished) = sink_result {
                         sink_finished = true;
@@ -519,13 +678,15 @@ impl PipeLine {
                     if sink.is_join_build()
                         && (!reduced_sink.is_join_build() || (sink.node() != reduced_sink.node()))
                     {
-                        let FinalizedSink::Operator(op) = sink.finalize(ec)? else {
+                        let Some(node) = pipeline.sink_nodes.pop() else {
                             unreachable!()
                         };
-                        let mut q = pipeline_q.borrow_mut();
-                        let Some(node) = pipeline.sink_nodes.pop() else {
+                        let FinalizedSink::Operator(op) =
+                            pipeline.finalize_sink_node(sink.as_mut(), Some(node), ec)?
+                        else {
                             unreachable!()
                         };
+                        let mut q = pipeline_q.borrow_mut();
 
                         for probe_side in q.iter_mut() {
                             let _ = probe_side.replace_operator(op.as_ref(), node);
@@ -538,7 +699,7 @@ impl PipeLine {
             }
 
             if i != last_i {
-                let sink_result = reduced_sink.finalize(ec)?;
+                let sink_result = self.finalize_sink(reduced_sink.as_mut(), ec)?;
                 match sink_result {
                     // turn this sink an a new source
                     FinalizedSink::Finished(df) => self.set_df_as_sources(df),
@@ -568,7 +729,9 @@ impl PipeLine {
         let (sink_shared_count, mut reduced_sink) =
             self.run_pipeline_no_finalize(ec, pipeline_q)?;
         assert_eq!(sink_shared_count, 0);
-        Ok(reduced_sink.finalize(ec).ok())
+        let out = self.finalize_sink(reduced_sink.as_mut(), ec).ok();
+        ec.report_progress();
+        Ok(out)
     }
 
     /// Executes all branches and replaces operators and sinks during execution to ensure
@@ -644,6 +807,15 @@ impl Debug for PipeLine {
     }
 }
 
+fn operator_output(out: &OperatorResult) -> Option<&DataFrame> {
+    match out {
+        OperatorResult::Finished(chunk) | OperatorResult::HaveMoreOutPut(chunk) => {
+            Some(&chunk.data)
+        },
+        OperatorResult::NeedsNewData => None,
+    }
+}
+
 /// Take a source and materialize it into a [`DataFrame`].
 fn consume_source(src: &mut dyn Source, context: &PExecutionContext) -> PolarsResult<DataFrame> {
     let mut frames = Vec::with_capacity(32);
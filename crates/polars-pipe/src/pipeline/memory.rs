@@ -0,0 +1,165 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::prelude::*;
+
+/// Memory budget of a streaming query.
+///
+/// A single manager is shared by all pipelines of a query. Sinks that buffer data reserve
+/// the bytes they hold and release them again once that data is spilled to disk or handed
+/// over. Spilling sinks (sort, group-by) use the remaining budget to decide when to go
+/// out-of-core, sinks that cannot spill (join build) return an error once the budget is
+/// exhausted.
+///
+/// Without a limit the manager only does bookkeeping and the sinks fall back to the
+/// available system memory.
+#[derive(Debug, Default)]
+pub struct MemoryManager {
+    limit: Option<usize>,
+    reserved: AtomicUsize,
+    peak: AtomicUsize,
+    spilled: AtomicUsize,
+}
+
+impl MemoryManager {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            ..Default::default()
+        }
+    }
+
+    /// The configured memory limit in bytes.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Bytes currently reserved by the sinks.
+    pub fn reserved(&self) -> usize {
+        self.reserved.load(Ordering::Relaxed)
+    }
+
+    /// Highest number of bytes that were reserved at once.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    /// Bytes that were written to disk by spilling sinks.
+    pub fn spilled(&self) -> usize {
+        self.spilled.load(Ordering::Relaxed)
+    }
+
+    /// Bytes that are left in the budget, or `None` if there is no limit.
+    pub fn remaining(&self) -> Option<usize> {
+        self.limit
+            .map(|limit| limit.saturating_sub(self.reserved()))
+    }
+
+    /// Reserve `bytes` regardless of the limit and return the previous reservation.
+    ///
+    /// Used by sinks that can spill; they check the budget afterwards.
+    pub fn reserve(&self, bytes: usize) -> usize {
+        let prev = self.reserved.fetch_add(bytes, Ordering::Relaxed);
+        self.peak.fetch_max(prev + bytes, Ordering::Relaxed);
+        prev
+    }
+
+    /// Reserve `bytes` if that fits in the budget.
+    pub fn try_reserve(&self, bytes: usize) -> PolarsResult<()> {
+        let Some(limit) = self.limit else {
+            self.reserve(bytes);
+            return Ok(());
+        };
+        let reserved =
+            self.reserved
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                    let new = reserved + bytes;
+                    (new <= limit).then_some(new)
+                });
+        match reserved {
+            Ok(prev) => {
+                self.peak.fetch_max(prev + bytes, Ordering::Relaxed);
+                Ok(())
+            },
+            Err(reserved) => polars_bail!(
+                ComputeError: "memory limit of {} bytes exceeded: {} bytes reserved, {} bytes requested",
+                limit, reserved, bytes
+            ),
+        }
+    }
+
+    /// Give `bytes` back to the budget.
+    pub fn release(&self, bytes: usize) {
+        let _ = self
+            .reserved
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                Some(reserved.saturating_sub(bytes))
+            });
+    }
+
+    pub(crate) fn record_spill(&self, bytes: usize) {
+        self.spilled.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
+/// Bytes reserved in a [`MemoryManager`] that are released when this is dropped.
+///
+/// A join build side cannot spill, so its data is held in the budget until the probe side is
+/// done with it. The build sink grows the reservation and hands it over to the probe operator
+/// it finalizes into.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryReservation {
+    manager: Option<Arc<MemoryManager>>,
+    bytes: usize,
+}
+
+impl MemoryReservation {
+    /// Reserve `bytes` more if that fits in the budget of `manager`.
+    pub(crate) fn try_grow(
+        &mut self,
+        manager: &Arc<MemoryManager>,
+        bytes: usize,
+    ) -> PolarsResult<()> {
+        manager.try_reserve(bytes)?;
+        self.manager = Some(manager.clone());
+        self.bytes += bytes;
+        Ok(())
+    }
+
+    /// Take over the bytes reserved by `other`.
+    pub(crate) fn merge(&mut self, mut other: MemoryReservation) {
+        if self.manager.is_none() {
+            self.manager = other.manager.take();
+        }
+        self.bytes += std::mem::take(&mut other.bytes);
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        if let Some(manager) = &self.manager {
+            manager.release(self.bytes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The bytes of a build side stay reserved until the last owner of the reservation is gone.
+    #[test]
+    fn reservation_released_on_drop() {
+        let manager = Arc::new(MemoryManager::new(Some(10)));
+        let mut a = MemoryReservation::default();
+        a.try_grow(&manager, 6).unwrap();
+        let mut b = MemoryReservation::default();
+        assert!(b.try_grow(&manager, 6).is_err());
+        b.try_grow(&manager, 4).unwrap();
+
+        a.merge(b);
+        assert_eq!(manager.reserved(), 10);
+        drop(a);
+        assert_eq!(manager.reserved(), 0);
+        assert_eq!(manager.peak(), 10);
+    }
+}
@@ -1,13 +1,19 @@
 mod config;
 mod convert;
 mod dispatcher;
+mod memory;
+mod progress;
 
 pub use convert::{create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order};
 pub use dispatcher::PipeLine;
+pub use memory::MemoryManager;
+pub(crate) use memory::MemoryReservation;
 use polars_core::prelude::*;
 use polars_core::POOL;
+pub use progress::{NodeProgress, ProgressCallback, ProgressTracker, StreamingProgress};
 
 pub use crate::executors::sinks::group_by::aggregates::can_convert_to_hash_agg;
+pub use crate::executors::sinks::sort::top_k::is_bounded_top_k;
 
 pub(crate) fn morsels_per_sink() -> usize {
     POOL.current_num_threads()
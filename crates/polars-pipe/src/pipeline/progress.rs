@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+/// Number of rows that passed through a source or sink.
+#[derive(Clone, Debug)]
+pub struct NodeProgress {
+    pub name: String,
+    pub rows: usize,
+}
+
+/// Snapshot of the progress of a streaming query.
+///
+/// The counters are cumulative over all pipelines of the query. Sinks that turn into a
+/// source for a next pipeline (e.g. a sort) show up as a new source.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingProgress {
+    /// Rows read per source, in the order the sources started.
+    pub rows_read: Vec<NodeProgress>,
+    /// Rows pushed into each sink, in the order the sinks started.
+    pub rows_emitted: Vec<NodeProgress>,
+    /// Bytes written to disk by spilling sinks.
+    pub bytes_spilled: usize,
+}
+
+pub type ProgressCallback = Arc<dyn Fn(&StreamingProgress) + Send + Sync>;
+
+/// Keeps the progress of a streaming query and reports it to a callback.
+///
+/// The callback is called from the thread that drives the pipeline, after every batch that
+/// was pushed through it and once when a pipeline finishes.
+pub struct ProgressTracker {
+    callback: ProgressCallback,
+    progress: Mutex<StreamingProgress>,
+}
+
+impl ProgressTracker {
+    pub fn new(callback: ProgressCallback) -> Self {
+        Self {
+            callback,
+            progress: Default::default(),
+        }
+    }
+
+    /// Get a snapshot of the current progress.
+    pub fn progress(&self) -> StreamingProgress {
+        self.progress.lock().unwrap().clone()
+    }
+
+    pub(crate) fn register_source(&self, name: &str) -> usize {
+        let mut progress = self.progress.lock().unwrap();
+        progress.rows_read.push(NodeProgress {
+            name: name.to_string(),
+            rows: 0,
+        });
+        progress.rows_read.len() - 1
+    }
+
+    pub(crate) fn register_sink(&self, name: &str) -> usize {
+        let mut progress = self.progress.lock().unwrap();
+        progress.rows_emitted.push(NodeProgress {
+            name: name.to_string(),
+            rows: 0,
+        });
+        progress.rows_emitted.len() - 1
+    }
+
+    pub(crate) fn add_rows_read(&self, source: usize, rows: usize) {
+        self.progress.lock().unwrap().rows_read[source].rows += rows;
+    }
+
+    pub(crate) fn add_rows_emitted(&self, sink: usize, rows: usize) {
+        self.progress.lock().unwrap().rows_emitted[sink].rows += rows;
+    }
+
+    pub(crate) fn report(&self, bytes_spilled: usize) {
+        let progress = {
+            let mut progress = self.progress.lock().unwrap();
+            progress.bytes_spilled = bytes_spilled;
+            progress.clone()
+        };
+        (self.callback)(&progress)
+    }
+}
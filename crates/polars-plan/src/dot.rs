@@ -63,6 +63,17 @@ impl Expr {
     }
 }
 
+/// Extra text for the labels of the plan nodes, in pre-order.
+type Annotations<'a> = std::slice::Iter<'a, Option<String>>;
+
+/// Add the next annotation to the label of a node.
+fn annotate<'a>(fmt: &'a str, annotations: &mut Annotations) -> Cow<'a, str> {
+    match annotations.next() {
+        Some(Some(annotation)) => Cow::Owned(format!("{fmt}\n{annotation}")),
+        _ => Cow::Borrowed(fmt),
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct DotNode<'a> {
     pub branch: usize,
@@ -71,8 +82,59 @@ pub struct DotNode<'a> {
 }
 
 impl LogicalPlan {
-    fn write_single_node(&self, acc_str: &mut String, node: DotNode) -> std::fmt::Result {
-        let fmt_node = node.fmt.replace('"', r#"\""#);
+    /// Get a dot language representation of the LogicalPlan.
+    pub fn to_dot(&self) -> PolarsResult<String> {
+        self.to_dot_annotated(&[])
+    }
+
+    /// Get a dot language representation with extra text for the nodes, given in pre-order.
+    pub fn to_dot_annotated(&self, annotations: &[Option<String>]) -> PolarsResult<String> {
+        let mut s = String::with_capacity(512);
+
+        let prev_node = DotNode {
+            branch: 0,
+            id: 0,
+            fmt: "",
+        };
+
+        // maps graphviz id to label
+        // we use this to create this graph
+        // first we create nodes including ids to make sure they are unique
+        // A [id] -- B [id]
+        // B [id] -- C [id]
+        //
+        // then later we hide the [id] by adding this to the graph
+        // A [id] [label="A"]
+        // B [id] [label="B"]
+        // C [id] [label="C"]
+
+        let mut id_map = PlHashMap::with_capacity(8);
+        self.dot_impl(
+            &mut s,
+            (0, 0),
+            prev_node,
+            &mut id_map,
+            &mut annotations.iter(),
+        )
+        .expect("io error");
+        s.push('\n');
+
+        for (id, label) in id_map {
+            // the label is wrapped in double quotes
+            // the id already is wrapped in double quotes
+            writeln!(s, "{id}[label=\"{label}\"]").unwrap();
+        }
+        s.push_str("\n}");
+        Ok(s)
+    }
+
+    fn write_single_node(
+        &self,
+        acc_str: &mut String,
+        node: DotNode,
+        annotations: &mut Annotations,
+    ) -> std::fmt::Result {
+        let fmt_node = annotate(node.fmt, annotations).replace('"', r#"\""#);
         writeln!(acc_str, "graph  polars_query {{\n\"[{fmt_node}]\"")?;
         Ok(())
     }
@@ -83,8 +145,18 @@ impl LogicalPlan {
         prev_node: DotNode,
         current_node: DotNode,
         id_map: &mut PlHashMap<String, String>,
+        annotations: &mut Annotations,
     ) -> std::fmt::Result {
+        // Only the labels are annotated, the ids must be the same when the node is an input.
+        let label = annotate(current_node.fmt, annotations).replace('"', r#"\""#);
         if current_node.id == 0 && current_node.branch == 0 {
+            let fmt_current_node = current_node.fmt.replace('"', r#"\""#);
+            let id_current_node = format!(
+                "\"{} [{:?}]\"",
+                &fmt_current_node,
+                (current_node.branch, current_node.id)
+            );
+            id_map.insert(id_current_node, label);
             writeln!(acc_str, "graph  polars_query {{")
         } else {
             let fmt_prev_node = prev_node.fmt.replace('"', r#"\""#);
@@ -103,8 +175,8 @@ impl LogicalPlan {
 
             writeln!(acc_str, "{} -- {}", &id_prev_node, &id_current_node)?;
 
-            id_map.insert(id_current_node, fmt_current_node);
-            id_map.insert(id_prev_node, fmt_prev_node);
+            id_map.insert(id_current_node, label);
+            id_map.entry(id_prev_node).or_insert(fmt_prev_node);
 
             Ok(())
         }
@@ -126,6 +198,17 @@ impl LogicalPlan {
         id: (usize, usize),
         prev_node: DotNode,
         id_map: &mut PlHashMap<String, String>,
+    ) -> std::fmt::Result {
+        self.dot_impl(acc_str, id, prev_node, id_map, &mut [].iter())
+    }
+
+    fn dot_impl(
+        &self,
+        acc_str: &mut String,
+        id: (usize, usize),
+        prev_node: DotNode,
+        id_map: &mut PlHashMap<String, String>,
+        annotations: &mut Annotations,
     ) -> std::fmt::Result {
         use LogicalPlan::*;
         let (mut branch, id) = id;
@@ -137,9 +220,9 @@ impl LogicalPlan {
                     id,
                     fmt: "UNION",
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
                 for input in inputs {
-                    input.dot(acc_str, (branch, id + 1), current_node, id_map)?;
+                    input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)?;
                     branch += 1;
                 }
                 Ok(())
@@ -150,9 +233,9 @@ impl LogicalPlan {
                     id,
                     fmt: "HCONCAT",
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
                 for input in inputs {
-                    input.dot(acc_str, (branch, id + 1), current_node, id_map)?;
+                    input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)?;
                     branch += 1;
                 }
                 Ok(())
@@ -173,8 +256,14 @@ impl LogicalPlan {
                     fmt: &fmt,
                 };
                 // here we take the cache id, to ensure the same cached subplans get the same ids
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (*cache_id, cache_id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(
+                    acc_str,
+                    (*cache_id, cache_id + 1),
+                    current_node,
+                    id_map,
+                    annotations,
+                )
             },
             Selection { predicate, input } => {
                 let pred = fmt_predicate(Some(predicate));
@@ -186,8 +275,8 @@ impl LogicalPlan {
                     fmt: &fmt,
                 };
 
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             #[cfg(feature = "python")]
             PythonScan { options } => self.write_scan(
@@ -201,6 +290,7 @@ impl LogicalPlan {
                 branch,
                 id,
                 id_map,
+                annotations,
             ),
             Projection { expr, input, .. } => {
                 let schema = input.schema().map_err(|_| {
@@ -215,8 +305,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Sort {
                 input, by_column, ..
@@ -227,8 +317,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Aggregate {
                 input, keys, aggs, ..
@@ -246,8 +336,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             HStack { input, exprs, .. } => {
                 let mut fmt = String::with_capacity(128);
@@ -268,8 +358,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Slice { input, offset, len } => {
                 let fmt = format!("SLICE offset: {offset}; len: {len}");
@@ -278,8 +368,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Distinct { input, options, .. } => {
                 let mut fmt = String::with_capacity(128);
@@ -296,8 +386,8 @@ impl LogicalPlan {
                     fmt: &fmt,
                 };
 
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             DataFrameScan {
                 schema,
@@ -319,9 +409,9 @@ impl LogicalPlan {
                     fmt: &fmt,
                 };
                 if self.is_single(branch, id) {
-                    self.write_single_node(acc_str, current_node)
+                    self.write_single_node(acc_str, current_node, annotations)
                 } else {
-                    self.write_dot(acc_str, prev_node, current_node, id_map)
+                    self.write_dot(acc_str, prev_node, current_node, id_map, annotations)
                 }
             },
             Scan {
@@ -344,6 +434,7 @@ impl LogicalPlan {
                     branch,
                     id,
                     id_map,
+                    annotations,
                 )
             },
             Join {
@@ -365,9 +456,21 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input_left.dot(acc_str, (branch + 100, id + 1), current_node, id_map)?;
-                input_right.dot(acc_str, (branch + 200, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input_left.dot_impl(
+                    acc_str,
+                    (branch + 100, id + 1),
+                    current_node,
+                    id_map,
+                    annotations,
+                )?;
+                input_right.dot_impl(
+                    acc_str,
+                    (branch + 200, id + 1),
+                    current_node,
+                    id_map,
+                    annotations,
+                )
             },
             MapFunction {
                 input, function, ..
@@ -378,8 +481,8 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             ExtContext { input, .. } => {
                 let current_node = DotNode {
@@ -387,8 +490,8 @@ impl LogicalPlan {
                     id,
                     fmt: "EXTERNAL_CONTEXT",
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Sink { input, payload, .. } => {
                 let current_node = DotNode {
@@ -401,8 +504,8 @@ impl LogicalPlan {
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
                     },
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)?;
-                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)?;
+                input.dot_impl(acc_str, (branch, id + 1), current_node, id_map, annotations)
             },
             Error { err, .. } => {
                 let fmt = format!("{:?}", &err.0);
@@ -411,7 +514,7 @@ impl LogicalPlan {
                     id,
                     fmt: &fmt,
                 };
-                self.write_dot(acc_str, prev_node, current_node, id_map)
+                self.write_dot(acc_str, prev_node, current_node, id_map, annotations)
             },
         }
     }
@@ -429,6 +532,7 @@ impl LogicalPlan {
         branch: usize,
         id: usize,
         id_map: &mut PlHashMap<String, String>,
+        annotations: &mut Annotations,
     ) -> std::fmt::Result {
         let mut n_columns_fmt = "*".to_string();
         if let Some(columns) = with_columns {
@@ -456,9 +560,9 @@ impl LogicalPlan {
             fmt: &fmt,
         };
         if self.is_single(branch, id) {
-            self.write_single_node(acc_str, current_node)
+            self.write_single_node(acc_str, current_node, annotations)
         } else {
-            self.write_dot(acc_str, prev_node, current_node, id_map)
+            self.write_dot(acc_str, prev_node, current_node, id_map, annotations)
         }
     }
 }
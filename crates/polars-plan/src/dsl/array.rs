@@ -1,4 +1,8 @@
 use polars_core::prelude::*;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
+#[cfg(feature = "array_distance")]
+use polars_ops::chunked_array::array::VectorMetric;
 #[cfg(feature = "array_to_struct")]
 use polars_ops::chunked_array::array::{
     arr_default_struct_name_gen, ArrToStructNameGenerator, ToStruct,
@@ -187,4 +191,198 @@ impl ArrayNameSpace {
             false,
         )
     }
+
+    /// Compute the mean of the items in every sub-array.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
+    }
+
+    /// Count the number of unique values in every sub-array.
+    pub fn n_unique(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::NUnique))
+    }
+
+    /// Compute the `n`-th discrete difference within every sub-array.
+    #[cfg(feature = "diff")]
+    pub fn diff(self, n: i64, null_behavior: NullBehavior) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Diff(
+                n,
+                null_behavior,
+            )))
+    }
+
+    /// Slice every sub-array. The width of the result is the same for all rows.
+    pub fn slice(self, offset: i64, length: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Slice(
+                offset, length,
+            )))
+    }
+
+    /// Get the first `n` items of every sub-array.
+    pub fn head(self, n: usize) -> Expr {
+        self.slice(0, n)
+    }
+
+    /// Get the last `n` items of every sub-array.
+    pub fn tail(self, n: usize) -> Expr {
+        self.slice(-(n as i64), n)
+    }
+
+    /// Gather the items at `index` of every sub-array into a list. The indices are either a list
+    /// per row or integers that are used for all rows.
+    #[cfg(feature = "list_gather")]
+    pub fn gather(self, index: Expr, null_on_oob: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Gather(null_on_oob)),
+            &[index],
+            false,
+            false,
+        )
+    }
+
+    /// Take every `n`-th item of every sub-array, starting at `offset`.
+    pub fn gather_every(self, n: usize, offset: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::GatherEvery(
+                n, offset,
+            )))
+    }
+
+    /// Drop the null items of every sub-array, returning a list.
+    #[cfg(feature = "list_drop_nulls")]
+    pub fn drop_nulls(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::DropNulls))
+    }
+
+    /// Sample `n` items of every sub-array.
+    #[cfg(feature = "list_sample")]
+    pub fn sample_n(
+        self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::SampleN {
+                n,
+                with_replacement,
+                shuffle,
+                seed,
+            }))
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+        self.0
+            .map_many_private(
+                FunctionExpr::ArrayExpr(ArrayFunction::SetOperation(set_operation)),
+                &[other],
+                false,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
+    /// Return the SET UNION between both arrays as a list.
+    #[cfg(feature = "list_sets")]
+    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Union)
+    }
+
+    /// Return the SET DIFFERENCE between both arrays as a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Difference)
+    }
+
+    /// Return the SET INTERSECTION between both arrays as a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Intersection)
+    }
+
+    /// Return the SET SYMMETRIC DIFFERENCE between both arrays as a list.
+    #[cfg(feature = "list_sets")]
+    pub fn set_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::SymmetricDifference)
+    }
+
+    /// Concatenate every sub-array with the sub-arrays of `other`.
+    pub fn concat<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, other: E) -> Expr {
+        let other: Vec<_> = other.as_ref().iter().map(|e| e.clone().into()).collect();
+        self.0
+            .map_many_private(
+                FunctionExpr::ArrayExpr(ArrayFunction::Concat),
+                &other,
+                false,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
+    #[cfg(feature = "array_distance")]
+    fn vector_function(self, function: ArrayFunction, other: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::ArrayExpr(function), &[other], false, false)
+    }
+
+    /// Compute the dot product with the sub-arrays of `other`. A literal vector is compared with
+    /// every sub-array.
+    #[cfg(feature = "array_distance")]
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.vector_function(ArrayFunction::Dot, other.into())
+    }
+
+    /// Compute the cosine similarity with the sub-arrays of `other`. A literal vector is
+    /// compared with every sub-array.
+    #[cfg(feature = "array_distance")]
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.vector_function(ArrayFunction::CosineSimilarity, other.into())
+    }
+
+    /// Compute the Euclidean distance to the sub-arrays of `other`. A literal vector is
+    /// compared with every sub-array.
+    #[cfg(feature = "array_distance")]
+    pub fn l2_distance<E: Into<Expr>>(self, other: E) -> Expr {
+        self.vector_function(ArrayFunction::L2Distance, other.into())
+    }
+
+    /// Scale every sub-array to unit length.
+    #[cfg(feature = "array_distance")]
+    pub fn normalize(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Normalize))
+    }
+
+    /// Find the `k` nearest sub-arrays to every vector in `queries` by an exact search. Returns a
+    /// struct per query with the row `index` of the neighbours and their `distance`, ordered from
+    /// nearest to farthest.
+    #[cfg(feature = "array_distance")]
+    pub fn nearest_neighbours<E: Into<Expr>>(
+        self,
+        queries: E,
+        k: usize,
+        metric: VectorMetric,
+    ) -> Expr {
+        self.vector_function(
+            ArrayFunction::NearestNeighbours { k, metric },
+            queries.into(),
+        )
+        .with_function_options(|mut options| {
+            options.changes_length = true;
+            options
+        })
+    }
 }
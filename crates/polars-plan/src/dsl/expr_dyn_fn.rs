@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use serde::{Deserializer, Serializer};
 
 use super::*;
+#[cfg(feature = "serde")]
+use crate::logical_plan::serialize::{read_registered, write_registered};
 
 /// A wrapper trait for any closure `Fn(Vec<Series>) -> PolarsResult<Series>`
 pub trait SeriesUdf: Send + Sync {
@@ -21,6 +23,14 @@ pub trait SeriesUdf: Send + Sync {
         polars_bail!(ComputeError: "serialize not supported for this 'opaque' function")
     }
 
+    /// Name under which the function is registered with `register_series_udf`.
+    ///
+    /// Functions with a name are serialized as that name and the output of
+    /// [`SeriesUdf::try_serialize`], and restored by the registered deserializer.
+    fn registry_name(&self) -> Option<&str> {
+        None
+    }
+
     // Needed for python functions. After they are deserialized we first check if they
     // have a function that generates an output
     // This will be slower during optimization, so it is up to us to move
@@ -38,24 +48,31 @@ impl Serialize for SpecialEq<Arc<dyn SeriesUdf>> {
     {
         use serde::ser::Error;
         let mut buf = vec![];
-        self.0
-            .try_serialize(&mut buf)
-            .map_err(|e| S::Error::custom(format!("{e}")))?;
+        match self.0.registry_name() {
+            Some(name) => write_registered(name, &mut buf, |buf| self.0.try_serialize(buf)),
+            None => self.0.try_serialize(&mut buf),
+        }
+        .map_err(|e| S::Error::custom(format!("{e}")))?;
         serializer.serialize_bytes(&buf)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<'a> Deserialize<'a> for SpecialEq<Arc<dyn SeriesUdf>> {
-    fn deserialize<D>(_deserializer: D) -> std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'a>,
     {
         use serde::de::Error;
+        let buf = Vec::<u8>::deserialize(deserializer)?;
+        if let Some(udf) = read_registered::<dyn SeriesUdf>(&buf) {
+            return udf
+                .map(SpecialEq::new)
+                .map_err(|e| D::Error::custom(format!("{e}")));
+        }
         #[cfg(feature = "python")]
         {
             use crate::dsl::python_udf::MAGIC_BYTE_MARK;
-            let buf = Vec::<u8>::deserialize(_deserializer)?;
 
             if buf.starts_with(MAGIC_BYTE_MARK) {
                 let udf = python_udf::PythonUdfExpression::try_deserialize(&buf)
@@ -1,5 +1,10 @@
+use polars_core::utils::slice_offsets;
 use polars_ops::chunked_array::array::*;
+#[cfg(feature = "list_sets")]
+use polars_ops::chunked_array::list::SetOperation;
 
+#[cfg(feature = "diff")]
+use super::schema::diff_dtype;
 use super::*;
 use crate::{map, map_as_slice};
 
@@ -29,6 +34,39 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    Mean,
+    NUnique,
+    #[cfg(feature = "diff")]
+    Diff(i64, NullBehavior),
+    Slice(i64, usize),
+    #[cfg(feature = "list_gather")]
+    Gather(bool),
+    GatherEvery(usize, usize),
+    #[cfg(feature = "list_drop_nulls")]
+    DropNulls,
+    #[cfg(feature = "list_sample")]
+    SampleN {
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    },
+    Concat,
+    #[cfg(feature = "list_sets")]
+    SetOperation(SetOperation),
+    #[cfg(feature = "array_distance")]
+    Dot,
+    #[cfg(feature = "array_distance")]
+    CosineSimilarity,
+    #[cfg(feature = "array_distance")]
+    L2Distance,
+    #[cfg(feature = "array_distance")]
+    Normalize,
+    #[cfg(feature = "array_distance")]
+    NearestNeighbours {
+        k: usize,
+        metric: VectorMetric,
+    },
 }
 
 impl ArrayFunction {
@@ -54,10 +92,96 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            Mean => mapper.try_map_dtype(|dt| match array_inner_dtype(dt)? {
+                DataType::Float32 => Ok(DataType::Float32),
+                _ => Ok(DataType::Float64),
+            }),
+            NUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "diff")]
+            Diff(n, null_behavior) => mapper.try_map_dtype(|dt| {
+                let (inner, width) = array_inner_dtype_and_width(dt)?;
+                let width = match null_behavior {
+                    NullBehavior::Ignore => width,
+                    NullBehavior::Drop => width.saturating_sub(n.unsigned_abs() as usize),
+                };
+                Ok(DataType::Array(Box::new(diff_dtype(inner)), width))
+            }),
+            Slice(offset, length) => mapper.try_map_dtype(|dt| {
+                let (inner, width) = array_inner_dtype_and_width(dt)?;
+                let (_, width) = slice_offsets(*offset, *length, width);
+                Ok(DataType::Array(Box::new(inner.clone()), width))
+            }),
+            #[cfg(feature = "list_gather")]
+            Gather(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
+            GatherEvery(n, offset) => mapper.try_map_dtype(|dt| {
+                let (inner, width) = array_inner_dtype_and_width(dt)?;
+                let width = width.saturating_sub(*offset).div_ceil((*n).max(1));
+                Ok(DataType::Array(Box::new(inner.clone()), width))
+            }),
+            #[cfg(feature = "list_drop_nulls")]
+            DropNulls => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
+            #[cfg(feature = "list_sample")]
+            SampleN { n, .. } => mapper.try_map_dtype(|dt| {
+                let (inner, _) = array_inner_dtype_and_width(dt)?;
+                Ok(DataType::Array(Box::new(inner.clone()), *n))
+            }),
+            Concat => mapper.try_map_dtypes(|dtypes| {
+                let mut inner = array_inner_dtype(dtypes[0])?.clone();
+                let mut width = 0;
+                for dt in dtypes {
+                    let (dt_inner, dt_width) = array_inner_dtype_and_width(dt)?;
+                    inner = try_get_supertype(&inner, dt_inner)?;
+                    width += dt_width;
+                }
+                Ok(DataType::Array(Box::new(inner), width))
+            }),
+            #[cfg(feature = "list_sets")]
+            SetOperation(_) => mapper.try_map_dtype(map_array_dtype_to_list_dtype),
+            #[cfg(feature = "array_distance")]
+            Dot | CosineSimilarity | L2Distance => mapper.try_map_dtypes(vector_float_dtype),
+            #[cfg(feature = "array_distance")]
+            Normalize => mapper.try_map_dtype(|dt| {
+                let (_, width) = array_inner_dtype_and_width(dt)?;
+                Ok(DataType::Array(Box::new(vector_float_dtype(&[dt])?), width))
+            }),
+            #[cfg(feature = "array_distance")]
+            NearestNeighbours { .. } => mapper.try_map_dtypes(|dtypes| {
+                let float_dtype = vector_float_dtype(dtypes)?;
+                Ok(DataType::Struct(vec![
+                    Field::new("index", DataType::List(Box::new(IDX_DTYPE))),
+                    Field::new("distance", DataType::List(Box::new(float_dtype))),
+                ]))
+            }),
         }
     }
 }
 
+fn array_inner_dtype_and_width(datatype: &DataType) -> PolarsResult<(&DataType, usize)> {
+    if let DataType::Array(inner, width) = datatype {
+        Ok((inner.as_ref(), *width))
+    } else {
+        polars_bail!(ComputeError: "expected array dtype")
+    }
+}
+
+fn array_inner_dtype(datatype: &DataType) -> PolarsResult<&DataType> {
+    array_inner_dtype_and_width(datatype).map(|(inner, _)| inner)
+}
+
+/// The float type that vector kernels compute in: `Float32` if all vectors are `Float32`.
+#[cfg(feature = "array_distance")]
+fn vector_float_dtype(dtypes: &[&DataType]) -> PolarsResult<DataType> {
+    let mut all_f32 = true;
+    for dt in dtypes {
+        all_f32 &= array_inner_dtype(dt)? == &DataType::Float32;
+    }
+    Ok(if all_f32 {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    })
+}
+
 fn map_array_dtype_to_list_dtype(datatype: &DataType) -> PolarsResult<DataType> {
     if let DataType::Array(inner, _) = datatype {
         Ok(DataType::List(inner.clone()))
@@ -93,6 +217,31 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            Mean => "mean",
+            NUnique => "n_unique",
+            #[cfg(feature = "diff")]
+            Diff(_, _) => "diff",
+            Slice(_, _) => "slice",
+            #[cfg(feature = "list_gather")]
+            Gather(_) => "gather",
+            GatherEvery(_, _) => "gather_every",
+            #[cfg(feature = "list_drop_nulls")]
+            DropNulls => "drop_nulls",
+            #[cfg(feature = "list_sample")]
+            SampleN { .. } => "sample_n",
+            Concat => "concat",
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => return write!(f, "arr.{s}"),
+            #[cfg(feature = "array_distance")]
+            Dot => "dot",
+            #[cfg(feature = "array_distance")]
+            CosineSimilarity => "cosine_similarity",
+            #[cfg(feature = "array_distance")]
+            L2Distance => "l2_distance",
+            #[cfg(feature = "array_distance")]
+            Normalize => "normalize",
+            #[cfg(feature = "array_distance")]
+            NearestNeighbours { .. } => "nearest_neighbours",
         };
         write!(f, "arr.{name}")
     }
@@ -125,6 +274,36 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            Mean => map!(mean),
+            NUnique => map!(n_unique),
+            #[cfg(feature = "diff")]
+            Diff(n, null_behavior) => map!(diff, n, null_behavior),
+            Slice(offset, length) => map!(slice, offset, length),
+            #[cfg(feature = "list_gather")]
+            Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
+            GatherEvery(n, offset) => map!(gather_every, n, offset),
+            #[cfg(feature = "list_drop_nulls")]
+            DropNulls => map!(drop_nulls),
+            #[cfg(feature = "list_sample")]
+            SampleN {
+                n,
+                with_replacement,
+                shuffle,
+                seed,
+            } => map!(sample_n, n, with_replacement, shuffle, seed),
+            Concat => map_as_slice!(concat),
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => map_as_slice!(set_operation, s),
+            #[cfg(feature = "array_distance")]
+            Dot => map_as_slice!(dot),
+            #[cfg(feature = "array_distance")]
+            CosineSimilarity => map_as_slice!(cosine_similarity),
+            #[cfg(feature = "array_distance")]
+            L2Distance => map_as_slice!(l2_distance),
+            #[cfg(feature = "array_distance")]
+            Normalize => map!(normalize),
+            #[cfg(feature = "array_distance")]
+            NearestNeighbours { k, metric } => map_as_slice!(nearest_neighbours, k, metric),
         }
     }
 }
@@ -235,3 +414,92 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
+pub(super) fn n_unique(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_n_unique()?.into_series())
+}
+
+#[cfg(feature = "diff")]
+pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+    Ok(s.array()?.array_diff(n, null_behavior)?.into_series())
+}
+
+pub(super) fn slice(s: &Series, offset: i64, length: usize) -> PolarsResult<Series> {
+    Ok(s.array()?.array_slice(offset, length)?.into_series())
+}
+
+#[cfg(feature = "list_gather")]
+pub(super) fn gather(s: &[Series], null_on_oob: bool) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    ca.array_gather(&s[1], null_on_oob)
+}
+
+pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
+    Ok(s.array()?.array_gather_every(n, offset)?.into_series())
+}
+
+#[cfg(feature = "list_drop_nulls")]
+pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_drop_nulls()?.into_series())
+}
+
+#[cfg(feature = "list_sample")]
+pub(super) fn sample_n(
+    s: &Series,
+    n: usize,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let ca = s.array()?;
+    Ok(ca
+        .array_sample_n(n, with_replacement, shuffle, seed)?
+        .into_series())
+}
+
+pub(super) fn concat(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    Ok(ca.array_concat(&s[1..])?.into_series())
+}
+
+#[cfg(feature = "list_sets")]
+pub(super) fn set_operation(s: &[Series], set_op: SetOperation) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    Ok(ca.array_set_operation(&s[1], set_op)?.into_series())
+}
+
+#[cfg(feature = "array_distance")]
+pub(super) fn dot(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_dot(s[1].array()?)
+}
+
+#[cfg(feature = "array_distance")]
+pub(super) fn cosine_similarity(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_cosine_similarity(s[1].array()?)
+}
+
+#[cfg(feature = "array_distance")]
+pub(super) fn l2_distance(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_l2_distance(s[1].array()?)
+}
+
+#[cfg(feature = "array_distance")]
+pub(super) fn normalize(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_normalize()?.into_series())
+}
+
+#[cfg(feature = "array_distance")]
+pub(super) fn nearest_neighbours(
+    s: &[Series],
+    k: usize,
+    metric: VectorMetric,
+) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    Ok(ca
+        .array_nearest_neighbours(s[1].array()?, k, metric)?
+        .into_series())
+}
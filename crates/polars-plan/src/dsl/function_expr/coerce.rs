@@ -3,3 +3,8 @@ use polars_core::prelude::*;
 pub fn as_struct(s: &[Series]) -> PolarsResult<Series> {
     Ok(StructChunked::new(s[0].name(), s)?.into_series())
 }
+
+#[cfg(feature = "dtype-union")]
+pub fn as_union(s: &[Series]) -> PolarsResult<Series> {
+    Ok(UnionChunked::from_variants(s[0].name(), s)?.into_series())
+}
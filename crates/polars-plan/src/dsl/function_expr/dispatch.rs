@@ -1,3 +1,5 @@
+use polars_core::frame::NullStrategy;
+
 use super::*;
 
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
@@ -85,8 +87,52 @@ pub(super) fn sum_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::sum_horizontal(s)
 }
 
-pub(super) fn mean_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
-    polars_ops::prelude::mean_horizontal(s)
+fn null_strategy(ignore_nulls: bool) -> NullStrategy {
+    if ignore_nulls {
+        NullStrategy::Ignore
+    } else {
+        NullStrategy::Propagate
+    }
+}
+
+pub(super) fn mean_horizontal(
+    s: &mut [Series],
+    ignore_nulls: bool,
+) -> PolarsResult<Option<Series>> {
+    if ignore_nulls {
+        polars_ops::prelude::mean_horizontal(s)
+    } else {
+        polars_ops::prelude::mean_horizontal_propagate_nulls(s)
+    }
+}
+
+pub(super) fn var_horizontal(s: &[Series], ddof: u8, ignore_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::var_horizontal(s, ddof, null_strategy(ignore_nulls))
+}
+
+pub(super) fn std_horizontal(s: &[Series], ddof: u8, ignore_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::std_horizontal(s, ddof, null_strategy(ignore_nulls))
+}
+
+pub(super) fn median_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::median_horizontal(s, null_strategy(ignore_nulls))
+}
+
+pub(super) fn quantile_horizontal(
+    s: &[Series],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+    ignore_nulls: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::quantile_horizontal(s, quantile, interpol, null_strategy(ignore_nulls))
+}
+
+pub(super) fn arg_max_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::arg_max_horizontal(s, null_strategy(ignore_nulls))
+}
+
+pub(super) fn arg_min_horizontal(s: &[Series], ignore_nulls: bool) -> PolarsResult<Series> {
+    polars_ops::prelude::arg_min_horizontal(s, null_strategy(ignore_nulls))
 }
 
 pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
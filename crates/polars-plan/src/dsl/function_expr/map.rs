@@ -0,0 +1,106 @@
+use polars_ops::chunked_array::map::*;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    ToStruct(Arc<Vec<String>>),
+    FromStruct,
+}
+
+impl MapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use MapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| Ok(map_key_value(dt)?.1.clone())),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            Keys => mapper
+                .try_map_dtype(|dt| Ok(DataType::List(Box::new(map_key_value(dt)?.0.clone())))),
+            Values => mapper
+                .try_map_dtype(|dt| Ok(DataType::List(Box::new(map_key_value(dt)?.1.clone())))),
+            ToStruct(keys) => mapper.try_map_dtype(|dt| {
+                let value = map_key_value(dt)?.1;
+                let fields = keys
+                    .iter()
+                    .map(|name| Field::new(name, value.clone()))
+                    .collect();
+                Ok(DataType::Struct(fields))
+            }),
+            FromStruct => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => {
+                    let value = fields.iter().try_fold(DataType::Null, |acc, fld| {
+                        try_get_supertype(&acc, fld.data_type())
+                    })?;
+                    Ok(DataType::Map(Box::new(DataType::String), Box::new(value)))
+                },
+                _ => polars_bail!(op = "to_map", got = dt, expected = "Struct"),
+            }),
+        }
+    }
+}
+
+fn map_key_value(dt: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dt {
+        DataType::Map(key, value) => Ok((key, value)),
+        _ => polars_bail!(InvalidOperation: "expected Map dtype, got: `{}`", dt),
+    }
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            ToStruct(_) => "to_struct",
+            FromStruct => return write!(f, "struct.to_map"),
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: MapFunction) -> Self {
+        use MapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            ContainsKey => map_as_slice!(contains_key),
+            Keys => map!(keys),
+            Values => map!(values),
+            ToStruct(keys) => map!(to_struct, &keys),
+            FromStruct => map!(from_struct),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Series]) -> PolarsResult<Series> {
+    s[0].map()?.map_get(&s[1])
+}
+
+pub(super) fn contains_key(s: &[Series]) -> PolarsResult<Series> {
+    Ok(s[0].map()?.map_contains_key(&s[1])?.into_series())
+}
+
+pub(super) fn keys(s: &Series) -> PolarsResult<Series> {
+    Ok(s.map()?.map_keys()?.into_series())
+}
+
+pub(super) fn values(s: &Series) -> PolarsResult<Series> {
+    Ok(s.map()?.map_values()?.into_series())
+}
+
+pub(super) fn to_struct(s: &Series, keys: &[String]) -> PolarsResult<Series> {
+    Ok(s.map()?.map_to_struct(keys)?.into_series())
+}
+
+pub(super) fn from_struct(s: &Series) -> PolarsResult<Series> {
+    Ok(struct_to_map(s.struct_()?)?.into_series())
+}
@@ -29,6 +29,8 @@ mod fused;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -60,6 +62,8 @@ mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
 mod unique;
 
 use std::fmt::{Display, Formatter};
@@ -85,6 +89,8 @@ pub use self::boolean::BooleanFunction;
 pub(crate) use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub(super) use self::datetime::TemporalFunction;
+#[cfg(feature = "dtype-map")]
+pub(super) use self::map::MapFunction;
 pub(super) use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
@@ -96,6 +102,8 @@ pub(crate) use self::strings::StringFunction;
 pub(super) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-union")]
+pub(super) use self::union::UnionFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -108,6 +116,10 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
+    #[cfg(feature = "dtype-union")]
+    UnionExpr(UnionFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -176,6 +188,8 @@ pub enum FunctionExpr {
     },
     #[cfg(feature = "dtype-struct")]
     AsStruct,
+    #[cfg(feature = "dtype-union")]
+    AsUnion,
     #[cfg(feature = "top_k")]
     TopK(bool),
     #[cfg(feature = "cum_agg")]
@@ -302,7 +316,33 @@ pub enum FunctionExpr {
     MaxHorizontal,
     MinHorizontal,
     SumHorizontal,
-    MeanHorizontal,
+    MeanHorizontal {
+        ignore_nulls: bool,
+    },
+    VarHorizontal {
+        ddof: u8,
+        ignore_nulls: bool,
+    },
+    StdHorizontal {
+        ddof: u8,
+        ignore_nulls: bool,
+    },
+    MedianHorizontal {
+        ignore_nulls: bool,
+    },
+    QuantileHorizontal {
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+        ignore_nulls: bool,
+    },
+    ArgMaxHorizontal {
+        ignore_nulls: bool,
+    },
+    ArgMinHorizontal {
+        ignore_nulls: bool,
+    },
+    NullCountHorizontal,
+    NUniqueHorizontal,
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -340,6 +380,10 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -376,8 +420,26 @@ impl Hash for FunctionExpr {
                 lib.hash(state);
                 symbol.hash(state);
             },
-            MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
-            | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            MaxHorizontal | MinHorizontal | SumHorizontal | NullCountHorizontal
+            | NUniqueHorizontal | DropNans | DropNulls | Reverse | ArgUnique | Shift
+            | ShiftAndFill => {},
+            MeanHorizontal { ignore_nulls }
+            | MedianHorizontal { ignore_nulls }
+            | ArgMaxHorizontal { ignore_nulls }
+            | ArgMinHorizontal { ignore_nulls } => ignore_nulls.hash(state),
+            VarHorizontal { ddof, ignore_nulls } | StdHorizontal { ddof, ignore_nulls } => {
+                ddof.hash(state);
+                ignore_nulls.hash(state);
+            },
+            QuantileHorizontal {
+                quantile,
+                interpol,
+                ignore_nulls,
+            } => {
+                quantile.to_bits().hash(state);
+                interpol.hash(state);
+                ignore_nulls.hash(state);
+            },
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -392,6 +454,8 @@ impl Hash for FunctionExpr {
             Atan2 => {},
             #[cfg(feature = "dtype-struct")]
             AsStruct => {},
+            #[cfg(feature = "dtype-union")]
+            AsUnion => {},
             #[cfg(feature = "sign")]
             Sign => {},
             #[cfg(feature = "row_hash")]
@@ -548,6 +612,10 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -602,6 +670,8 @@ impl Display for FunctionExpr {
             },
             #[cfg(feature = "dtype-struct")]
             AsStruct => "as_struct",
+            #[cfg(feature = "dtype-union")]
+            AsUnion => "as_union",
             #[cfg(feature = "top_k")]
             TopK(descending) => {
                 if *descending {
@@ -692,7 +762,15 @@ impl Display for FunctionExpr {
             MaxHorizontal => "max_horizontal",
             MinHorizontal => "min_horizontal",
             SumHorizontal => "sum_horizontal",
-            MeanHorizontal => "mean_horizontal",
+            MeanHorizontal { .. } => "mean_horizontal",
+            VarHorizontal { .. } => "var_horizontal",
+            StdHorizontal { .. } => "std_horizontal",
+            MedianHorizontal { .. } => "median_horizontal",
+            QuantileHorizontal { .. } => "quantile_horizontal",
+            ArgMaxHorizontal { .. } => "arg_max_horizontal",
+            ArgMinHorizontal { .. } => "arg_min_horizontal",
+            NullCountHorizontal => "null_count_horizontal",
+            NUniqueHorizontal => "n_unique_horizontal",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma")]
@@ -798,6 +876,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -917,6 +999,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             AsStruct => {
                 map_as_slice!(coerce::as_struct)
             },
+            #[cfg(feature = "dtype-union")]
+            AsUnion => {
+                map_as_slice!(coerce::as_union)
+            },
             #[cfg(feature = "top_k")]
             TopK(descending) => {
                 map_as_slice!(top_k, descending)
@@ -1050,7 +1136,36 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             MaxHorizontal => wrap!(dispatch::max_horizontal),
             MinHorizontal => wrap!(dispatch::min_horizontal),
             SumHorizontal => wrap!(dispatch::sum_horizontal),
-            MeanHorizontal => wrap!(dispatch::mean_horizontal),
+            MeanHorizontal { ignore_nulls } => {
+                wrap!(move |s: &mut [Series]| dispatch::mean_horizontal(s, ignore_nulls))
+            },
+            VarHorizontal { ddof, ignore_nulls } => {
+                map_as_slice!(dispatch::var_horizontal, ddof, ignore_nulls)
+            },
+            StdHorizontal { ddof, ignore_nulls } => {
+                map_as_slice!(dispatch::std_horizontal, ddof, ignore_nulls)
+            },
+            MedianHorizontal { ignore_nulls } => {
+                map_as_slice!(dispatch::median_horizontal, ignore_nulls)
+            },
+            QuantileHorizontal {
+                quantile,
+                interpol,
+                ignore_nulls,
+            } => map_as_slice!(
+                dispatch::quantile_horizontal,
+                quantile,
+                interpol,
+                ignore_nulls
+            ),
+            ArgMaxHorizontal { ignore_nulls } => {
+                map_as_slice!(dispatch::arg_max_horizontal, ignore_nulls)
+            },
+            ArgMinHorizontal { ignore_nulls } => {
+                map_as_slice!(dispatch::arg_min_horizontal, ignore_nulls)
+            },
+            NullCountHorizontal => map_as_slice!(polars_ops::prelude::null_count_horizontal),
+            NUniqueHorizontal => map_as_slice!(polars_ops::prelude::n_unique_horizontal),
             #[cfg(feature = "ewma")]
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma")]
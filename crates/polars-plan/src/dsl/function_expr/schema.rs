@@ -18,6 +18,10 @@ impl FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -88,6 +92,11 @@ impl FunctionExpr {
                 fields[0].name(),
                 DataType::Struct(fields.to_vec()),
             )),
+            #[cfg(feature = "dtype-union")]
+            AsUnion => Ok(Field::new(
+                fields[0].name(),
+                DataType::Union(fields.to_vec()),
+            )),
             #[cfg(feature = "top_k")]
             TopK(_) => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
@@ -136,18 +145,7 @@ impl FunctionExpr {
                 }
             },
             #[cfg(feature = "diff")]
-            Diff(_, _) => mapper.map_dtype(|dt| match dt {
-                #[cfg(feature = "dtype-datetime")]
-                DataType::Datetime(tu, _) => DataType::Duration(*tu),
-                #[cfg(feature = "dtype-date")]
-                DataType::Date => DataType::Duration(TimeUnit::Milliseconds),
-                #[cfg(feature = "dtype-time")]
-                DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
-                DataType::UInt64 | DataType::UInt32 => DataType::Int64,
-                DataType::UInt16 => DataType::Int32,
-                DataType::UInt8 => DataType::Int16,
-                dt => dt.clone(),
-            }),
+            Diff(_, _) => mapper.map_dtype(diff_dtype),
             #[cfg(feature = "pct_change")]
             PctChange => mapper.map_dtype(|dt| match dt {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
@@ -272,7 +270,15 @@ impl FunctionExpr {
             MaxHorizontal => mapper.map_to_supertype(),
             MinHorizontal => mapper.map_to_supertype(),
             SumHorizontal => mapper.map_to_supertype(),
-            MeanHorizontal => mapper.map_to_float_dtype(),
+            MeanHorizontal { .. } => mapper.map_to_float_dtype(),
+            VarHorizontal { .. }
+            | StdHorizontal { .. }
+            | MedianHorizontal { .. }
+            | QuantileHorizontal { .. } => mapper.with_dtype(DataType::Float64),
+            ArgMaxHorizontal { .. }
+            | ArgMinHorizontal { .. }
+            | NullCountHorizontal
+            | NUniqueHorizontal => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
@@ -494,3 +500,20 @@ impl<'a> FieldsMapper<'a> {
         self.with_dtype(dtype)
     }
 }
+
+/// The dtype of the differences between values of `dtype`.
+#[cfg(feature = "diff")]
+pub(super) fn diff_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, _) => DataType::Duration(*tu),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => DataType::Duration(TimeUnit::Milliseconds),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
+        DataType::UInt64 | DataType::UInt32 => DataType::Int64,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt8 => DataType::Int16,
+        dt => dt.clone(),
+    }
+}
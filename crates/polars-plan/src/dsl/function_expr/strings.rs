@@ -38,6 +38,8 @@ pub enum StringFunction {
         strict: bool,
     },
     CountMatches(bool),
+    #[cfg(feature = "string_similarity")]
+    Distance(StringDistance),
     EndsWith,
     Explode,
     Extract(usize),
@@ -132,6 +134,10 @@ impl StringFunction {
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            Distance(StringDistance::Levenshtein) => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            Distance(_) => mapper.with_dtype(DataType::Float64),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             Explode => mapper.with_same_dtype(),
             Extract(_) => mapper.with_same_dtype(),
@@ -196,6 +202,12 @@ impl Display for StringFunction {
             #[cfg(feature = "regex")]
             Contains { .. } => "contains",
             CountMatches(_) => "count_matches",
+            #[cfg(feature = "string_similarity")]
+            Distance(StringDistance::Levenshtein) => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            Distance(StringDistance::JaroWinkler) => "jaro_winkler_distance",
+            #[cfg(feature = "string_similarity")]
+            Distance(StringDistance::Jaccard { .. }) => "jaccard_distance",
             EndsWith { .. } => "ends_with",
             Extract(_) => "extract",
             #[cfg(feature = "concat_str")]
@@ -282,6 +294,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             CountMatches(literal) => {
                 map_as_slice!(strings::count_matches, literal)
             },
+            #[cfg(feature = "string_similarity")]
+            Distance(distance) => map_as_slice!(strings::distance, distance),
             EndsWith { .. } => map_as_slice!(strings::ends_with),
             StartsWith { .. } => map_as_slice!(strings::starts_with),
             Extract(group_index) => map_as_slice!(strings::extract, group_index),
@@ -441,6 +455,17 @@ pub(super) fn find(s: &[Series], literal: bool, strict: bool) -> PolarsResult<Se
         .map(|ok| ok.into_series())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn distance(s: &[Series], distance: StringDistance) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    match distance {
+        StringDistance::Levenshtein => ca.levenshtein_distance(other).map(|ok| ok.into_series()),
+        StringDistance::JaroWinkler => ca.jaro_winkler_distance(other).map(|ok| ok.into_series()),
+        StringDistance::Jaccard { n } => ca.jaccard_distance(other, n).map(|ok| ok.into_series()),
+    }
+}
+
 pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = &s[0].str()?.as_binary();
     let suffix = &s[1].str()?.as_binary();
@@ -0,0 +1,57 @@
+use super::*;
+use crate::map;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnionFunction {
+    Tag,
+    FieldByName(Arc<str>),
+}
+
+impl UnionFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use UnionFunction::*;
+        match self {
+            Tag => mapper.with_dtype(DataType::String),
+            FieldByName(name) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Union(variants) => variants
+                    .iter()
+                    .find(|fld| fld.name() == name.as_ref())
+                    .map(|fld| fld.data_type().clone())
+                    .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name.as_ref())),
+                _ => polars_bail!(op = "union.field", got = dt, expected = "Union"),
+            }),
+        }
+    }
+}
+
+impl Display for UnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UnionFunction::*;
+        let name = match self {
+            Tag => "tag",
+            FieldByName(_) => "field",
+        };
+        write!(f, "union.{name}")
+    }
+}
+
+impl From<UnionFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: UnionFunction) -> Self {
+        use UnionFunction::*;
+        match func {
+            Tag => map!(tag),
+            FieldByName(name) => map!(field, &name),
+        }
+    }
+}
+
+pub(super) fn tag(s: &Series) -> PolarsResult<Series> {
+    Ok(s.union()?.tag().into_series())
+}
+
+pub(super) fn field(s: &Series, name: &str) -> PolarsResult<Series> {
+    let mut out = s.union()?.variant(name)?;
+    out.rename(s.name());
+    Ok(out)
+}
@@ -13,3 +13,19 @@ pub fn as_struct(exprs: Vec<Expr>) -> Expr {
         },
     }
 }
+
+/// Take several expressions and collect them into a [`UnionChunked`]. Every row takes the
+/// value of the first expression that is not null in that row.
+#[cfg(feature = "dtype-union")]
+pub fn as_union(exprs: Vec<Expr>) -> Expr {
+    Expr::Function {
+        input: exprs,
+        function: FunctionExpr::AsUnion,
+        options: FunctionOptions {
+            input_wildcard_expansion: true,
+            pass_name_to_apply: true,
+            collect_groups: ApplyOptions::ElementWise,
+            ..Default::default()
+        },
+    }
+}
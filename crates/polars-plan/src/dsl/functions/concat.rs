@@ -69,6 +69,24 @@ pub fn concat_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult
     })
 }
 
+/// Concat array entries. The widths of the arrays add up.
+#[cfg(feature = "dtype-array")]
+pub fn concat_arr<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult<Expr> {
+    let s: Vec<_> = s.as_ref().iter().map(|e| e.clone().into()).collect();
+
+    polars_ensure!(!s.is_empty(), ComputeError: "`concat_arr` needs one or more expressions");
+
+    Ok(Expr::Function {
+        input: s,
+        function: FunctionExpr::ArrayExpr(ArrayFunction::Concat),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            input_wildcard_expansion: true,
+            ..Default::default()
+        },
+    })
+}
+
 pub fn concat_expr<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
     s: E,
     rechunk: bool,
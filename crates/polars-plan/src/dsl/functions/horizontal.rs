@@ -305,14 +305,28 @@ pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     })
 }
 
-/// Compute the mean of all values horizontally across columns.
+/// Compute the mean of all values horizontally across columns, ignoring nulls.
 pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    mean_horizontal_with_options(exprs, true)
+}
+
+/// Compute the mean of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn mean_horizontal_with_options<E: AsRef<[Expr]>>(
+    exprs: E,
+    ignore_nulls: bool,
+) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::MeanHorizontal { ignore_nulls })
+}
+
+fn horizontal_function<E: AsRef<[Expr]>>(exprs: E, function: FunctionExpr) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
 
     Ok(Expr::Function {
         input: exprs,
-        function: FunctionExpr::MeanHorizontal,
+        function,
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             input_wildcard_expansion: true,
@@ -323,6 +337,80 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     })
 }
 
+/// Compute the variance of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn var_horizontal<E: AsRef<[Expr]>>(
+    exprs: E,
+    ddof: u8,
+    ignore_nulls: bool,
+) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::VarHorizontal { ddof, ignore_nulls })
+}
+
+/// Compute the standard deviation of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn std_horizontal<E: AsRef<[Expr]>>(
+    exprs: E,
+    ddof: u8,
+    ignore_nulls: bool,
+) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::StdHorizontal { ddof, ignore_nulls })
+}
+
+/// Compute the median of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn median_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::MedianHorizontal { ignore_nulls })
+}
+
+/// Compute a quantile of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn quantile_horizontal<E: AsRef<[Expr]>>(
+    exprs: E,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+    ignore_nulls: bool,
+) -> PolarsResult<Expr> {
+    horizontal_function(
+        exprs,
+        FunctionExpr::QuantileHorizontal {
+            quantile,
+            interpol,
+            ignore_nulls,
+        },
+    )
+}
+
+/// Get the index of the column that holds the maximum value of every row. Ties resolve to
+/// the first column.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn arg_max_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::ArgMaxHorizontal { ignore_nulls })
+}
+
+/// Get the index of the column that holds the minimum value of every row. Ties resolve to
+/// the first column.
+///
+/// If `ignore_nulls` is `false`, rows that contain a null are null.
+pub fn arg_min_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::ArgMinHorizontal { ignore_nulls })
+}
+
+/// Count the null values of every row.
+pub fn null_count_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::NullCountHorizontal)
+}
+
+/// Count the unique values of every row. Null counts as a value.
+pub fn n_unique_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    horizontal_function(exprs, FunctionExpr::NUniqueHorizontal)
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
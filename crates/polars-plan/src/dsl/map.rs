@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value of `key` in every map, or null if the map doesn't hold the key.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::MapExpr(MapFunction::Get),
+            &[key],
+            false,
+            false,
+        )
+    }
+
+    /// Check whether every map holds `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::MapExpr(MapFunction::ContainsKey),
+            &[key],
+            false,
+            false,
+        )
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_private(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Convert the maps to a struct with a field for each of the given `keys`.
+    /// Missing keys become null.
+    pub fn to_struct(self, keys: Vec<String>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::ToStruct(Arc::new(keys))))
+    }
+}
@@ -24,6 +24,8 @@ mod from;
 pub(crate) mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -39,6 +41,8 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -52,6 +56,8 @@ pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
@@ -65,6 +71,8 @@ pub(crate) use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 
 use crate::constants::MAP_LIST_NAME;
 pub use crate::logical_plan::lit;
@@ -1666,6 +1674,12 @@ impl Expr {
         array::ArrayNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`CategoricalNameSpace`].
     #[cfg(feature = "dtype-categorical")]
     pub fn cat(self) -> cat::CategoricalNameSpace {
@@ -1678,6 +1692,12 @@ impl Expr {
         struct_::StructNameSpace(self)
     }
 
+    /// Get the [`union::UnionNameSpace`].
+    #[cfg(feature = "dtype-union")]
+    pub fn union_(self) -> union::UnionNameSpace {
+        union::UnionNameSpace(self)
+    }
+
     /// Get the [`meta::MetaNameSpace`]
     #[cfg(feature = "meta")]
     pub fn meta(self) -> meta::MetaNameSpace {
@@ -92,6 +92,35 @@ impl StringNameSpace {
         )
     }
 
+    /// The number of single character edits that turn a string into the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn levenshtein_distance(self, other: Expr) -> Expr {
+        self.distance(other, StringDistance::Levenshtein)
+    }
+
+    /// One minus the Jaro-Winkler similarity with the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaro_winkler_distance(self, other: Expr) -> Expr {
+        self.distance(other, StringDistance::JaroWinkler)
+    }
+
+    /// One minus the Jaccard similarity of the `n` character long substrings of a string and
+    /// the `other` string.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaccard_distance(self, other: Expr, n: usize) -> Expr {
+        self.distance(other, StringDistance::Jaccard { n })
+    }
+
+    #[cfg(feature = "string_similarity")]
+    fn distance(self, other: Expr, distance: StringDistance) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StringExpr(StringFunction::Distance(distance)),
+            &[other],
+            false,
+            true,
+        )
+    }
+
     #[cfg(feature = "string_encoding")]
     pub fn hex_encode(self) -> Expr {
         self.0
@@ -35,6 +35,13 @@ impl StructNameSpace {
             )))
     }
 
+    /// Convert every row to a map from the field names to the field values.
+    #[cfg(feature = "dtype-map")]
+    pub fn to_map(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::FromStruct))
+    }
+
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
         self.0
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::dsl::function_expr::UnionFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Union`].
+pub struct UnionNameSpace(pub Expr);
+
+impl UnionNameSpace {
+    /// Get the name of the active variant of every row.
+    pub fn tag(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::UnionExpr(UnionFunction::Tag))
+    }
+
+    /// Get the values of the variant `name`. These are null where another variant is active.
+    pub fn field(self, name: &str) -> Expr {
+        self.0
+            .map_private(FunctionExpr::UnionExpr(UnionFunction::FieldByName(
+                Arc::from(name),
+            )))
+    }
+}
@@ -14,6 +14,17 @@ pub struct OptState {
     pub streaming: bool,
     pub eager: bool,
     pub fast_projection: bool,
+    /// Reorder chains of inner joins by their estimated cardinality.
+    pub join_reorder: bool,
+    /// Memory budget in bytes of the streaming engine. Sinks spill to disk
+    /// (or fail if they cannot) instead of exceeding it.
+    pub memory_limit: Option<usize>,
+    /// Serve the results of repeated subplans from the process wide result cache.
+    pub result_cache: bool,
+    /// Run the subplans that are estimated not to fit in memory on the streaming engine.
+    pub auto_streaming: bool,
+    /// Filter the probe side scans of inner and semi joins by the keys of a small build side.
+    pub runtime_join_filters: bool,
 }
 
 impl Default for OptState {
@@ -32,7 +43,12 @@ impl Default for OptState {
             comm_subexpr_elim: true,
             streaming: false,
             fast_projection: true,
+            join_reorder: false,
             eager: false,
+            memory_limit: None,
+            result_cache: false,
+            auto_streaming: false,
+            runtime_join_filters: true,
         }
     }
 }
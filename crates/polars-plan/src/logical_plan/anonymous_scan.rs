@@ -2,10 +2,16 @@ use std::any::Any;
 use std::fmt::{Debug, Formatter};
 
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_io::predicates::{BatchStats, StatsEvaluator};
 
 pub use super::options::AnonymousScanOptions;
 use crate::dsl::Expr;
 
+/// Iterator over the batches of a partition of an [`AnonymousScan`].
+pub type AnonymousBatchIter = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
+#[derive(Clone)]
 pub struct AnonymousScanArgs {
     pub n_rows: Option<usize>,
     pub with_columns: Option<Arc<Vec<String>>>,
@@ -42,6 +48,65 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+
+    /// Number of partitions the source is split in.
+    ///
+    /// Sources that return `Some` are read partition by partition with
+    /// [`AnonymousScan::scan_batches`] instead of [`AnonymousScan::scan`]. This allows the
+    /// streaming engine to use the source and partitions to be pruned with
+    /// [`AnonymousScan::partition_stats`].
+    ///
+    /// Defaults to `None`
+    fn n_partitions(&self) -> Option<usize> {
+        None
+    }
+
+    /// Read a partition as an iterator of batches.
+    ///
+    /// Only called if [`AnonymousScan::n_partitions`] returns `Some`.
+    fn scan_batches(
+        &self,
+        _partition: usize,
+        _scan_opts: AnonymousScanArgs,
+    ) -> PolarsResult<AnonymousBatchIter> {
+        polars_bail!(ComputeError: "anonymous scan doesn't support batched reads");
+    }
+
+    /// Statistics of a partition. Partitions of which the statistics show that no row matches
+    /// the predicate are not read.
+    ///
+    /// Defaults to `None`
+    fn partition_stats(&self, _partition: usize) -> Option<BatchStats> {
+        None
+    }
+
+    /// The exact number of rows of the source, if known before scanning.
+    ///
+    /// Defaults to `None`
+    fn num_rows(&self) -> Option<usize> {
+        None
+    }
+
+    /// Columns by which the output of the scan is sorted.
+    ///
+    /// The columns are sorted as by a default `sort`, with the nulls first.
+    /// Defaults to no columns
+    fn sorted_columns(&self) -> Vec<(String, IsSorted)> {
+        vec![]
+    }
+
+    /// Name under which the scan is registered, so that plans reading from it can be
+    /// serialized.
+    ///
+    /// Defaults to `None`
+    fn registry_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Write the state needed by the registered deserializer to restore the scan.
+    fn try_serialize(&self, _buf: &mut Vec<u8>) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "serialize not supported for this anonymous scan")
+    }
 }
 
 impl<F> AnonymousScan for F
@@ -57,6 +122,32 @@ where
     }
 }
 
+impl dyn AnonymousScan {
+    /// Whether partition `partition` may contain rows that match the predicate.
+    pub fn should_read_partition(
+        &self,
+        partition: usize,
+        evaluator: Option<&dyn StatsEvaluator>,
+    ) -> PolarsResult<bool> {
+        match (evaluator, self.partition_stats(partition)) {
+            (Some(evaluator), Some(stats)) => evaluator.should_read(&stats),
+            _ => Ok(true),
+        }
+    }
+
+    /// Set the sorted flags declared by [`AnonymousScan::sorted_columns`] on the scanned data.
+    pub fn set_sorted_flags(&self, df: &mut DataFrame) {
+        for (name, sorted) in self.sorted_columns() {
+            // the column may be projected away
+            let _ = df.apply(&name, |s| {
+                let mut s = s.clone();
+                s.set_sorted_flag(sorted);
+                s
+            });
+        }
+    }
+}
+
 impl Debug for dyn AnonymousScan {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "anonymous_scan")
@@ -4,6 +4,17 @@ use polars_core::prelude::*;
 
 pub trait DataFrameUdf: Send + Sync {
     fn call_udf(&self, df: DataFrame) -> PolarsResult<DataFrame>;
+
+    /// Name under which the function is registered, so that plans containing it can be
+    /// serialized.
+    fn registry_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Write the state needed by the registered deserializer to restore the function.
+    fn try_serialize(&self, _buf: &mut Vec<u8>) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "serialize not supported for this 'opaque' function")
+    }
 }
 
 impl<F> DataFrameUdf for F
@@ -41,6 +52,17 @@ impl Debug for dyn DataFrameUdfMut {
 
 pub trait UdfSchema: Send + Sync {
     fn get_schema(&self, input_schema: &Schema) -> PolarsResult<SchemaRef>;
+
+    /// Name under which the function is registered, so that plans containing it can be
+    /// serialized.
+    fn registry_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Write the state needed by the registered deserializer to restore the function.
+    fn try_serialize(&self, _buf: &mut Vec<u8>) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "serialize not supported for this 'opaque' function")
+    }
 }
 
 impl<F> UdfSchema for F
@@ -2,6 +2,7 @@
 use std::io::{Read, Seek};
 
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 #[cfg(feature = "parquet")]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "ipc")]
@@ -109,7 +110,24 @@ impl LogicalPlanBuilder {
             None => function.schema(infer_schema_length)?,
         };
 
-        let file_info = FileInfo::new(schema.clone(), None, (n_rows, n_rows.unwrap_or(usize::MAX)));
+        let row_estimation = match (function.num_rows(), n_rows) {
+            (Some(num_rows), Some(n_rows)) => {
+                let num_rows = std::cmp::min(num_rows, n_rows);
+                (Some(num_rows), num_rows)
+            },
+            (Some(num_rows), None) => (Some(num_rows), num_rows),
+            (None, n_rows) => (n_rows, n_rows.unwrap_or(usize::MAX)),
+        };
+        let mut file_info = FileInfo::new(schema.clone(), None, row_estimation);
+        file_info.sorted_columns = function
+            .sorted_columns()
+            .into_iter()
+            .filter_map(|(name, sorted)| match sorted {
+                IsSorted::Ascending => Some(SortedColumn::new(&name, false, Some(false))),
+                IsSorted::Descending => Some(SortedColumn::new(&name, true, Some(false))),
+                IsSorted::Not => None,
+            })
+            .collect();
         let file_options = FileScanOptions {
             n_rows,
             with_columns: None,
@@ -203,6 +221,17 @@ impl LogicalPlanBuilder {
         if hive_partitioning {
             file_info.init_hive_partitions(path.as_path())?;
         }
+        // The sorting columns of a single file hold for the whole scan.
+        if paths.len() == 1 && !hive_partitioning {
+            if let (Some(md), Some(reader_schema)) = (&metadata, &file_info.reader_schema) {
+                if let Some((name, descending, nulls_first)) =
+                    polars_io::parquet::predicates::sorted_column(md, reader_schema)?
+                {
+                    file_info.sorted_columns =
+                        vec![SortedColumn::new(&name, descending, Some(!nulls_first))];
+                }
+            }
+        }
 
         let options = FileScanOptions {
             with_columns: None,
@@ -434,6 +463,7 @@ impl LogicalPlanBuilder {
                 |_| Ok(DataFrame::empty()),
                 AllowedOptimizations::default(),
                 Some(Arc::new(|_: &Schema| Ok(Arc::new(Schema::default())))),
+                None,
                 "EMPTY PROJECTION",
             )
         } else {
@@ -459,6 +489,7 @@ impl LogicalPlanBuilder {
                 |_| Ok(DataFrame::empty()),
                 AllowedOptimizations::default(),
                 Some(Arc::new(|_: &Schema| Ok(Arc::new(Schema::default())))),
+                None,
                 "EMPTY PROJECTION",
             )
         } else {
@@ -951,6 +982,7 @@ impl LogicalPlanBuilder {
         function: F,
         optimizations: AllowedOptimizations,
         schema: Option<Arc<dyn UdfSchema>>,
+        lineage: Option<ColumnLineage>,
         name: &'static str,
     ) -> Self
     where
@@ -966,6 +998,7 @@ impl LogicalPlanBuilder {
                 predicate_pd: optimizations.predicate_pushdown,
                 projection_pd: optimizations.projection_pushdown,
                 streamable: optimizations.streaming,
+                lineage,
                 fmt_str: name,
             },
         }
@@ -0,0 +1,763 @@
+//! Estimation of the size of query results.
+//!
+//! The estimates start at the scans: in-memory frames are sampled, parquet files are summarized
+//! from their metadata and CSV files are sampled from their first rows. They are then propagated
+//! through the plan with simple selectivity rules, so they are meant to distinguish small
+//! results from large ones, not to be exact.
+use polars_core::prelude::*;
+use polars_io::predicates::BatchStats;
+use smartstring::alias::String as SmartString;
+
+use crate::prelude::*;
+
+// Selectivity of predicates we can't derive from statistics.
+const EQ_SELECTIVITY: f64 = 0.1;
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+/// Number of rows that are sampled from in-memory frames and CSV files.
+const SAMPLE_SIZE: usize = 1024;
+
+/// Estimated statistics of a column of a query result.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnEstimate {
+    pub null_count: Option<usize>,
+    pub distinct_count: Option<usize>,
+    /// Size in bytes of the materialized column.
+    pub byte_size: Option<usize>,
+}
+
+/// Estimated size of a query result.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlanEstimate {
+    pub rows: Option<usize>,
+    pub columns: Vec<(SmartString, ColumnEstimate)>,
+}
+
+impl PlanEstimate {
+    pub fn column(&self, name: &str) -> Option<&ColumnEstimate> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, estimate)| estimate)
+    }
+
+    /// Size in bytes of the materialized result, if it is known for all columns.
+    pub fn byte_size(&self) -> Option<usize> {
+        self.columns
+            .iter()
+            .map(|(_, estimate)| estimate.byte_size)
+            .sum::<Option<usize>>()
+    }
+}
+
+/// Estimate the size of the result of the plan at `root`.
+pub fn estimate_plan(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> PlanEstimate {
+    estimate_node(root, lp_arena, expr_arena, &mut None).finish()
+}
+
+/// Estimate the size of the results of all subplans of the plan at `root`.
+pub fn estimate_subplans(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<Node, PlanEstimate> {
+    let mut subplans = Some(PlHashMap::new());
+    estimate_node(root, lp_arena, expr_arena, &mut subplans);
+    subplans.unwrap()
+}
+
+fn round(v: f64) -> usize {
+    v.round() as usize
+}
+
+/// What we know about the values of a column, independent of the number of rows.
+#[derive(Clone, Debug, Default)]
+struct Sketch {
+    null_fraction: Option<f64>,
+    ndv: Option<f64>,
+    /// Bytes per row.
+    width: Option<f64>,
+}
+
+impl Sketch {
+    fn from_dtype(dtype: &DataType) -> Self {
+        Self {
+            width: dtype_width(dtype),
+            ..Default::default()
+        }
+    }
+
+    /// Summarize a sample of `total` values.
+    fn from_sample(sample: &Series, total: f64) -> Self {
+        let n = sample.len();
+        if n == 0 {
+            return Self::from_dtype(sample.dtype());
+        }
+        Self {
+            null_fraction: Some(sample.null_count() as f64 / n as f64),
+            ndv: estimate_ndv(sample, total),
+            width: Some(sample.estimated_size() as f64 / n as f64),
+        }
+    }
+}
+
+struct Estimate {
+    rows: Option<f64>,
+    columns: PlIndexMap<SmartString, Sketch>,
+}
+
+impl Estimate {
+    fn finish(&self) -> PlanEstimate {
+        let rows = self.rows;
+        let columns = self
+            .columns
+            .iter()
+            .map(|(name, sketch)| {
+                let column = ColumnEstimate {
+                    null_count: rows.zip(sketch.null_fraction).map(|(r, f)| round(r * f)),
+                    distinct_count: sketch
+                        .ndv
+                        .map(|ndv| round(rows.map_or(ndv, |rows| ndv.min(rows)))),
+                    byte_size: rows.zip(sketch.width).map(|(r, w)| round(r * w)),
+                };
+                (name.clone(), column)
+            })
+            .collect();
+        PlanEstimate {
+            rows: rows.map(round),
+            columns,
+        }
+    }
+
+    fn from_schema(rows: Option<f64>, schema: &Schema) -> Self {
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| (name.clone(), Sketch::from_dtype(dtype)))
+            .collect();
+        Self { rows, columns }
+    }
+
+    /// Take the sketches of the columns of `schema` that are in `input`.
+    fn project(input: &Self, rows: Option<f64>, schema: &Schema) -> Self {
+        let mut estimate = Self::from_schema(rows, schema);
+        for (name, sketch) in estimate.columns.iter_mut() {
+            if let Some(input) = input.columns.get(name) {
+                *sketch = input.clone()
+            }
+        }
+        estimate
+    }
+}
+
+/// Byte size of a value of a fixed size type.
+fn dtype_width(dtype: &DataType) -> Option<f64> {
+    use DataType::*;
+    let width = match dtype.to_physical() {
+        Null => 0.0,
+        Boolean => 0.125,
+        Int8 | UInt8 => 1.0,
+        Int16 | UInt16 => 2.0,
+        Int32 | UInt32 | Float32 => 4.0,
+        Int64 | UInt64 | Float64 => 8.0,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => 16.0,
+        _ => return None,
+    };
+    Some(width)
+}
+
+/// Estimate the number of distinct values of a column from a sample of its `total` values.
+fn estimate_ndv(sample: &Series, total: f64) -> Option<f64> {
+    let n = sample.len() as f64;
+    let counts = sample.group_tuples(false, false).ok()?.group_count();
+    let distinct = counts.len() as f64;
+    // Values that occur once in the sample are likely to be followed by more unseen values.
+    let singletons = counts.into_no_null_iter().filter(|c| *c == 1).count() as f64;
+    Some(distinct + singletons * (total - n).max(0.0) / n)
+}
+
+fn sample(s: &Series) -> Series {
+    if s.len() > SAMPLE_SIZE {
+        s.gather_every(s.len() / SAMPLE_SIZE, 0)
+    } else {
+        s.clone()
+    }
+}
+
+/// Estimate the result of `node`, recording the estimates of all visited nodes in `subplans`.
+fn estimate_node(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    subplans: &mut Option<PlHashMap<Node, PlanEstimate>>,
+) -> Estimate {
+    use ALogicalPlan::*;
+    let lp = lp_arena.get(node);
+    let mut input = |node: &Node| estimate_node(*node, lp_arena, expr_arena, subplans);
+    let selectivity =
+        |predicate: &Option<Node>| predicate.map_or(1.0, |e| predicate_selectivity(e, expr_arena));
+    let estimate = match lp {
+        DataFrameScan {
+            df,
+            schema,
+            output_schema,
+            selection,
+            ..
+        } => {
+            let height = df.height() as f64;
+            let schema = output_schema.as_ref().unwrap_or(schema);
+            let mut estimate = Estimate::from_schema(Some(height * selectivity(selection)), schema);
+            for (name, sketch) in estimate.columns.iter_mut() {
+                if let Ok(s) = df.column(name) {
+                    *sketch = Sketch::from_sample(&sample(s), height);
+                }
+            }
+            estimate
+        },
+        Scan {
+            paths,
+            file_info,
+            predicate,
+            output_schema,
+            scan_type,
+            file_options,
+        } => {
+            let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+            let (known, estimated) = file_info.row_estimation;
+            let per_file = match known {
+                Some(known) => Some(known as f64),
+                // `usize::MAX` is used for an unknown size.
+                None if estimated != usize::MAX => Some(estimated as f64),
+                None => None,
+            };
+            let base = per_file.map(|rows| rows * paths.len() as f64);
+            let mut rows = match scan_type {
+                FileScan::Anonymous { function, .. } => {
+                    anonymous_scan_rows(function.as_ref(), *predicate, expr_arena)
+                },
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }
+            .or_else(|| base.map(|base| base * selectivity(predicate)));
+            if let Some(n_rows) = file_options.n_rows {
+                rows = Some(rows.map_or(n_rows as f64, |rows| rows.min(n_rows as f64)))
+            }
+
+            let mut estimate = Estimate::from_schema(rows, schema);
+            let sketches: Vec<(SmartString, Sketch)> = match scan_type {
+                #[cfg(feature = "parquet")]
+                FileScan::Parquet {
+                    metadata: Some(md), ..
+                } => parquet_sketches(md, file_info),
+                #[cfg(feature = "csv")]
+                FileScan::Csv { options } => {
+                    csv_sketches(&paths[0], options, file_info, file_options, base)
+                },
+                _ => vec![],
+            };
+            for (name, sketch) in sketches {
+                if let Some(s) = estimate.columns.get_mut(&name) {
+                    *s = sketch
+                }
+            }
+            estimate
+        },
+        Selection {
+            input: i,
+            predicate,
+        } => {
+            let mut estimate = input(i);
+            estimate.rows = estimate
+                .rows
+                .map(|rows| rows * predicate_selectivity(*predicate, expr_arena));
+            estimate
+        },
+        Slice { input: i, len, .. } => {
+            let mut estimate = input(i);
+            estimate.rows = Some(estimate.rows.map_or(*len as f64, |r| r.min(*len as f64)));
+            estimate
+        },
+        Projection {
+            input: i,
+            expr,
+            schema,
+            ..
+        } => {
+            let input = input(i);
+            let mut estimate = Estimate::from_schema(input.rows, schema);
+            for ((_, sketch), e) in estimate.columns.iter_mut().zip(expr.iter()) {
+                if let Some(s) = expr_sketch(*e, &input, expr_arena) {
+                    *sketch = s
+                }
+            }
+            estimate
+        },
+        HStack {
+            input: i,
+            exprs,
+            schema,
+            ..
+        } => {
+            let input = input(i);
+            let mut estimate = Estimate::project(&input, input.rows, schema);
+            for e in exprs {
+                if let (Some(name), Some(s)) = (
+                    output_name(*e, expr_arena),
+                    expr_sketch(*e, &input, expr_arena),
+                ) {
+                    if let Some(sketch) = estimate.columns.get_mut(name.as_ref()) {
+                        *sketch = s
+                    }
+                }
+            }
+            estimate
+        },
+        Sort { input: i, args, .. } => {
+            let mut estimate = input(i);
+            if let Some((_, len)) = args.slice {
+                estimate.rows = estimate.rows.map(|rows| rows.min(len as f64));
+            }
+            estimate
+        },
+        Cache { input: i, .. } | ExtContext { input: i, .. } | Sink { input: i, .. } => input(i),
+        Distinct { input: i, options } => {
+            let input = input(i);
+            let ndv = match &options.subset {
+                Some(subset) => group_count(subset.iter().map(|s| s.as_str()), &input),
+                None => group_count(input.columns.keys().map(|s| s.as_str()), &input),
+            };
+            let mut rows = input.rows.zip(ndv).map(|(rows, ndv)| rows.min(ndv));
+            if let Some((_, len)) = options.slice {
+                rows = rows.map(|rows| rows.min(len as f64));
+            }
+            Estimate {
+                rows: rows.or(input.rows),
+                columns: input.columns,
+            }
+        },
+        Aggregate {
+            input: i,
+            keys,
+            aggs,
+            schema,
+            options,
+            ..
+        } => {
+            let input = input(i);
+            let key_names = keys.iter().filter_map(|e| output_name(*e, expr_arena));
+            let groups = if keys.is_empty() {
+                Some(1.0)
+            } else {
+                group_count(key_names, &input)
+            };
+            let mut rows = match (input.rows, groups) {
+                (Some(rows), Some(groups)) => Some(rows.min(groups)),
+                (rows, groups) => rows.or(groups),
+            };
+            if let Some((_, len)) = options.slice {
+                rows = rows.map(|rows| rows.min(len as f64));
+            }
+            let mut estimate = Estimate::from_schema(rows, schema);
+            for ((_, sketch), e) in estimate.columns.iter_mut().zip(keys.iter().chain(aggs)) {
+                if let Some(s) = expr_sketch(*e, &input, expr_arena) {
+                    *sketch = s
+                }
+            }
+            estimate
+        },
+        Join {
+            input_left,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options,
+        } => {
+            let left = input(input_left);
+            let right = input(input_right);
+            let rows = join_rows(&left, &right, left_on, right_on, options, expr_arena);
+            let mut estimate = Estimate::project(&left, rows, schema);
+            for (name, sketch) in estimate.columns.iter_mut() {
+                if left.columns.contains_key(name) {
+                    continue;
+                }
+                // Right columns that collide with left columns get a suffix.
+                let right_name = name.strip_suffix(options.args.suffix()).unwrap_or(name);
+                if let Some(s) = right
+                    .columns
+                    .get(name.as_str())
+                    .or_else(|| right.columns.get(right_name))
+                {
+                    *sketch = s.clone()
+                }
+            }
+            estimate
+        },
+        Union { inputs, options } => {
+            let inputs = inputs.iter().map(&mut input).collect::<Vec<_>>();
+            let mut rows = inputs.iter().map(|e| e.rows).sum::<Option<f64>>();
+            if let Some((_, len)) = options.slice {
+                rows = rows.map(|rows| rows.min(len as f64));
+            }
+            let schema = lp.schema(lp_arena);
+            let mut estimate = Estimate::from_schema(rows, &schema);
+            for (name, sketch) in estimate.columns.iter_mut() {
+                *sketch = union_sketch(name, &inputs);
+            }
+            estimate
+        },
+        HConcat { inputs, schema, .. } => {
+            let inputs = inputs.iter().map(&mut input).collect::<Vec<_>>();
+            let rows = inputs
+                .iter()
+                .map(|e| e.rows)
+                .try_fold(0.0, |acc: f64, rows| Some(acc.max(rows?)));
+            let mut estimate = Estimate::from_schema(rows, schema);
+            for (name, sketch) in estimate.columns.iter_mut() {
+                if let Some(s) = inputs.iter().find_map(|e| e.columns.get(name)) {
+                    *sketch = s.clone()
+                }
+            }
+            estimate
+        },
+        MapFunction { input: i, function } => {
+            let input = input(i);
+            let schema = lp.schema(lp_arena);
+            match function {
+                FunctionNode::Count { .. } => Estimate::from_schema(Some(1.0), &schema),
+                FunctionNode::Rename { existing, new, .. } => {
+                    let mut estimate = Estimate::from_schema(input.rows, &schema);
+                    for (name, sketch) in estimate.columns.iter_mut() {
+                        let input_name = new
+                            .iter()
+                            .position(|new| new == name)
+                            .map_or(name, |idx| &existing[idx]);
+                        if let Some(s) = input.columns.get(input_name) {
+                            *sketch = s.clone()
+                        }
+                    }
+                    estimate
+                },
+                FunctionNode::DropNulls { subset } => {
+                    let not_null = subset
+                        .iter()
+                        .map(|name| {
+                            let sketch = input.columns.get(name.as_ref())?;
+                            Some(1.0 - sketch.null_fraction?)
+                        })
+                        .product::<Option<f64>>()
+                        .unwrap_or(DEFAULT_SELECTIVITY);
+                    let mut estimate =
+                        Estimate::project(&input, input.rows.map(|r| r * not_null), &schema);
+                    for (_, sketch) in estimate.columns.iter_mut() {
+                        sketch.null_fraction = Some(0.0)
+                    }
+                    estimate
+                },
+                function if function.expands_rows() => Estimate::from_schema(None, &schema),
+                _ => Estimate::project(&input, input.rows, &schema),
+            }
+        },
+        #[allow(unreachable_patterns)]
+        _ => Estimate::from_schema(None, &lp.schema(lp_arena)),
+    };
+    if let Some(subplans) = subplans {
+        subplans.insert(node, estimate.finish());
+    }
+    estimate
+}
+
+/// The name of the column an expression produces.
+fn output_name(node: Node, expr_arena: &Arena<AExpr>) -> Option<Arc<str>> {
+    match expr_arena.get(node) {
+        AExpr::Alias(_, name) => Some(name.clone()),
+        _ => aexpr_to_leaf_names(node, expr_arena).into_iter().next(),
+    }
+}
+
+fn expr_sketch(node: Node, input: &Estimate, expr_arena: &Arena<AExpr>) -> Option<Sketch> {
+    match expr_arena.get(node) {
+        AExpr::Alias(e, _) => expr_sketch(*e, input, expr_arena),
+        AExpr::Column(name) => input.columns.get(name.as_ref()).cloned(),
+        AExpr::Literal(lv) => {
+            let is_null = matches!(lv, LiteralValue::Null);
+            Some(Sketch {
+                null_fraction: Some(if is_null { 1.0 } else { 0.0 }),
+                ndv: Some(1.0),
+                width: dtype_width(&lv.get_datatype()),
+            })
+        },
+        // Casts keep the nulls and distinct values, but change the width.
+        AExpr::Cast {
+            expr, data_type, ..
+        } => {
+            let sketch = expr_sketch(*expr, input, expr_arena)?;
+            Some(Sketch {
+                width: dtype_width(data_type),
+                ..sketch
+            })
+        },
+        _ => None,
+    }
+}
+
+/// Upper bound of the number of distinct combinations of the given columns.
+fn group_count<S: AsRef<str>>(names: impl Iterator<Item = S>, input: &Estimate) -> Option<f64> {
+    names
+        .map(|name| input.columns.get(name.as_ref())?.ndv)
+        .product()
+}
+
+fn join_rows(
+    left: &Estimate,
+    right: &Estimate,
+    left_on: &[Node],
+    right_on: &[Node],
+    options: &JoinOptions,
+    expr_arena: &Arena<AExpr>,
+) -> Option<f64> {
+    let (rows_left, rows_right) = (left.rows?, right.rows?);
+    // The distinct values of a key are bounded by the side with the most distinct values. If
+    // they are unknown, the smaller side is assumed to hold the unique keys.
+    let key_ndv = |on: &[Node], input: &Estimate| {
+        let names = on.iter().filter_map(|e| output_name(*e, expr_arena));
+        group_count(names, input)
+    };
+    let ndv_left = key_ndv(left_on, left);
+    let ndv_right = key_ndv(right_on, right);
+    let ndv = match (ndv_left, ndv_right) {
+        (Some(l), Some(r)) => l.max(r),
+        _ => rows_left.min(rows_right),
+    }
+    .max(1.0);
+    let inner = rows_left * rows_right / ndv;
+
+    let rows = match &options.args.how {
+        JoinType::Inner => inner,
+        JoinType::Left => inner.max(rows_left),
+        JoinType::Outer { .. } => inner.max(rows_left).max(rows_right),
+        JoinType::Cross => rows_left * rows_right,
+        #[cfg(feature = "iejoin")]
+        JoinType::IEJoin(options) => {
+            options
+                .operators
+                .iter()
+                .fold(rows_left * rows_right, |rows, op| match op {
+                    JoinOperator::Eq => rows * EQ_SELECTIVITY,
+                    _ => rows * RANGE_SELECTIVITY,
+                })
+        },
+        #[cfg(feature = "interval_join")]
+        JoinType::Interval(options) => match options.mode {
+            IntervalJoinMode::Nearest => rows_left,
+            _ => rows_left * rows_right * RANGE_SELECTIVITY * RANGE_SELECTIVITY,
+        },
+        #[cfg(feature = "fuzzy_join")]
+        JoinType::Fuzzy(_) => rows_left * rows_right * EQ_SELECTIVITY,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi | JoinType::Anti => {
+            let matched = match (ndv_left, ndv_right) {
+                (Some(l), Some(r)) => (r / l.max(1.0)).min(1.0),
+                _ => DEFAULT_SELECTIVITY,
+            };
+            if matches!(options.args.how, JoinType::Semi) {
+                rows_left * matched
+            } else {
+                rows_left * (1.0 - matched)
+            }
+        },
+        // Asof joins find at most one match for every left row. The variant exists if another
+        // crate enables the join in polars-ops.
+        #[allow(unreachable_patterns)]
+        _ => rows_left,
+    };
+    Some(match options.args.slice {
+        Some((_, len)) => rows.min(len as f64),
+        None => rows,
+    })
+}
+
+fn union_sketch(name: &str, inputs: &[Estimate]) -> Sketch {
+    let mut sketch = Sketch {
+        null_fraction: Some(0.0),
+        ndv: Some(0.0),
+        width: Some(0.0),
+    };
+    let total = inputs.iter().map(|e| e.rows).sum::<Option<f64>>();
+    for input in inputs {
+        let s = input.columns.get(name).cloned().unwrap_or_default();
+        // Weigh the per row statistics by the share of the input in the result.
+        let weight = total
+            .zip(input.rows)
+            .map(|(total, rows)| if total > 0.0 { rows / total } else { 0.0 });
+        sketch.null_fraction = sketch
+            .null_fraction
+            .zip(s.null_fraction.zip(weight))
+            .map(|(acc, (f, w))| acc + f * w);
+        sketch.width = sketch
+            .width
+            .zip(s.width.zip(weight))
+            .map(|(acc, (f, w))| acc + f * w);
+        sketch.ndv = sketch.ndv.zip(s.ndv).map(|(acc, ndv)| acc + ndv);
+    }
+    sketch
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_sketches(
+    md: &polars_parquet::write::FileMetaData,
+    file_info: &FileInfo,
+) -> Vec<(SmartString, Sketch)> {
+    let Some(reader_schema) = &file_info.reader_schema else {
+        return vec![];
+    };
+    let Ok(summaries) = polars_io::parquet::predicates::column_summaries(md, reader_schema) else {
+        return vec![];
+    };
+    let rows = md.num_rows as f64;
+    reader_schema
+        .fields
+        .iter()
+        .zip(summaries)
+        .map(|(field, summary)| {
+            let sketch = Sketch {
+                null_fraction: summary
+                    .null_count
+                    .filter(|_| rows > 0.0)
+                    .map(|n| n as f64 / rows),
+                ndv: summary.distinct_count.map(|n| n as f64),
+                width: (rows > 0.0).then(|| summary.uncompressed_size as f64 / rows),
+            };
+            (field.name.as_str().into(), sketch)
+        })
+        .collect()
+}
+
+/// Sample the first rows of a CSV file.
+#[cfg(feature = "csv")]
+fn csv_sketches(
+    path: &std::path::Path,
+    options: &CsvParserOptions,
+    file_info: &FileInfo,
+    file_options: &FileScanOptions,
+    rows: Option<f64>,
+) -> Vec<(SmartString, Sketch)> {
+    use polars_io::csv::CsvReader;
+    use polars_io::SerReader;
+
+    let sample = CsvReader::from_path(path).and_then(|reader| {
+        reader
+            .has_header(options.has_header)
+            .with_dtypes(Some(file_info.schema.clone()))
+            .with_separator(options.separator)
+            .with_ignore_errors(options.ignore_errors)
+            .with_skip_rows(options.skip_rows)
+            .with_n_rows(Some(SAMPLE_SIZE))
+            .with_null_values(options.null_values.clone())
+            ._with_comment_prefix(options.comment_prefix.clone())
+            .with_quote_char(options.quote_char)
+            .with_end_of_line_char(options.eol_char)
+            .with_encoding(options.encoding)
+            .with_row_index(file_options.row_index.clone())
+            .with_try_parse_dates(options.try_parse_dates)
+            .truncate_ragged_lines(options.truncate_ragged_lines)
+            .finish()
+    });
+    let Ok(sample) = sample else {
+        return vec![];
+    };
+    let total = rows.unwrap_or(sample.height() as f64);
+    sample
+        .get_columns()
+        .iter()
+        .map(|s| (s.name().into(), Sketch::from_sample(s, total)))
+        .collect()
+}
+
+/// Sum the rows of the partitions whose statistics don't exclude the predicate.
+pub(crate) fn anonymous_scan_rows(
+    function: &dyn AnonymousScan,
+    predicate: Option<Node>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<f64> {
+    let selectivity = predicate.map_or(1.0, |e| predicate_selectivity(e, expr_arena));
+    let mut rows = 0.0;
+    for partition in 0..function.n_partitions()? {
+        let stats = function.partition_stats(partition)?;
+        if predicate.map_or(false, |e| excluded_by_stats(e, &stats, expr_arena)) {
+            continue;
+        }
+        rows += stats.num_rows()? as f64 * selectivity;
+    }
+    Some(rows)
+}
+
+pub(crate) fn predicate_selectivity(node: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    use Operator::*;
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            And | LogicalAnd => {
+                predicate_selectivity(*left, expr_arena) * predicate_selectivity(*right, expr_arena)
+            },
+            Or | LogicalOr => {
+                let left = predicate_selectivity(*left, expr_arena);
+                let right = predicate_selectivity(*right, expr_arena);
+                left + right - left * right
+            },
+            Eq | EqValidity => EQ_SELECTIVITY,
+            NotEq | NotEqValidity => 1.0 - EQ_SELECTIVITY,
+            Lt | LtEq | Gt | GtEq => RANGE_SELECTIVITY,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+/// Check if the min/max statistics prove that no row matches a `column <op> literal` predicate.
+fn excluded_by_stats(node: Node, stats: &BatchStats, expr_arena: &Arena<AExpr>) -> bool {
+    use Operator::*;
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+        return false;
+    };
+    if matches!(op, And | LogicalAnd) {
+        return excluded_by_stats(*left, stats, expr_arena)
+            || excluded_by_stats(*right, stats, expr_arena);
+    }
+    let (name, value, op) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+        (AExpr::Column(name), AExpr::Literal(lv)) => (name, lv, *op),
+        (AExpr::Literal(lv), AExpr::Column(name)) => {
+            let op = match op {
+                Lt => Gt,
+                LtEq => GtEq,
+                Gt => Lt,
+                GtEq => LtEq,
+                op => *op,
+            };
+            (name, lv, op)
+        },
+        _ => return false,
+    };
+    let Some(value) = value.to_any_value().and_then(|av| av.extract::<f64>()) else {
+        return false;
+    };
+    let Ok(column_stats) = stats.get_stats(name) else {
+        return false;
+    };
+    let bound = |s: Option<&Series>| s.and_then(|s| s.get(0).ok()?.extract::<f64>());
+    let (Some(min), Some(max)) = (bound(column_stats.to_min()), bound(column_stats.to_max()))
+    else {
+        return false;
+    };
+    match op {
+        Eq => value < min || value > max,
+        Lt => min >= value,
+        LtEq => min > value,
+        Gt => max <= value,
+        GtEq => max < value,
+        _ => false,
+    }
+}
@@ -12,14 +12,18 @@ pub enum FileScan {
     Parquet {
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
+        // Cached footer, read again from the file after deserialization.
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<Arc<FileMetaData>>,
     },
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
-    #[cfg_attr(feature = "serde", serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::logical_plan::serialize::registered")
+        )]
         function: Arc<dyn AnonymousScan>,
     },
 }
@@ -91,6 +95,7 @@ impl FileScan {
             Self::Ipc { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
+            Self::Anonymous { function, .. } => function.n_partitions().is_some(),
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -0,0 +1,311 @@
+//! Fingerprints of subplans, used to serve the results of repeated subplans across queries.
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::is_cloud_url;
+
+use crate::prelude::*;
+
+/// Identifies the result of a subplan.
+///
+/// Two subplans have the same fingerprint if they compute the same expressions over the same
+/// files, and the files have not been modified in between. The fingerprint holds a complete
+/// description of the subplan, the hash is only used to find it.
+#[derive(Clone, Debug)]
+pub struct PlanFingerprint {
+    hash: u64,
+    repr: Arc<str>,
+}
+
+impl PlanFingerprint {
+    /// Hash of the subplan and the modification times of the files it reads.
+    pub fn id(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl PartialEq for PlanFingerprint {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.repr == other.repr
+    }
+}
+
+impl Eq for PlanFingerprint {}
+
+impl Hash for PlanFingerprint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+/// Compute the fingerprint of the subplan at `root`.
+///
+/// Returns `None` if the result of the subplan can't be identified across queries, e.g.
+/// because it reads in-memory or remote data, applies user defined functions or samples
+/// without a seed.
+pub fn subplan_fingerprint(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<PlanFingerprint> {
+    let mut writer = FingerprintWriter {
+        lp_arena,
+        expr_arena,
+        out: String::new(),
+    };
+    writer.write_lp(root)?;
+
+    // the default hasher has fixed keys, so ids are stable within a build
+    let mut hasher = DefaultHasher::new();
+    writer.out.hash(&mut hasher);
+    Some(PlanFingerprint {
+        hash: hasher.finish(),
+        repr: writer.out.into(),
+    })
+}
+
+/// Fingerprints of the subplans whose results are worth caching: the whole plan and the inputs
+/// of joins, unions and horizontal concatenations.
+pub fn result_cache_candidates(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<Node, PlanFingerprint> {
+    let mut candidates = vec![root];
+    let mut stack = vec![root];
+    let mut inputs = vec![];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        lp.copy_inputs(&mut inputs);
+        if matches!(
+            lp,
+            ALogicalPlan::Join { .. } | ALogicalPlan::Union { .. } | ALogicalPlan::HConcat { .. }
+        ) {
+            candidates.extend_from_slice(&inputs);
+        }
+        stack.append(&mut inputs);
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|node| Some((node, subplan_fingerprint(node, lp_arena, expr_arena)?)))
+        .collect()
+}
+
+struct FingerprintWriter<'a> {
+    lp_arena: &'a Arena<ALogicalPlan>,
+    expr_arena: &'a Arena<AExpr>,
+    out: String,
+}
+
+impl FingerprintWriter<'_> {
+    fn write_exprs(&mut self, exprs: &[Node]) -> Option<()> {
+        write!(self.out, "[").unwrap();
+        for e in exprs {
+            self.write_expr(*e)?;
+            write!(self.out, ",").unwrap();
+        }
+        write!(self.out, "]").unwrap();
+        Some(())
+    }
+
+    fn write_expr(&mut self, node: Node) -> Option<()> {
+        let ae = self.expr_arena.get(node);
+        match ae {
+            AExpr::AnonymousFunction { .. } => return None,
+            // without a seed every run gives a different result
+            #[cfg(feature = "random")]
+            AExpr::Function {
+                function: FunctionExpr::Random { seed: None, .. },
+                ..
+            } => return None,
+            AExpr::Literal(lv) => return write_literal(&mut self.out, lv),
+            _ => {},
+        }
+        // Write the node with its inputs replaced by their position, so that the description
+        // doesn't depend on the layout of the arena, followed by the inputs.
+        let mut inputs = vec![];
+        ae.nodes(&mut inputs);
+        let positions = (0..inputs.len()).map(Node).collect::<Vec<_>>();
+        write!(self.out, "{:?}(", ae.clone().replace_inputs(&positions)).unwrap();
+        for input in inputs {
+            self.write_expr(input)?;
+            write!(self.out, ",").unwrap();
+        }
+        write!(self.out, ")").unwrap();
+        Some(())
+    }
+
+    fn write_lp(&mut self, node: Node) -> Option<()> {
+        use ALogicalPlan::*;
+        match self.lp_arena.get(node) {
+            Scan {
+                paths,
+                file_info,
+                predicate,
+                output_schema,
+                scan_type,
+                file_options,
+            } => {
+                write!(self.out, "SCAN[").unwrap();
+                for path in paths.iter() {
+                    write_file(&mut self.out, path)?;
+                }
+                let options = match scan_type {
+                    #[cfg(feature = "csv")]
+                    FileScan::Csv { options } => Some(format!("{options:?}")),
+                    #[cfg(feature = "parquet")]
+                    FileScan::Parquet { options, .. } => Some(format!("{options:?}")),
+                    #[cfg(feature = "ipc")]
+                    FileScan::Ipc { options } => Some(format!("{options:?}")),
+                    FileScan::Anonymous { .. } => None::<String>,
+                }?;
+                write!(
+                    self.out,
+                    "] {options} schema: {:?} output_schema: {:?} n_rows: {:?} with_columns: {:?} row_index: {:?} hive: {}",
+                    file_info.schema,
+                    output_schema,
+                    file_options.n_rows,
+                    file_options.with_columns,
+                    file_options.row_index,
+                    file_options.hive_partitioning,
+                )
+                .unwrap();
+                if let Some(predicate) = predicate {
+                    write!(self.out, " predicate: ").unwrap();
+                    self.write_expr(*predicate)?;
+                }
+                return Some(());
+            },
+            // the cache ids differ between queries
+            Cache { input, .. } => return self.write_lp(*input),
+            Slice { offset, len, .. } => write!(self.out, "SLICE {offset} {len}").unwrap(),
+            Selection { predicate, .. } => {
+                write!(self.out, "FILTER ").unwrap();
+                self.write_expr(*predicate)?;
+            },
+            Projection { expr, options, .. } => {
+                write!(self.out, "SELECT {options:?} ").unwrap();
+                self.write_exprs(expr)?;
+            },
+            HStack { exprs, options, .. } => {
+                write!(self.out, "WITH_COLUMNS {options:?} ").unwrap();
+                self.write_exprs(exprs)?;
+            },
+            Sort {
+                by_column, args, ..
+            } => {
+                write!(self.out, "SORT {args:?} ").unwrap();
+                self.write_exprs(by_column)?;
+            },
+            Aggregate {
+                keys,
+                aggs,
+                apply: None,
+                maintain_order,
+                options,
+                ..
+            } => {
+                write!(self.out, "AGGREGATE {maintain_order} {options:?} ").unwrap();
+                self.write_exprs(keys)?;
+                self.write_exprs(aggs)?;
+            },
+            Join {
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                write!(self.out, "JOIN {:?} ", options.args).unwrap();
+                self.write_exprs(left_on)?;
+                self.write_exprs(right_on)?;
+            },
+            Distinct { options, .. } => write!(self.out, "UNIQUE {options:?}").unwrap(),
+            Union { options, .. } => write!(self.out, "UNION {options:?}").unwrap(),
+            HConcat { options, .. } => write!(self.out, "HCONCAT {options:?}").unwrap(),
+            MapFunction { function, .. } => {
+                write!(self.out, "MAP ").unwrap();
+                write_function(&mut self.out, function)?;
+            },
+            // in-memory data, user defined functions and sinks can't be identified
+            _ => return None,
+        }
+
+        let mut inputs = vec![];
+        self.lp_arena.get(node).copy_inputs(&mut inputs);
+        write!(self.out, "(").unwrap();
+        for input in inputs {
+            self.write_lp(input)?;
+            write!(self.out, ",").unwrap();
+        }
+        write!(self.out, ")").unwrap();
+        Some(())
+    }
+}
+
+/// Write the path of a local file with its size and modification time.
+fn write_file(out: &mut String, path: &Path) -> Option<()> {
+    if is_cloud_url(path) {
+        return None;
+    }
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?;
+    write!(out, "{path:?}@{modified:?}:{},", metadata.len()).unwrap();
+    Some(())
+}
+
+fn write_literal(out: &mut String, lv: &LiteralValue) -> Option<()> {
+    // The `Debug` implementation of literals is not exact, e.g. for series.
+    match lv {
+        LiteralValue::Binary(v) => write!(out, "lit(binary {v:?})").unwrap(),
+        LiteralValue::Range {
+            low,
+            high,
+            data_type,
+        } => write!(out, "lit(range {low} {high} {data_type:?})").unwrap(),
+        LiteralValue::Series(s) => {
+            write!(out, "lit(series {:?} {:?} [", s.name(), s.dtype()).unwrap();
+            for av in s.iter() {
+                write!(out, "{av:?},").unwrap();
+            }
+            write!(out, "])").unwrap();
+        },
+        lv => write!(out, "lit({:?})", lv.to_any_value()?).unwrap(),
+    }
+    Some(())
+}
+
+fn write_function(out: &mut String, function: &FunctionNode) -> Option<()> {
+    use FunctionNode::*;
+    match function {
+        Count { paths, .. } => {
+            write!(out, "COUNT[").unwrap();
+            for path in paths.iter() {
+                write_file(out, path)?;
+            }
+            write!(out, "]").unwrap();
+        },
+        Unnest { columns } => write!(out, "UNNEST {columns:?}").unwrap(),
+        FastProjection {
+            columns,
+            duplicate_check,
+        } => write!(out, "FAST_PROJECT {columns:?} {duplicate_check}").unwrap(),
+        DropNulls { subset } => write!(out, "DROP_NULLS {subset:?}").unwrap(),
+        Rechunk => write!(out, "RECHUNK").unwrap(),
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted { column } => write!(out, "MERGE_SORTED {column:?}").unwrap(),
+        Rename {
+            existing,
+            new,
+            swapping,
+        } => write!(out, "RENAME {existing:?} {new:?} {swapping}").unwrap(),
+        Explode { columns, .. } => write!(out, "EXPLODE {columns:?}").unwrap(),
+        Melt { args, .. } => write!(out, "MELT {args:?}").unwrap(),
+        RowIndex { name, offset, .. } => write!(out, "ROW_INDEX {name:?} {offset:?}").unwrap(),
+        _ => return None,
+    }
+    Some(())
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+/// Declares how the output columns of a user defined function relate to its input columns, so
+/// that the optimizer can push predicates and projections past the function.
+///
+/// Declaring a lineage asserts that the function maps every input row to exactly one output row
+/// without looking at the other rows, e.g. because it enriches the rows with extra columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnLineage {
+    /// Columns that are in the output with the same name and values as in the input.
+    pub passthrough: Arc<[SmartString]>,
+    /// Columns of the input the function reads. Other input columns may be removed before the
+    /// function is called.
+    pub reads: Arc<[SmartString]>,
+}
+
+impl Default for ColumnLineage {
+    fn default() -> Self {
+        Self {
+            passthrough: Arc::from([]),
+            reads: Arc::from([]),
+        }
+    }
+}
+
+impl ColumnLineage {
+    pub fn new<I, J, S, T>(passthrough: I, reads: J) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        J: IntoIterator<Item = T>,
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        Self {
+            passthrough: passthrough.into_iter().map(|s| s.as_ref().into()).collect(),
+            reads: reads.into_iter().map(|s| s.as_ref().into()).collect(),
+        }
+    }
+
+    /// Whether the column `name` has the same values before and after the function.
+    pub fn passes_through(&self, name: &str) -> bool {
+        self.passthrough.iter().any(|s| s.as_str() == name)
+    }
+}
@@ -1,4 +1,5 @@
 mod count;
+mod lineage;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 #[cfg(feature = "python")]
@@ -14,6 +15,7 @@ use polars_core::prelude::*;
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
+pub use self::lineage::ColumnLineage;
 #[cfg(feature = "python")]
 use crate::dsl::python_udf::PythonFunction;
 #[cfg(feature = "merge_sorted")]
@@ -34,15 +36,25 @@ pub enum FunctionNode {
         streamable: bool,
         validate_output: bool,
     },
-    #[cfg_attr(feature = "serde", serde(skip))]
     Opaque {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::logical_plan::serialize::registered")
+        )]
         function: Arc<dyn DataFrameUdf>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::logical_plan::serialize::registered_opt")
+        )]
         schema: Option<Arc<dyn UdfSchema>>,
         ///  allow predicate pushdown optimizations
         predicate_pd: bool,
         ///  allow projection pushdown optimizations
         projection_pd: bool,
         streamable: bool,
+        /// allow predicate and projection pushdown by the declared lineage of the columns
+        #[cfg_attr(feature = "serde", serde(default))]
+        lineage: Option<ColumnLineage>,
         // used for formatting
         #[cfg_attr(feature = "serde", serde(skip))]
         fmt_str: &'static str,
@@ -154,6 +166,7 @@ impl FunctionNode {
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
+            // the streaming engine adds the row index in a sink
             RowIndex { .. } => false,
         }
     }
@@ -255,7 +268,11 @@ impl FunctionNode {
     pub(crate) fn allow_predicate_pd(&self) -> bool {
         use FunctionNode::*;
         match self {
-            Opaque { predicate_pd, .. } => *predicate_pd,
+            Opaque {
+                predicate_pd,
+                lineage,
+                ..
+            } => *predicate_pd || lineage.is_some(),
             #[cfg(feature = "python")]
             OpaquePython { predicate_pd, .. } => *predicate_pd,
             FastProjection { .. }
@@ -275,7 +292,11 @@ impl FunctionNode {
     pub(crate) fn allow_projection_pd(&self) -> bool {
         use FunctionNode::*;
         match self {
-            Opaque { projection_pd, .. } => *projection_pd,
+            Opaque {
+                projection_pd,
+                lineage,
+                ..
+            } => *projection_pd || lineage.is_some(),
             #[cfg(feature = "python")]
             OpaquePython { projection_pd, .. } => *projection_pd,
             FastProjection { .. }
@@ -300,6 +321,16 @@ impl FunctionNode {
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
             MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            Opaque {
+                lineage: Some(lineage),
+                ..
+            } => Cow::Owned(
+                lineage
+                    .reads
+                    .iter()
+                    .map(|s| Arc::from(s.as_str()))
+                    .collect(),
+            ),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -21,7 +21,9 @@ pub mod builder_functions;
 pub(crate) mod conversion;
 #[cfg(feature = "debugging")]
 pub(crate) mod debug;
+mod estimate;
 mod file_scan;
+mod fingerprint;
 mod format;
 mod functions;
 pub(super) mod hive;
@@ -29,11 +31,15 @@ pub(crate) mod iterator;
 mod lit;
 pub(crate) mod optimizer;
 pub(crate) mod options;
+mod profile;
 pub(crate) mod projection;
 mod projection_expr;
 #[cfg(feature = "python")]
 mod pyarrow;
 mod schema;
+#[cfg(feature = "serde")]
+pub(crate) mod serialize;
+mod sortedness;
 pub(crate) mod tree_format;
 pub mod visitor;
 
@@ -44,14 +50,23 @@ pub use apply::*;
 pub use builder::*;
 pub use builder_alp::*;
 pub use conversion::*;
+pub use estimate::{estimate_plan, estimate_subplans, ColumnEstimate, PlanEstimate};
 pub use file_scan::*;
+pub use fingerprint::*;
 pub use functions::*;
 pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
+pub use profile::*;
 pub use schema::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde")]
+pub use serialize::{
+    register_anonymous_scan, register_dataframe_udf, register_series_udf, register_udf_schema,
+    PlanFormat, MIN_PLAN_FORMAT_VERSION, PLAN_FORMAT_VERSION,
+};
+pub use sortedness::*;
 use strum_macros::IntoStaticStr;
 
 use self::tree_format::{TreeFmtNode, TreeFmtVisitor};
@@ -183,7 +198,10 @@ pub enum LogicalPlan {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         schema: SchemaRef,
-        #[cfg_attr(feature = "serde", serde(skip))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::logical_plan::serialize::registered_opt")
+        )]
         apply: Option<Arc<dyn DataFrameUdf>>,
         maintain_order: bool,
         options: Arc<GroupbyOptions>,
@@ -274,7 +292,12 @@ impl LogicalPlan {
     }
 
     pub fn describe_tree_format(&self) -> String {
-        let mut visitor = TreeFmtVisitor::default();
+        self.describe_tree_format_annotated(vec![])
+    }
+
+    /// Describe the plan in tree format with extra text for the nodes, given in pre-order.
+    pub fn describe_tree_format_annotated(&self, annotations: Vec<Option<String>>) -> String {
+        let mut visitor = TreeFmtVisitor::with_annotations(annotations);
         TreeFmtNode::root_logical_plan(self).traverse(&mut visitor);
         format!("{visitor:#?}")
     }
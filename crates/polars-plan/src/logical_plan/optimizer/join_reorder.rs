@@ -0,0 +1,324 @@
+//! Reorders chains of inner joins by estimated cardinality.
+//!
+//! A chain of inner equi-joins is flattened into its base relations. Every relation gets a row
+//! estimate from the scan metadata (parquet row counts, anonymous scan statistics, in-memory
+//! heights) times the selectivity of the filters that were pushed down into it. The joins are
+//! then rebuilt greedily so that the smallest intermediate results are produced first. This makes
+//! sure a large fact table is reduced by the most selective dimensions before it meets the others.
+//!
+//! Only chains that give the same result in any order are touched:
+//! - all joins are inner joins without validation or slice,
+//! - every join key is a plain column with the same name on both sides,
+//! - no non-key columns collide (so no suffixes are introduced).
+//!
+//! Under those conditions a column name that occurs in multiple relations is always a join key,
+//! so the joins of the rebuilt chain are on the columns the relations share.
+use polars_core::prelude::*;
+
+use crate::logical_plan::estimate::{anonymous_scan_rows, predicate_selectivity};
+use crate::prelude::*;
+
+/// Reorder all chains of inner joins in the plan rooted at `node`.
+pub(super) fn reorder_joins(
+    node: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let mut leaves = vec![];
+    match collect_chain(node, lp_arena, expr_arena, &mut leaves) {
+        Some(join_nulls) => {
+            for leaf in &leaves {
+                reorder_joins(*leaf, lp_arena, expr_arena)
+            }
+            if leaves.len() > 2 {
+                if let Some(lp) = reorder_chain(node, &leaves, join_nulls, lp_arena, expr_arena) {
+                    lp_arena.replace(node, lp);
+                }
+            }
+        },
+        None => {
+            let mut inputs = vec![];
+            lp_arena.get(node).copy_inputs(&mut inputs);
+            for input in inputs {
+                reorder_joins(input, lp_arena, expr_arena)
+            }
+        },
+    }
+}
+
+/// Returns the `join_nulls` argument if `node` is a join that may be reordered.
+fn reorderable_join(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<bool> {
+    let ALogicalPlan::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Inner)
+        || args.validation.needs_checks()
+        || args.slice.is_some()
+    {
+        return None;
+    }
+
+    let schema_left = lp_arena.get(*input_left).schema(lp_arena);
+    let schema_right = lp_arena.get(*input_right).schema(lp_arena);
+    let mut keys = PlHashSet::with_capacity(left_on.len());
+    for (l, r) in left_on.iter().zip(right_on) {
+        match (expr_arena.get(*l), expr_arena.get(*r)) {
+            (AExpr::Column(l), AExpr::Column(r))
+                if l == r
+                    && schema_left.get(l).is_some()
+                    && schema_left.get(l) == schema_right.get(r) =>
+            {
+                keys.insert(l.as_ref());
+            },
+            _ => return None,
+        }
+    }
+    // Any other shared name would get a suffix.
+    let no_suffix = schema_right
+        .iter_names()
+        .all(|name| keys.contains(name.as_str()) || !schema_left.contains(name));
+    no_suffix.then_some(args.join_nulls)
+}
+
+/// Flatten the chain of reorderable joins rooted at `node` into `leaves`.
+fn collect_chain(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    leaves: &mut Vec<Node>,
+) -> Option<bool> {
+    let join_nulls = reorderable_join(node, lp_arena, expr_arena)?;
+    let ALogicalPlan::Join {
+        input_left,
+        input_right,
+        ..
+    } = lp_arena.get(node)
+    else {
+        unreachable!()
+    };
+    for input in [*input_left, *input_right] {
+        if reorderable_join(input, lp_arena, expr_arena) == Some(join_nulls) {
+            collect_chain(input, lp_arena, expr_arena, leaves);
+        } else {
+            leaves.push(input)
+        }
+    }
+    Some(join_nulls)
+}
+
+fn reorder_chain(
+    root: Node,
+    leaves: &[Node],
+    join_nulls: bool,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<ALogicalPlan> {
+    // We don't guess if we don't know the size of all relations.
+    let estimates = leaves
+        .iter()
+        .map(|node| estimate_cardinality(*node, lp_arena, expr_arena))
+        .collect::<Option<Vec<_>>>()?;
+    let schemas = leaves
+        .iter()
+        .map(|node| lp_arena.get(*node).schema(lp_arena).into_owned())
+        .collect::<Vec<_>>();
+
+    // The distinct values of a join key are approximated by the size of the smallest relation
+    // containing the key. In star and snowflake schemas that is the side with the primary key.
+    let mut key_ndv = PlHashMap::<&str, f64>::new();
+    for (schema, (base, _)) in schemas.iter().zip(&estimates) {
+        for name in schema.iter_names() {
+            key_ndv
+                .entry(name.as_str())
+                .and_modify(|ndv| *ndv = ndv.min(*base))
+                .or_insert(*base);
+        }
+    }
+    let join_size = |rows_left: f64, names_left: &PlHashSet<&str>, right: usize| {
+        let mut denominator = None;
+        for name in schemas[right].iter_names() {
+            if names_left.contains(name.as_str()) {
+                let ndv = key_ndv[name.as_str()];
+                denominator = Some(denominator.map_or(ndv, |d: f64| d.max(ndv)));
+            }
+        }
+        // Not connected, this would be a cross join.
+        denominator.map(|d| rows_left * estimates[right].1 / d.max(1.0))
+    };
+
+    // Start with the pair of relations that gives the smallest join.
+    let mut best: Option<(f64, usize, usize)> = None;
+    for i in 0..leaves.len() {
+        let names_left: PlHashSet<_> = schemas[i].iter_names().map(|s| s.as_str()).collect();
+        for j in i + 1..leaves.len() {
+            if let Some(size) = join_size(estimates[i].1, &names_left, j) {
+                if best.map_or(true, |(best, _, _)| size < best) {
+                    best = Some((size, i, j))
+                }
+            }
+        }
+    }
+    let (mut rows, i, j) = best?;
+    // The larger relation goes left, so that it is on the probe side of every join.
+    let mut order = if estimates[j].1 > estimates[i].1 {
+        vec![j, i]
+    } else {
+        vec![i, j]
+    };
+    let mut names_left = PlHashSet::new();
+    for idx in &order {
+        names_left.extend(schemas[*idx].iter_names().map(|s| s.as_str()))
+    }
+
+    // Then keep adding the relation that gives the smallest intermediate result.
+    while order.len() < leaves.len() {
+        let mut best: Option<(f64, usize)> = None;
+        for right in (0..leaves.len()).filter(|idx| !order.contains(idx)) {
+            if let Some(size) = join_size(rows, &names_left, right) {
+                if best.map_or(true, |(best, _)| size < best) {
+                    best = Some((size, right))
+                }
+            }
+        }
+        let (size, right) = best?;
+        rows = size;
+        names_left.extend(schemas[right].iter_names().map(|s| s.as_str()));
+        order.push(right);
+    }
+    if order.iter().copied().eq(0..leaves.len()) {
+        return None;
+    }
+
+    let ALogicalPlan::Join { options, .. } = lp_arena.get(root) else {
+        unreachable!()
+    };
+    debug_assert_eq!(options.args.join_nulls, join_nulls);
+    let options = options.clone();
+
+    let mut acc = leaves[order[0]];
+    let mut acc_schema = schemas[order[0]].clone();
+    for idx in &order[1..] {
+        let schema_right = &schemas[*idx];
+        let keys = schema_right
+            .iter_names()
+            .filter(|name| acc_schema.contains(name.as_str()))
+            .collect::<Vec<_>>();
+        let left_on = keys
+            .iter()
+            .map(|name| expr_arena.add(AExpr::Column(Arc::from(name.as_str()))))
+            .collect();
+        let right_on = keys
+            .iter()
+            .map(|name| expr_arena.add(AExpr::Column(Arc::from(name.as_str()))))
+            .collect();
+
+        let mut schema = (*acc_schema).clone();
+        for (name, dtype) in schema_right.iter() {
+            if !acc_schema.contains(name) {
+                schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+        let schema = Arc::new(schema);
+        acc = lp_arena.add(ALogicalPlan::Join {
+            input_left: acc,
+            input_right: leaves[*idx],
+            schema: schema.clone(),
+            left_on,
+            right_on,
+            options: options.clone(),
+        });
+        acc_schema = schema;
+    }
+
+    // Restore the column order of the original chain.
+    let schema = lp_arena.get(root).schema(lp_arena).into_owned();
+    if schema.iter_names().eq(acc_schema.iter_names()) {
+        return Some(lp_arena.take(acc));
+    }
+    let exprs = schema
+        .iter_names()
+        .map(|name| expr_arena.add(AExpr::Column(Arc::from(name.as_str()))))
+        .collect::<Vec<_>>();
+    Some(ALogicalPlan::Projection {
+        input: acc,
+        expr: exprs.into(),
+        schema,
+        options: Default::default(),
+    })
+}
+
+/// Estimate the number of rows of a relation.
+///
+/// Returns `(base, estimated)` where `base` is the number of rows before any filter is applied.
+fn estimate_cardinality(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(f64, f64)> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            let base = df.height() as f64;
+            let selectivity = selection.map_or(1.0, |e| predicate_selectivity(e, expr_arena));
+            Some((base, base * selectivity))
+        },
+        Scan {
+            file_info,
+            predicate,
+            scan_type,
+            file_options,
+            ..
+        } => {
+            let (known, estimated) = file_info.row_estimation;
+            let base = match known {
+                Some(known) => known as f64,
+                // `usize::MAX` is used for an unknown size.
+                None if estimated != usize::MAX => estimated as f64,
+                None => return None,
+            };
+            let mut rows = match scan_type {
+                FileScan::Anonymous { function, .. } => {
+                    anonymous_scan_rows(function.as_ref(), *predicate, expr_arena)
+                },
+                #[allow(unreachable_patterns)]
+                _ => None,
+            }
+            .unwrap_or_else(|| {
+                base * predicate.map_or(1.0, |e| predicate_selectivity(e, expr_arena))
+            });
+            if let Some(n_rows) = file_options.n_rows {
+                rows = rows.min(n_rows as f64)
+            }
+            Some((base, rows))
+        },
+        Selection { input, predicate } => {
+            let (base, rows) = estimate_cardinality(*input, lp_arena, expr_arena)?;
+            Some((base, rows * predicate_selectivity(*predicate, expr_arena)))
+        },
+        Slice { input, len, .. } => {
+            let (base, rows) = estimate_cardinality(*input, lp_arena, expr_arena)?;
+            Some((base, rows.min(*len as f64)))
+        },
+        Projection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        // Upper bounds.
+        | Distinct { input, .. }
+        | Aggregate { input, .. } => estimate_cardinality(*input, lp_arena, expr_arena),
+        _ => None,
+    }
+}
@@ -24,8 +24,10 @@ pub(crate) mod file_caching;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod predicate_pushdown;
 mod projection_pushdown;
+mod redundant_sort;
 mod simplify_expr;
 mod simplify_functions;
 mod slice_pushdown_expr;
@@ -37,7 +39,7 @@ use delay_rechunk::DelayRechunk;
 use drop_nulls::ReplaceDropNulls;
 use fast_projection::FastProjectionAndCollapse;
 use polars_io::predicates::PhysicalIoExpr;
-pub use predicate_pushdown::PredicatePushDown;
+pub use predicate_pushdown::{aexpr_blocks_predicate_pushdown, PredicatePushDown};
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
@@ -81,6 +83,7 @@ pub fn optimize(
     let slice_pushdown = opt_state.slice_pushdown;
     let streaming = opt_state.streaming;
     let fast_projection = opt_state.fast_projection;
+    let join_reorder = opt_state.join_reorder;
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
     let eager = opt_state.eager;
@@ -107,7 +110,7 @@ pub fn optimize(
 
     // Collect members for optimizations that need it.
     let mut members = MemberCollector::new();
-    if !eager && (comm_subexpr_elim || projection_pushdown) {
+    if !eager && (comm_subexpr_elim || projection_pushdown || join_reorder) {
         members.collect(lp_top, lp_arena)
     }
 
@@ -154,6 +157,12 @@ pub fn optimize(
         lp_arena.replace(lp_top, alp);
     }
 
+    // run after predicate pushdown, so that the filters are at the scans
+    // when we estimate the cardinalities.
+    if join_reorder && !eager && members.has_joins_or_unions {
+        join_reorder::reorder_joins(lp_top, lp_arena, expr_arena);
+    }
+
     // make sure its before slice pushdown.
     if fast_projection {
         rules.push(Box::new(FastProjectionAndCollapse::new(eager)));
@@ -161,6 +170,7 @@ pub fn optimize(
 
     if !eager {
         rules.push(Box::new(DelayRechunk::new()));
+        rules.push(Box::new(redundant_sort::RedundantSort {}));
     }
 
     if slice_pushdown {
@@ -102,14 +102,24 @@ pub(super) fn process_join(
     let mut pushdown_right = init_hashmap(Some(acc_predicates.len()));
     let mut local_predicates = Vec::with_capacity(acc_predicates.len());
 
+    // filtering the right table changes which interval is the nearest one
+    #[cfg(feature = "interval_join")]
+    let block_right = matches!(
+        &options.args.how,
+        JoinType::Interval(interval_options) if interval_options.mode == IntervalJoinMode::Nearest
+    );
+    #[cfg(not(feature = "interval_join"))]
+    let block_right = false;
+
     for (_, predicate) in acc_predicates {
         // check if predicate can pass the joins node
         let block_pushdown_left = has_aexpr(predicate, expr_arena, |ae| {
             should_block_join_specific(ae, &options.args.how).0
         });
-        let block_pushdown_right = has_aexpr(predicate, expr_arena, |ae| {
-            should_block_join_specific(ae, &options.args.how).1
-        });
+        let block_pushdown_right = block_right
+            || has_aexpr(predicate, expr_arena, |ae| {
+                should_block_join_specific(ae, &options.args.how).1
+            });
 
         // these indicate to which tables we are going to push down the predicate
         let mut filter_left = false;
@@ -7,6 +7,7 @@ mod utils;
 use polars_core::config::verbose;
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
+pub use utils::aexpr_blocks_predicate_pushdown;
 use utils::*;
 
 use super::*;
@@ -501,6 +502,20 @@ impl<'a> PredicatePushDown<'a> {
                             Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
 
                         }
+                        FunctionNode::Opaque {
+                            predicate_pd: false,
+                            lineage: Some(lineage),
+                            ..
+                        } => {
+                            // only predicates on the columns that pass through unchanged can be
+                            // applied before the function
+                            let condition = |name: Arc<str>| !lineage.passes_through(&name);
+                            let local_predicates =
+                                transfer_to_local_by_name(expr_arena, &mut acc_predicates, condition);
+
+                            let lp = self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)?;
+                            Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+                        }
                         _ => {
                             self.pushdown_and_continue(lp, acc_predicates, lp_arena, expr_arena, false)
                         }
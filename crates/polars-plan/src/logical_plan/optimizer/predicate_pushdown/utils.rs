@@ -199,7 +199,7 @@ fn check_and_extend_predicate_pd_nodes(
 /// Examples of expressions whose results would change, and thus block push-down:
 /// - any aggregation - sum, mean, first, last, min, max etc.
 /// - sorting - as the sort keys would change between filters
-pub(super) fn aexpr_blocks_predicate_pushdown(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+pub fn aexpr_blocks_predicate_pushdown(node: Node, expr_arena: &Arena<AExpr>) -> bool {
     let mut stack = Vec::<Node>::with_capacity(4);
     stack.push(node);
 
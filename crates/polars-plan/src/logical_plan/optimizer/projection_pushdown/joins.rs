@@ -28,6 +28,49 @@ fn add_keys_to_accumulated_state(
     }
 }
 
+/// Push the `by` columns of asof and interval joins down to both tables. The left ones are also
+/// projected locally if they are projected, the right ones if `keep_right` holds for them.
+#[cfg(any(feature = "asof_join", feature = "interval_join"))]
+fn add_by_columns_to_accumulated_state<S: AsRef<str>>(
+    left_by: &[S],
+    right_by: &[S],
+    keep_right: impl Fn(&str) -> bool,
+    pushdown_left: &mut Vec<Node>,
+    pushdown_right: &mut Vec<Node>,
+    local_projection: &mut Vec<Node>,
+    names_left: &mut PlHashSet<Arc<str>>,
+    names_right: &mut PlHashSet<Arc<str>>,
+    projected_names: &PlHashSet<Arc<str>>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    for name in left_by {
+        let name = name.as_ref();
+        let add = projected_names.contains(name);
+
+        let node = expr_arena.add(AExpr::Column(Arc::from(name)));
+        add_keys_to_accumulated_state(
+            node,
+            pushdown_left,
+            local_projection,
+            names_left,
+            expr_arena,
+            add,
+        );
+    }
+    for name in right_by {
+        let name = name.as_ref();
+        let node = expr_arena.add(AExpr::Column(Arc::from(name)));
+        add_keys_to_accumulated_state(
+            node,
+            pushdown_right,
+            local_projection,
+            names_right,
+            expr_arena,
+            keep_right(name),
+        );
+    }
+}
+
 #[cfg(feature = "asof_join")]
 pub(super) fn process_asof_join(
     proj_pd: &mut ProjectionPushDown,
@@ -68,9 +111,24 @@ pub(super) fn process_asof_join(
 
         // make sure that the asof join 'by' columns are projected
         if let (Some(left_by), Some(right_by)) = (&asof_options.left_by, &asof_options.right_by) {
-            for name in left_by {
-                let add = _projected_names.contains(name.as_str());
+            add_by_columns_to_accumulated_state(
+                left_by,
+                right_by,
+                |_| false,
+                &mut pushdown_left,
+                &mut pushdown_right,
+                &mut local_projection,
+                &mut names_left,
+                &mut names_right,
+                &_projected_names,
+                expr_arena,
+            );
+        }
 
+        // and the column with the tolerance of every left row
+        if let Some(name) = &asof_options.tolerance_column {
+            if !names_left.contains(name.as_str()) {
+                let add = _projected_names.contains(name.as_str());
                 let node = expr_arena.add(AExpr::Column(Arc::from(name.as_str())));
                 add_keys_to_accumulated_state(
                     node,
@@ -81,17 +139,6 @@ pub(super) fn process_asof_join(
                     add,
                 );
             }
-            for name in right_by {
-                let node = expr_arena.add(AExpr::Column(Arc::from(name.as_str())));
-                add_keys_to_accumulated_state(
-                    node,
-                    &mut pushdown_right,
-                    &mut local_projection,
-                    &mut names_right,
-                    expr_arena,
-                    false,
-                );
-            }
         }
 
         // The join on keys can lead that columns are already added, we don't want to create
@@ -258,8 +305,9 @@ pub(super) fn process_join(
             .unwrap();
             already_added_local_to_local_projected.insert(local_name);
         }
-        // In outer joins both columns remain. So `add_local=true` also for the right table
-        let add_local = matches!(options.args.how, JoinType::Outer { coalesce: false });
+        // In outer and inequality joins both columns remain. So `add_local=true` also for the
+        // right table
+        let add_local = !options.args.how.merges_join_keys();
         for e in &right_on {
             // In case of outer joins we also add the columns.
             // But before we do that we must check if the column wasn't already added by the lhs.
@@ -284,6 +332,32 @@ pub(super) fn process_join(
             }
         }
 
+        // make sure that the interval join 'by' columns are projected, the right ones are in
+        // the output unless the left one has the same name
+        #[cfg(feature = "interval_join")]
+        if let JoinType::Interval(interval_options) = &options.args.how {
+            if let (Some(left_by), Some(right_by)) =
+                (&interval_options.left_by, &interval_options.right_by)
+            {
+                add_by_columns_to_accumulated_state(
+                    left_by,
+                    right_by,
+                    |name| {
+                        _projected_names.contains(name)
+                            && !interval_options.merges_by_column(name)
+                            && !schema_left.contains(name)
+                    },
+                    &mut pushdown_left,
+                    &mut pushdown_right,
+                    &mut local_projection,
+                    &mut names_left,
+                    &mut names_right,
+                    &_projected_names,
+                    expr_arena,
+                );
+            }
+        }
+
         for proj in acc_projections {
             let mut add_local = if already_added_local_to_local_projected.is_empty() {
                 true
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Removes sorts of data that is already sorted by the sort key.
+pub(super) struct RedundantSort {}
+
+impl OptimizationRule for RedundantSort {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let ALogicalPlan::Sort {
+            input,
+            by_column,
+            args,
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        // With multiple keys the ties of the first key may still need sorting.
+        let [by] = by_column.as_slice() else {
+            return None;
+        };
+        let AExpr::Column(name) = expr_arena.get(*by) else {
+            return None;
+        };
+        let sorted = sorted_column(*input, name, lp_arena, expr_arena)?;
+        if !sorted.satisfies(args.descending[0], args.nulls_last) {
+            return None;
+        }
+        match args.slice {
+            Some((offset, len)) => Some(ALogicalPlan::Slice {
+                input: *input,
+                offset,
+                len: len as IdxSize,
+            }),
+            None => Some(lp_arena.get(*input).clone()),
+        }
+    }
+}
@@ -164,8 +164,6 @@ pub struct DistinctOptions {
     pub subset: Option<Arc<Vec<String>>>,
     /// This will maintain the order of the input.
     /// Note that this is more expensive.
-    /// `maintain_order` is not supported in the streaming
-    /// engine.
     pub maintain_order: bool,
     /// Which rows to keep.
     pub keep_strategy: UniqueKeepStrategy,
@@ -324,6 +322,7 @@ pub struct PythonOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AnonymousScanOptions {
     pub skip_rows: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
     pub fmt_str: &'static str,
 }
 
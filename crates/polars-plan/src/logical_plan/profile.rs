@@ -0,0 +1,212 @@
+//! Runtime statistics of the nodes of an executed plan, used by `explain_analyze`.
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Runtime statistics of a node of an executed plan.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeProfile {
+    /// Wall time spent in the node.
+    ///
+    /// In the default engine this includes the time spent in its inputs. The streaming engine
+    /// runs the nodes of a pipeline interleaved on multiple threads, there it is the time spent
+    /// in the node itself, summed over the threads.
+    pub time: Duration,
+    /// Rows produced by the inputs of the node, `None` if no input was recorded, as for sources.
+    pub rows_in: Option<usize>,
+    /// Rows produced by the node.
+    pub rows_out: usize,
+    /// Estimated size in bytes of the largest amount of data the node held at once.
+    pub peak_memory: usize,
+}
+
+impl NodeProfile {
+    fn describe(&self) -> String {
+        let mut s = format!("time: {:.2?}\nrows: ", self.time);
+        if let Some(rows_in) = self.rows_in {
+            write!(s, "{rows_in} -> ").unwrap();
+        }
+        write!(
+            s,
+            "{}\npeak memory: {}",
+            self.rows_out,
+            fmt_bytes(self.peak_memory)
+        )
+        .unwrap();
+        s
+    }
+}
+
+fn fmt_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[derive(Default)]
+struct NodeRecord {
+    time: Duration,
+    rows_out: usize,
+    bytes: usize,
+    /// The whole output was held at once, together with the materialized inputs.
+    materialized: bool,
+}
+
+/// The plan as it was optimized, together with the nodes it was converted from in pre-order.
+struct PlanSnapshot {
+    plan: LogicalPlan,
+    nodes: Vec<(Node, Vec<Node>)>,
+}
+
+/// Collects the runtime statistics of the nodes of a plan during its execution.
+///
+/// The nodes are identified by their [`Node`] in the [`ALogicalPlan`] arena. The plan has
+/// to be registered with [`PlanProfiler::set_plan`] before it is converted for execution.
+#[derive(Default)]
+pub struct PlanProfiler {
+    records: Mutex<PlHashMap<Node, NodeRecord>>,
+    plan: OnceLock<PlanSnapshot>,
+}
+
+impl PlanProfiler {
+    /// Register the optimized plan whose nodes are profiled.
+    pub fn set_plan(&self, root: Node, lp_arena: &Arena<ALogicalPlan>, expr_arena: &Arena<AExpr>) {
+        let mut nodes = vec![];
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            let inputs = plan_inputs(lp_arena.get(node));
+            // pre-order with the inputs in the order they are formatted
+            stack.extend(inputs.iter().rev());
+            nodes.push((node, inputs));
+        }
+        let plan = node_to_lp_cloned(root, expr_arena, lp_arena);
+        let _ = self.plan.set(PlanSnapshot { plan, nodes });
+    }
+
+    /// Record a node that materialized its whole output.
+    pub fn record_materialized(&self, node: Node, time: Duration, df: &DataFrame) {
+        let mut records = self.records.lock().unwrap();
+        let record = records.entry(node).or_default();
+        record.time += time;
+        record.rows_out += df.height();
+        record.bytes = std::cmp::max(record.bytes, df.estimated_size());
+        record.materialized = true;
+    }
+
+    /// Record a node that processed a batch of the data.
+    pub fn record_batch(&self, node: Node, time: Duration, out: Option<&DataFrame>) {
+        let mut records = self.records.lock().unwrap();
+        let record = records.entry(node).or_default();
+        record.time += time;
+        if let Some(df) = out {
+            record.rows_out += df.height();
+            record.bytes = std::cmp::max(record.bytes, df.estimated_size());
+        }
+    }
+
+    /// Combine the records with the registered plan.
+    pub fn finish(&self) -> PolarsResult<PlanProfile> {
+        let snapshot = self
+            .plan
+            .get()
+            .ok_or_else(|| polars_err!(ComputeError: "no plan was profiled"))?;
+        let records = self.records.lock().unwrap();
+
+        let nodes = snapshot
+            .nodes
+            .iter()
+            .map(|(node, inputs)| {
+                let record = records.get(node)?;
+                let input_records = inputs
+                    .iter()
+                    .filter_map(|input| records.get(input))
+                    .collect::<Vec<_>>();
+                let rows_in = (!input_records.is_empty())
+                    .then(|| input_records.iter().map(|input| input.rows_out).sum());
+                let peak_memory = if record.materialized {
+                    record.bytes
+                        + input_records
+                            .iter()
+                            .filter(|input| input.materialized)
+                            .map(|input| input.bytes)
+                            .sum::<usize>()
+                } else {
+                    record.bytes
+                };
+                Some(NodeProfile {
+                    time: record.time,
+                    rows_in,
+                    rows_out: record.rows_out,
+                    peak_memory,
+                })
+            })
+            .collect();
+
+        Ok(PlanProfile {
+            plan: snapshot.plan.clone(),
+            nodes,
+        })
+    }
+}
+
+/// The inputs of a node in the order the formatted plans visit them.
+fn plan_inputs(lp: &ALogicalPlan) -> Vec<Node> {
+    match lp {
+        // the contexts are not part of the formatted plan
+        ALogicalPlan::ExtContext { input, .. } => vec![*input],
+        lp => {
+            let mut inputs = vec![];
+            lp.copy_inputs(&mut inputs);
+            inputs
+        },
+    }
+}
+
+/// An executed plan with the runtime statistics of its nodes.
+#[derive(Clone, Debug)]
+pub struct PlanProfile {
+    plan: LogicalPlan,
+    /// Statistics of the nodes of `plan` in pre-order, `None` if a node was not executed.
+    nodes: Vec<Option<NodeProfile>>,
+}
+
+impl PlanProfile {
+    /// The optimized plan that was executed.
+    pub fn plan(&self) -> &LogicalPlan {
+        &self.plan
+    }
+
+    /// The statistics of the nodes of the plan in pre-order.
+    pub fn nodes(&self) -> &[Option<NodeProfile>] {
+        &self.nodes
+    }
+
+    fn annotations(&self) -> Vec<Option<String>> {
+        self.nodes
+            .iter()
+            .map(|node| node.as_ref().map(|node| node.describe()))
+            .collect()
+    }
+
+    /// Return a String describing the plan in tree format, annotated with the statistics.
+    pub fn describe_tree_format(&self) -> String {
+        self.plan.describe_tree_format_annotated(self.annotations())
+    }
+
+    /// Get a dot language representation of the plan, annotated with the statistics.
+    pub fn to_dot(&self) -> PolarsResult<String> {
+        self.plan.to_dot_annotated(&self.annotations())
+    }
+}
@@ -54,6 +54,8 @@ pub struct FileInfo {
     /// - estimated size
     pub row_estimation: (Option<usize>, usize),
     pub hive_parts: Option<Arc<hive::HivePartitions>>,
+    /// Columns the scanned data is declared to be sorted by.
+    pub sorted_columns: Vec<SortedColumn>,
 }
 
 impl FileInfo {
@@ -67,6 +69,7 @@ impl FileInfo {
             reader_schema,
             row_estimation,
             hive_parts: None,
+            sorted_columns: vec![],
         }
     }
 
@@ -284,6 +287,26 @@ pub(crate) fn det_join_schema(
         #[cfg(feature = "semi_anti_join")]
         JoinType::Semi | JoinType::Anti => Ok(schema_left.clone()),
         _ => {
+            // The keys of inequality, interval and fuzzy joins hold different values, so all columns
+            // of both sides are kept.
+            #[cfg(feature = "iejoin")]
+            let (left_on, right_on) = if matches!(options.args.how, JoinType::IEJoin(_)) {
+                (&[][..], &[][..])
+            } else {
+                (left_on, right_on)
+            };
+            #[cfg(feature = "interval_join")]
+            let (left_on, right_on) = if matches!(options.args.how, JoinType::Interval(_)) {
+                (&[][..], &[][..])
+            } else {
+                (left_on, right_on)
+            };
+            #[cfg(feature = "fuzzy_join")]
+            let (left_on, right_on) = if matches!(options.args.how, JoinType::Fuzzy(_)) {
+                (&[][..], &[][..])
+            } else {
+                (left_on, right_on)
+            };
             let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
 
             for (name, dtype) in schema_left.iter() {
@@ -349,6 +372,13 @@ pub(crate) fn det_join_schema(
                             }
                         }
 
+                        #[cfg(feature = "interval_join")]
+                        if let JoinType::Interval(interval_options) = &options.args.how {
+                            if interval_options.merges_by_column(name) {
+                                continue;
+                            }
+                        }
+
                         let new_name = format_smartstring!("{}{}", name, options.args.suffix());
                         new_schema.with_column(new_name, dtype.clone());
                     } else {
@@ -0,0 +1,132 @@
+//! Versioned serialization of logical plans.
+//!
+//! Serialized plans start with the version of the format they were written in, so that a
+//! process can refuse plans it doesn't understand instead of misinterpreting them. The version
+//! has to be bumped whenever the serialized representation of the plan changes in a way older
+//! readers can't handle, e.g. when a field is added to or removed from a variant.
+//!
+//! User defined functions and anonymous scans are serialized by name, see the `registry`
+//! module.
+mod registry;
+
+use std::io::{Read, Write};
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+pub(crate) use registry::{read_registered, registered, registered_opt, write_registered};
+pub use registry::{
+    register_anonymous_scan, register_dataframe_udf, register_series_udf, register_udf_schema,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Version of the plan format written by [`LogicalPlan::serialize_versioned`].
+pub const PLAN_FORMAT_VERSION: u32 = 1;
+/// Oldest version of the plan format [`LogicalPlan::deserialize_versioned`] can read.
+pub const MIN_PLAN_FORMAT_VERSION: u32 = 1;
+
+const BINARY_MAGIC: &[u8] = b"POLARS_PLAN";
+
+/// Encoding of a serialized plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PlanFormat {
+    /// A header with the format version followed by the plan encoded as CBOR.
+    Binary,
+    /// A JSON object with a `version` and a `plan` field.
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonPlanRef<'a> {
+    version: u32,
+    plan: &'a LogicalPlan,
+}
+
+#[derive(Deserialize)]
+struct JsonVersion {
+    version: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct JsonPlan {
+    plan: LogicalPlan,
+}
+
+fn check_version(version: u32) -> PolarsResult<()> {
+    polars_ensure!(
+        version <= PLAN_FORMAT_VERSION,
+        ComputeError: "plan was serialized with format version {}, this version of polars reads up to version {}; upgrade polars to read it",
+        version, PLAN_FORMAT_VERSION
+    );
+    polars_ensure!(
+        version >= MIN_PLAN_FORMAT_VERSION,
+        ComputeError: "plan was serialized with format version {}, which is no longer supported; the oldest supported version is {}",
+        version, MIN_PLAN_FORMAT_VERSION
+    );
+    Ok(())
+}
+
+impl LogicalPlan {
+    /// Serialize the plan in the given format, prefixed with [`PLAN_FORMAT_VERSION`].
+    ///
+    /// Fails if the plan contains user defined functions or anonymous scans that are not
+    /// registered, or nodes that can't be serialized, such as deferred errors.
+    pub fn serialize_versioned<W: Write>(
+        &self,
+        mut writer: W,
+        format: PlanFormat,
+    ) -> PolarsResult<()> {
+        match format {
+            PlanFormat::Binary => {
+                writer.write_all(BINARY_MAGIC)?;
+                writer.write_all(&PLAN_FORMAT_VERSION.to_le_bytes())?;
+                ciborium::ser::into_writer(self, writer).map_err(to_compute_err)
+            },
+            PlanFormat::Json => {
+                let plan = JsonPlanRef {
+                    version: PLAN_FORMAT_VERSION,
+                    plan: self,
+                };
+                serde_json::to_writer(writer, &plan).map_err(to_compute_err)
+            },
+        }
+    }
+
+    /// Deserialize a plan written by [`LogicalPlan::serialize_versioned`].
+    ///
+    /// The user defined functions and anonymous scans in the plan must have been registered in
+    /// this process.
+    pub fn deserialize_versioned<R: Read>(mut reader: R, format: PlanFormat) -> PolarsResult<Self> {
+        match format {
+            PlanFormat::Binary => {
+                let mut magic = [0; BINARY_MAGIC.len()];
+                let mut version = [0; 4];
+                reader
+                    .read_exact(&mut magic)
+                    .and_then(|_| reader.read_exact(&mut version))
+                    .ok()
+                    .filter(|_| magic == BINARY_MAGIC)
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "data is not a serialized polars plan"),
+                    )?;
+                check_version(u32::from_le_bytes(version))?;
+                ciborium::de::from_reader(reader).map_err(to_compute_err)
+            },
+            PlanFormat::Json => {
+                let mut buf = vec![];
+                reader.read_to_end(&mut buf)?;
+                // Check the version before interpreting the plan.
+                let version = serde_json::from_slice::<JsonVersion>(&buf)
+                    .ok()
+                    .and_then(|header| header.version)
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "data is not a serialized polars plan with a format version"),
+                    )?;
+                check_version(version)?;
+                let plan = serde_json::from_slice::<JsonPlan>(&buf).map_err(to_compute_err)?;
+                Ok(plan.plan)
+            },
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Registry of named user defined functions, so that plans containing them can be serialized.
+//!
+//! A function that returns a name from its `registry_name` method is serialized as that name
+//! followed by the payload written by its `try_serialize` method. On deserialization the
+//! payload is passed to the deserializer registered under that name in the receiving process.
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::prelude::*;
+
+const REGISTERED_UDF_MARK: &[u8] = b"POLARS_REGISTERED_UDF";
+
+type DeserializeFn<T> = Arc<dyn Fn(&[u8]) -> PolarsResult<Arc<T>> + Send + Sync>;
+type Registry<T> = RwLock<PlHashMap<String, DeserializeFn<T>>>;
+
+static SERIES_UDFS: Lazy<Registry<dyn SeriesUdf>> = Lazy::new(Default::default);
+static DATAFRAME_UDFS: Lazy<Registry<dyn DataFrameUdf>> = Lazy::new(Default::default);
+static UDF_SCHEMAS: Lazy<Registry<dyn UdfSchema>> = Lazy::new(Default::default);
+static ANONYMOUS_SCANS: Lazy<Registry<dyn AnonymousScan>> = Lazy::new(Default::default);
+
+/// The kinds of functions that can be registered.
+pub(crate) trait Registered: Send + Sync {
+    const KIND: &'static str;
+
+    fn registry() -> &'static Registry<Self>;
+
+    fn name(&self) -> Option<&str>;
+
+    fn write_payload(&self, buf: &mut Vec<u8>) -> PolarsResult<()>;
+}
+
+impl Registered for dyn SeriesUdf {
+    const KIND: &'static str = "series udf";
+
+    fn registry() -> &'static Registry<Self> {
+        &SERIES_UDFS
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.registry_name()
+    }
+
+    fn write_payload(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        self.try_serialize(buf)
+    }
+}
+
+impl Registered for dyn DataFrameUdf {
+    const KIND: &'static str = "dataframe udf";
+
+    fn registry() -> &'static Registry<Self> {
+        &DATAFRAME_UDFS
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.registry_name()
+    }
+
+    fn write_payload(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        self.try_serialize(buf)
+    }
+}
+
+impl Registered for dyn UdfSchema {
+    const KIND: &'static str = "udf schema";
+
+    fn registry() -> &'static Registry<Self> {
+        &UDF_SCHEMAS
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.registry_name()
+    }
+
+    fn write_payload(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        self.try_serialize(buf)
+    }
+}
+
+impl Registered for dyn AnonymousScan {
+    const KIND: &'static str = "anonymous scan";
+
+    fn registry() -> &'static Registry<Self> {
+        &ANONYMOUS_SCANS
+    }
+
+    fn name(&self) -> Option<&str> {
+        self.registry_name()
+    }
+
+    fn write_payload(&self, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        self.try_serialize(buf)
+    }
+}
+
+fn register<T: ?Sized + Registered>(name: &str, deserialize: DeserializeFn<T>) {
+    T::registry()
+        .write()
+        .unwrap()
+        .insert(name.to_string(), deserialize);
+}
+
+/// Register the deserializer of the [`SeriesUdf`]s named `name`.
+pub fn register_series_udf<F>(name: &str, deserialize: F)
+where
+    F: Fn(&[u8]) -> PolarsResult<Arc<dyn SeriesUdf>> + Send + Sync + 'static,
+{
+    register(name, Arc::new(deserialize))
+}
+
+/// Register the deserializer of the [`DataFrameUdf`]s named `name`.
+pub fn register_dataframe_udf<F>(name: &str, deserialize: F)
+where
+    F: Fn(&[u8]) -> PolarsResult<Arc<dyn DataFrameUdf>> + Send + Sync + 'static,
+{
+    register(name, Arc::new(deserialize))
+}
+
+/// Register the deserializer of the [`UdfSchema`]s named `name`.
+pub fn register_udf_schema<F>(name: &str, deserialize: F)
+where
+    F: Fn(&[u8]) -> PolarsResult<Arc<dyn UdfSchema>> + Send + Sync + 'static,
+{
+    register(name, Arc::new(deserialize))
+}
+
+/// Register the deserializer of the [`AnonymousScan`]s named `name`.
+pub fn register_anonymous_scan<F>(name: &str, deserialize: F)
+where
+    F: Fn(&[u8]) -> PolarsResult<Arc<dyn AnonymousScan>> + Send + Sync + 'static,
+{
+    register(name, Arc::new(deserialize))
+}
+
+/// Write the name of a registered function followed by its payload.
+pub(crate) fn write_registered(
+    name: &str,
+    buf: &mut Vec<u8>,
+    write_payload: impl FnOnce(&mut Vec<u8>) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    buf.extend_from_slice(REGISTERED_UDF_MARK);
+    buf.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    buf.extend_from_slice(name.as_bytes());
+    write_payload(buf)
+}
+
+/// Restore a function written by [`write_registered`].
+///
+/// Returns `None` if `buf` doesn't hold a registered function.
+pub(crate) fn read_registered<T: ?Sized + Registered>(buf: &[u8]) -> Option<PolarsResult<Arc<T>>> {
+    let buf = buf.strip_prefix(REGISTERED_UDF_MARK)?;
+    let read = || {
+        polars_ensure!(buf.len() >= 4, ComputeError: "invalid serialized {}", T::KIND);
+        let (len, buf) = buf.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        polars_ensure!(buf.len() >= len, ComputeError: "invalid serialized {}", T::KIND);
+        let (name, payload) = buf.split_at(len);
+        let name = std::str::from_utf8(name)
+            .map_err(|_| polars_err!(ComputeError: "invalid serialized {}", T::KIND))?;
+
+        let deserialize = T::registry().read().unwrap().get(name).cloned();
+        let deserialize = deserialize.ok_or_else(|| {
+            polars_err!(
+                ComputeError: "no {} is registered under the name '{}'; register it before deserializing the plan",
+                T::KIND, name
+            )
+        })?;
+        deserialize(payload)
+    };
+    Some(read())
+}
+
+fn serialize_registered<T: ?Sized + Registered>(function: &T) -> PolarsResult<Vec<u8>> {
+    let name = function.name().ok_or_else(|| {
+        polars_err!(
+            ComputeError: "cannot serialize an unnamed {}; give it a `registry_name` and register its deserializer",
+            T::KIND
+        )
+    })?;
+    let mut buf = vec![];
+    write_registered(name, &mut buf, |buf| function.write_payload(buf))?;
+    Ok(buf)
+}
+
+fn deserialize_registered<T: ?Sized + Registered>(buf: &[u8]) -> PolarsResult<Arc<T>> {
+    read_registered(buf)
+        .unwrap_or_else(|| Err(polars_err!(ComputeError: "expected a registered {}", T::KIND)))
+}
+
+/// Serde implementation for registered functions, to be used with `#[serde(with = "...")]`.
+pub(crate) mod registered {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(function: &Arc<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Registered,
+        S: Serializer,
+    {
+        let buf = serialize_registered(function.as_ref()).map_err(S::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Arc<T>, D::Error>
+    where
+        T: ?Sized + Registered,
+        D: Deserializer<'de>,
+    {
+        let buf = Vec::<u8>::deserialize(deserializer)?;
+        deserialize_registered(&buf).map_err(D::Error::custom)
+    }
+}
+
+/// Serde implementation for optional registered functions, to be used with
+/// `#[serde(with = "...")]`.
+pub(crate) mod registered_opt {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(
+        function: &Option<Arc<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + Registered,
+        S: Serializer,
+    {
+        match function {
+            Some(function) => {
+                let buf = serialize_registered(function.as_ref()).map_err(S::Error::custom)?;
+                serializer.serialize_some(serde_bytes(&buf))
+            },
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Arc<T>>, D::Error>
+    where
+        T: ?Sized + Registered,
+        D: Deserializer<'de>,
+    {
+        Option::<Vec<u8>>::deserialize(deserializer)?
+            .map(|buf| deserialize_registered(&buf).map_err(D::Error::custom))
+            .transpose()
+    }
+
+    /// Serialize as bytes rather than as a sequence.
+    fn serde_bytes(buf: &[u8]) -> impl serde::Serialize + '_ {
+        struct Bytes<'a>(&'a [u8]);
+
+        impl serde::Serialize for Bytes<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+        Bytes(buf)
+    }
+}
@@ -0,0 +1,252 @@
+//! Tracks which output columns of a plan are sorted.
+//!
+//! Scans declare the columns they are sorted by (in-memory data by the sorted flags of
+//! its columns, parquet files by their `sorting_columns`, anonymous scans by
+//! [`AnonymousScan::sorted_columns`]). The sortedness is then propagated through the
+//! nodes that keep the order of their input.
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A column the output of a node is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SortedColumn {
+    pub name: Arc<str>,
+    pub descending: bool,
+    /// Where the nulls are, `None` if the column has no nulls.
+    pub nulls_last: Option<bool>,
+}
+
+impl SortedColumn {
+    pub fn new(name: &str, descending: bool, nulls_last: Option<bool>) -> Self {
+        Self {
+            name: Arc::from(name),
+            descending,
+            nulls_last,
+        }
+    }
+
+    /// Check if sorting by this column with these arguments leaves the data unchanged.
+    pub fn satisfies(&self, descending: bool, nulls_last: bool) -> bool {
+        self.descending == descending && self.nulls_last.map_or(true, |nl| nl == nulls_last)
+    }
+
+    pub fn is_sorted(&self) -> IsSorted {
+        if self.descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        }
+    }
+
+    fn renamed(&self, name: &str) -> Self {
+        Self {
+            name: Arc::from(name),
+            ..self.clone()
+        }
+    }
+}
+
+/// Get the sortedness of a [`Series`] from its sorted flag.
+pub(crate) fn series_sortedness(s: &Series) -> Option<SortedColumn> {
+    let descending = match s.is_sorted_flag() {
+        IsSorted::Ascending => false,
+        IsSorted::Descending => true,
+        IsSorted::Not => return None,
+    };
+    let nulls_last =
+        (s.null_count() > 0).then(|| s.get(0).map_or(false, |av| !matches!(av, AnyValue::Null)));
+    Some(SortedColumn::new(s.name(), descending, nulls_last))
+}
+
+/// The columns the output of `node` is sorted by.
+pub fn sorted_columns(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<SortedColumn> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        DataFrameScan {
+            df, output_schema, ..
+        } => df
+            .get_columns()
+            .iter()
+            .filter(|s| {
+                output_schema
+                    .as_ref()
+                    .map_or(true, |schema| schema.contains(s.name()))
+            })
+            .filter_map(series_sortedness)
+            .collect(),
+        Scan {
+            file_info,
+            output_schema,
+            file_options,
+            ..
+        } => {
+            let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+            let mut sorted = file_info
+                .sorted_columns
+                .iter()
+                .filter(|s| schema.contains(&s.name))
+                .cloned()
+                .collect::<Vec<_>>();
+            if let Some(row_index) = &file_options.row_index {
+                sorted.push(SortedColumn::new(&row_index.name, false, None))
+            }
+            sorted
+        },
+        Selection { input, .. }
+        | Slice { input, .. }
+        | Cache { input, .. }
+        | Sink { input, .. }
+        | ExtContext { input, .. } => sorted_columns(*input, lp_arena, expr_arena),
+        Distinct { input, options } if options.maintain_order => {
+            sorted_columns(*input, lp_arena, expr_arena)
+        },
+        Sort {
+            by_column, args, ..
+        } => {
+            // Only the first key is sorted over the whole output.
+            match expr_arena.get(by_column[0]) {
+                AExpr::Column(name) => vec![SortedColumn::new(
+                    name,
+                    args.descending[0],
+                    Some(args.nulls_last),
+                )],
+                _ => vec![],
+            }
+        },
+        Projection { input, expr, .. } => {
+            let input_sorted = sorted_columns(*input, lp_arena, expr_arena);
+            project_sorted(&input_sorted, expr.default_exprs(), expr_arena)
+        },
+        HStack {
+            input,
+            exprs,
+            schema,
+            ..
+        } => {
+            let input_sorted = sorted_columns(*input, lp_arena, expr_arena);
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let mut sorted = project_sorted(&input_sorted, exprs.default_exprs(), expr_arena);
+            // Columns that are not overwritten keep their order.
+            for s in input_sorted {
+                let overwritten = exprs.default_exprs().iter().any(|e| {
+                    expr_arena
+                        .get(*e)
+                        .to_field(&input_schema, Context::Default, expr_arena)
+                        .map_or(true, |field| field.name().as_str() == s.name.as_ref())
+                });
+                if !overwritten && schema.contains(&s.name) && !sorted.contains(&s) {
+                    sorted.push(s)
+                }
+            }
+            sorted
+        },
+        MapFunction { input, function } => {
+            let input_sorted = || sorted_columns(*input, lp_arena, expr_arena);
+            match function {
+                FunctionNode::Rechunk | FunctionNode::DropNulls { .. } => input_sorted(),
+                FunctionNode::FastProjection { columns, .. } => input_sorted()
+                    .into_iter()
+                    .filter(|s| columns.iter().any(|c| c.as_str() == s.name.as_ref()))
+                    .collect(),
+                FunctionNode::Rename { existing, new, .. } => input_sorted()
+                    .into_iter()
+                    .filter_map(|s| {
+                        match existing
+                            .iter()
+                            .position(|name| name.as_str() == s.name.as_ref())
+                        {
+                            Some(idx) => Some(s.renamed(&new[idx])),
+                            // An existing column can be overwritten by a renamed one.
+                            None if new.iter().any(|name| name.as_str() == s.name.as_ref()) => None,
+                            None => Some(s),
+                        }
+                    })
+                    .collect(),
+                FunctionNode::RowIndex { name, .. } => {
+                    let mut sorted = input_sorted();
+                    sorted.push(SortedColumn::new(name, false, None));
+                    sorted
+                },
+                _ => vec![],
+            }
+        },
+        Aggregate {
+            input,
+            keys,
+            maintain_order,
+            options: _options,
+            ..
+        } if *maintain_order => {
+            #[cfg(feature = "dynamic_group_by")]
+            if _options.dynamic.is_some() || _options.rolling.is_some() {
+                return vec![];
+            }
+            // The groups are in the order of their first occurrence.
+            let input_sorted = sorted_columns(*input, lp_arena, expr_arena);
+            project_sorted(&input_sorted, keys, expr_arena)
+        },
+        Join {
+            input_left,
+            options,
+            ..
+        } if matches!(options.args.how, JoinType::Left) => {
+            // A left join keeps the order of the left table.
+            let left_sorted = sorted_columns(*input_left, lp_arena, expr_arena);
+            let schema = lp_arena.get(node).schema(lp_arena);
+            left_sorted
+                .into_iter()
+                .filter(|s| schema.contains(&s.name))
+                .collect()
+        },
+        _ => vec![],
+    }
+}
+
+/// Find the sortedness of the expressions that are a (renamed) column.
+fn project_sorted(
+    input_sorted: &[SortedColumn],
+    exprs: &[Node],
+    expr_arena: &Arena<AExpr>,
+) -> Vec<SortedColumn> {
+    if input_sorted.is_empty() {
+        return vec![];
+    }
+    exprs
+        .iter()
+        .filter_map(|e| {
+            let (input, output) = match expr_arena.get(*e) {
+                AExpr::Column(name) => (name, name),
+                AExpr::Alias(input, output) => match expr_arena.get(*input) {
+                    AExpr::Column(name) => (name, output),
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            input_sorted
+                .iter()
+                .find(|s| s.name == *input)
+                .map(|s| s.renamed(output))
+        })
+        .collect()
+}
+
+/// Find the column `name` in the output of `node` is sorted by.
+pub fn sorted_column(
+    node: Node,
+    name: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<SortedColumn> {
+    sorted_columns(node, lp_arena, expr_arena)
+        .into_iter()
+        .find(|s| s.name.as_ref() == name)
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::fmt::{Debug, Display, Formatter, UpperExp};
+use std::fmt::{Debug, Display, Formatter, UpperExp, Write};
 
 use polars_core::error::*;
 #[cfg(feature = "regex")]
@@ -91,7 +91,19 @@ impl<'a> TreeFmtNode<'a> {
     }
 
     pub fn traverse(&self, visitor: &mut TreeFmtVisitor) {
-        let TreeFmtNodeData(title, child_nodes) = self.node_data();
+        let TreeFmtNodeData(mut title, child_nodes) = self.node_data();
+        if let TreeFmtNode::LogicalPlan(..) = self {
+            // the annotations are given in the pre-order of the plan nodes
+            let annotation = visitor
+                .annotations
+                .get(visitor.plan_nodes)
+                .cloned()
+                .flatten();
+            visitor.plan_nodes += 1;
+            if let Some(annotation) = annotation {
+                write!(title, "\n[{annotation}]").unwrap();
+            }
+        }
 
         if visitor.levels.len() <= visitor.depth {
             visitor.levels.push(vec![]);
@@ -300,6 +312,19 @@ pub(crate) struct TreeFmtVisitor {
     prev_depth: usize,
     depth: usize,
     width: usize,
+    /// Extra text for the plan nodes, in pre-order.
+    annotations: Vec<Option<String>>,
+    /// Number of plan nodes that were visited.
+    plan_nodes: usize,
+}
+
+impl TreeFmtVisitor {
+    pub(crate) fn with_annotations(annotations: Vec<Option<String>>) -> Self {
+        Self {
+            annotations,
+            ..Default::default()
+        }
+    }
 }
 
 impl Visitor for TreeFmtVisitor {
@@ -24,8 +24,19 @@ impl TreeWalker for Expr {
         Ok(VisitRecursion::Continue)
     }
 
-    fn map_children(self, _op: &mut dyn FnMut(Self) -> PolarsResult<Self>) -> PolarsResult<Self> {
-        todo!()
+    fn map_children(
+        mut self,
+        op: &mut dyn FnMut(Self) -> PolarsResult<Self>,
+    ) -> PolarsResult<Self> {
+        let mut scratch = unitvec![];
+
+        self.nodes_mut(&mut scratch);
+
+        for child in scratch.iter_mut() {
+            **child = op(std::mem::take(*child))?;
+        }
+        drop(scratch);
+        Ok(self)
     }
 }
 
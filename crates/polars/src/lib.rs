@@ -188,6 +188,7 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_similarity` - Levenshtein, Jaro-Winkler and Jaccard distances between strings.
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -225,6 +226,9 @@
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
+//!     - `iejoin` - Joins on inequalities such as `<`, `<=` and ranges between the keys.
+//!     - `interval_join` - Join `[start, end)` intervals that overlap, contain each other or are nearest.
+//!     - `fuzzy_join` - Join strings that are within an edit or similarity distance of each other.
 //!     - `group_by_list` - Allow group_by operation on keys of type List.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
@@ -262,7 +266,8 @@
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
 //!     - `list_count` - Count elements in lists.
-//!     - `list_eval` - Apply expressions over list elements.
+//!     - `list_eval` - Apply expressions over list and array elements.
+//!     - `array_distance` - Dot products, cosine similarities, L2 distances and nearest neighbours of array vectors.
 //!     - `list_sets` - Compute UNION, INTERSECTION, and DIFFERENCE on list types.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
@@ -309,11 +314,14 @@
 //! | UInt16                  | dtype-u16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Union                   | dtype-union       |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
 //!
-//! * `dtype-full` - all opt-in dtypes.
+//! * `dtype-full` - all opt-in dtypes, except for `dtype-map` and `dtype-union`. Without them,
+//!   maps are read as a list of `{key, value}` structs.
 //! * `dtype-slim` - slim preset of opt-in dtypes.
 //!
 //! ## Performance and string data
@@ -25,3 +25,62 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+fn test_horizontal_aggregations() -> PolarsResult<()> {
+    use polars_lazy::dsl::{
+        arg_max_horizontal, arg_min_horizontal, mean_horizontal, mean_horizontal_with_options,
+        median_horizontal, n_unique_horizontal, null_count_horizontal, std_horizontal,
+        var_horizontal,
+    };
+
+    let df = df![
+        "a" => [Some(1), Some(4), None],
+        "b" => [Some(3), Some(4), Some(2)],
+        "c" => [Some(2), None, Some(5)]
+    ]?;
+    let cols = [col("*")];
+
+    let out = df
+        .lazy()
+        .select([
+            mean_horizontal(cols.clone())?.alias("mean"),
+            mean_horizontal_with_options(cols.clone(), false)?.alias("mean_propagate_nulls"),
+            var_horizontal(cols.clone(), 1, true)?.alias("var"),
+            std_horizontal(cols.clone(), 1, false)?.alias("std"),
+            median_horizontal(cols.clone(), true)?.alias("median"),
+            arg_max_horizontal(cols.clone(), true)?.alias("arg_max"),
+            arg_min_horizontal(cols.clone(), false)?.alias("arg_min"),
+            null_count_horizontal(cols.clone())?.alias("null_count"),
+            n_unique_horizontal(cols)?.alias("n_unique"),
+        ])
+        .collect()?;
+
+    let floats =
+        |name: &str| -> PolarsResult<Vec<Option<f64>>> { Ok(Vec::from(out.column(name)?.f64()?)) };
+    let indices = |name: &str| -> PolarsResult<Vec<Option<IdxSize>>> {
+        Ok(Vec::from(out.column(name)?.idx()?))
+    };
+    assert_eq!(floats("mean")?, &[Some(2.0), Some(4.0), Some(3.5)]);
+    assert_eq!(floats("mean_propagate_nulls")?, &[Some(2.0), None, None]);
+    assert_eq!(floats("var")?, &[Some(1.0), Some(0.0), Some(4.5)]);
+    assert_eq!(floats("std")?, &[Some(1.0), None, None]);
+    assert_eq!(floats("median")?, &[Some(2.0), Some(4.0), Some(3.5)]);
+    assert_eq!(indices("arg_max")?, &[Some(1), Some(0), Some(2)]);
+    assert_eq!(indices("arg_min")?, &[Some(0), None, None]);
+    assert_eq!(indices("null_count")?, &[Some(0), Some(1), Some(1)]);
+    assert_eq!(indices("n_unique")?, &[Some(3), Some(2), Some(3)]);
+
+    // wide rows are deduplicated with a hash set
+    let wide = DataFrame::new(
+        (0..10)
+            .map(|i| Series::new(&format!("c{i}"), [Some(i % 3), None]))
+            .collect(),
+    )?;
+    let out = wide
+        .lazy()
+        .select([n_unique_horizontal([col("*")])?])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("c0")?.idx()?), &[Some(3), Some(1)]);
+    Ok(())
+}
@@ -7,6 +7,8 @@ mod folds;
 mod functions;
 mod group_by;
 mod group_by_dynamic;
+#[cfg(feature = "pivot")]
+mod pivot;
 mod predicate_queries;
 mod projection_queries;
 mod queries;
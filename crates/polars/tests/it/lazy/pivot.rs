@@ -0,0 +1,96 @@
+use polars::prelude::*;
+use polars_ops::pivot::{pivot_stable, PivotAgg};
+
+#[test]
+fn test_pivot_lazy() -> PolarsResult<()> {
+    let df = df![
+        "index1"=> ["foo", "foo", "foo", "foo", "foo", "bar", "bar", "bar", "bar"],
+        "index2"=> ["one", "one", "one", "two", "two", "one", "one", "two", "two"],
+        "cols1"=> ["small", "large", "large", "small", "small", "large", "small", "small", "large"],
+        "values1"=> [1, 2, 2, 3, 3, 4, 5, 6, 7],
+        "values2"=> [2, 4, 5, 5, 6, 6, 8, 9, 9]
+    ]?;
+    let on_columns = Series::new("cols1", ["large", "small"]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .pivot(
+            "cols1",
+            &on_columns,
+            [col("index1"), col("index2")],
+            [col("values1").sum()],
+            None,
+        )
+        .collect()?;
+    let expected = pivot_stable(
+        &df,
+        ["index1", "index2"],
+        ["cols1"],
+        Some(["values1"]),
+        true,
+        Some(PivotAgg::Sum),
+        None,
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let q = df.clone().lazy().pivot(
+        "cols1",
+        &on_columns,
+        [col("index1"), col("index2")],
+        [
+            col("values1").sum().alias("sum"),
+            col("values2").max().alias("max"),
+            len(),
+        ],
+        None,
+    );
+    assert_eq!(
+        q.schema()?
+            .iter_names()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>(),
+        &[
+            "index1",
+            "index2",
+            "sum_large",
+            "sum_small",
+            "max_large",
+            "max_small",
+            "len_large",
+            "len_small"
+        ]
+    );
+    let out = q.clone().collect()?;
+    let expected = df![
+        "index1" => ["foo", "foo", "bar", "bar"],
+        "index2" => ["one", "two", "one", "two"],
+        "sum_large" => [Some(4), None, Some(4), Some(7)],
+        "sum_small" => [1, 6, 5, 6],
+        "max_large" => [Some(5), None, Some(6), Some(9)],
+        "max_small" => [2, 6, 8, 9],
+        "len_large" => [Some(2 as IdxSize), None, Some(1), Some(1)],
+        "len_small" => [1 as IdxSize, 2, 1, 1],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let q = q.select([col("index1"), col("sum_small")]);
+    let expected = q.clone().without_optimizations().collect()?;
+    assert!(q.collect()?.equals_missing(&expected));
+
+    // every aggregation must produce a single column per value
+    for agg in [
+        all().sum(),
+        cols(["values1", "values2"]).sum(),
+        Expr::Nth(3).sum(),
+        dtype_col(&DataType::Int32).sum(),
+    ] {
+        let q = df
+            .clone()
+            .lazy()
+            .pivot("cols1", &on_columns, [col("index1")], [agg], None);
+        assert!(q.collect().is_err());
+    }
+
+    Ok(())
+}
@@ -217,6 +217,14 @@ impl ToPyObject for Wrap<DataType> {
                 let inner = Wrap(*inner.clone()).to_object(py);
                 class.call1((inner,)).unwrap().into()
             },
+            // Python has no map dtype yet, so maps show up as a list of their entries.
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                Wrap(DataType::List(Box::new(map_entries_dtype(key, value)))).to_object(py)
+            },
+            // Python has no union dtype yet, so unions show up as their tagged struct.
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => Wrap(union_entries_dtype(variants)).to_object(py),
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date")).unwrap();
                 class.call0().unwrap().into()
@@ -56,6 +56,10 @@ impl From<&DataType> for PyDataType {
             DataType::Binary => Binary,
             DataType::Array(_, width) => Array(*width),
             DataType::List(_) => List,
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => List,
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_) => Struct,
             DataType::Date => Date,
             DataType::Datetime(tu, tz) => Datetime(*tu, tz.clone()),
             DataType::Duration(tu) => Duration(*tu),
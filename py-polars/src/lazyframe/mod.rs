@@ -787,6 +787,7 @@ impl PyLazyFrame {
                 right_by: right_by.map(strings_to_smartstrings),
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                ..Default::default()
             }))
             .suffix(suffix)
             .finish()
@@ -78,6 +78,16 @@ impl PySeries {
                         }
                         v
                     },
+                    #[cfg(feature = "dtype-map")]
+                    DataType::Map(_, _) => {
+                        let entries = series.map().unwrap().physical().clone().into_series();
+                        return to_list_recursive(py, &entries);
+                    },
+                    #[cfg(feature = "dtype-union")]
+                    DataType::Union(_) => {
+                        let ca = series.union().unwrap().physical();
+                        return Wrap(ca).to_object(py);
+                    },
                     DataType::Date => {
                         let ca = series.date().unwrap();
                         return Wrap(ca).to_object(py);